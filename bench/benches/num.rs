@@ -57,6 +57,17 @@ fn arithmetic(c: &mut Criterion) {
         b.iter(|| black_box(nums[0]).wrapping_mul(black_box(nums[1])))
     });
 
+    #[cfg(not(feature = "primitive-types"))]
+    {
+        c.bench_function("U256::widening_mul", |b| {
+            b.iter(|| black_box(nums[0]).widening_mul(black_box(nums[1])))
+        });
+
+        c.bench_function("U256::widening_mul/words", |b| {
+            b.iter(|| widening_mul_words(black_box(nums[0]), black_box(nums[1])))
+        });
+    }
+
     c.bench_function("U256::sub", |b| {
         b.iter(|| black_box(nums[0]) - black_box(nums[1]))
     });
@@ -99,5 +110,23 @@ fn arithmetic(c: &mut Criterion) {
     }
 }
 
+/// Schoolbook multiplication over the 128-bit words written with `U256`
+/// operations, as a baseline for `widening_mul`. The result is returned in
+/// the same order, low bits first.
+#[cfg(not(feature = "primitive-types"))]
+fn widening_mul_words(a: U256, b: U256) -> (U256, U256) {
+    let (ah, al) = a.into_words();
+    let (bh, bl) = b.into_words();
+    let ll = U256::new(al) * bl;
+    let lh = U256::new(al) * bh;
+    let hl = U256::new(ah) * bl;
+    let hh = U256::new(ah) * bh;
+
+    let (mid, mid_carry) = lh.overflowing_add(hl);
+    let (lo, lo_carry) = ll.overflowing_add(mid << 128);
+    let hi = hh + (mid >> 128) + (U256::new(mid_carry as u128) << 128) + lo_carry as u128;
+    (lo, hi)
+}
+
 criterion_group!(num, arithmetic);
 criterion_main!(num);
@@ -0,0 +1,26 @@
+fn main() {
+    #[cfg(feature = "ffi")]
+    ffi::generate_header();
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    use std::{env, path::PathBuf};
+
+    /// Generates the C header for the `ffi` module into `$OUT_DIR/ethnum.h`.
+    pub fn generate_header() {
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+
+        let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+        let config = cbindgen::Config::from_file(PathBuf::from(&crate_dir).join("cbindgen.toml"))
+            .expect("invalid cbindgen configuration");
+        cbindgen::Builder::new()
+            .with_src(PathBuf::from(&crate_dir).join("src").join("ffi.rs"))
+            .with_config(config)
+            .generate()
+            .expect("failed to generate the C header")
+            .write_to_file(out_dir.join("ethnum.h"));
+    }
+}
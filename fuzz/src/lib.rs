@@ -9,6 +9,7 @@ use std::error::Error;
 pub enum Target {
     Signed(Op),
     Unsigned(Op),
+    Wide(WideOp),
 }
 
 impl Target {
@@ -47,6 +48,11 @@ pub enum Op {
     Rem(Num, Num),
 }
 
+#[derive(Arbitrary, Debug)]
+pub enum WideOp {
+    DivMod(Num, Num, Num),
+}
+
 #[derive(Arbitrary, Debug, Eq, PartialEq)]
 pub struct Num(u128, u128);
 
@@ -95,6 +101,13 @@ pub fn fuzz(data: &[u8]) -> Result<(), Box<dyn Error>> {
         match target {
             Target::Signed(op) => ops!(op, as_i256),
             Target::Unsigned(op) => ops!(op, as_u256),
+            Target::Wide(WideOp::DivMod(hi, lo, d)) => {
+                let (hi, lo, d) = (hi.as_u256(), lo.as_u256(), d.as_u256());
+                assert_eq!(
+                    ethnum::intrinsics::udivmod_512_256(hi, lo, d),
+                    reference::udivmod_512_256(hi, lo, d),
+                );
+            }
         }
     }
 
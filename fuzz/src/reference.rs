@@ -1,6 +1,8 @@
 //! Reference implementation based on `num` crate.
 
 use crate::conv::{self, Int};
+use ethnum::U256;
+use num::Zero as _;
 
 macro_rules! impl_ref {
     ($(
@@ -39,3 +41,18 @@ impl_ref! {
         a % b
     }
 }
+
+pub fn udivmod_512_256(hi: U256, lo: U256, d: U256) -> Option<(U256, U256)> {
+    let n = (conv::to_bigint(&hi) << 256) + conv::to_bigint(&lo);
+    let d = conv::to_bigint(&d);
+    if d.is_zero() {
+        return None;
+    }
+
+    let (q, overflow) = conv::from_bigint(&(&n / &d));
+    if overflow {
+        return None;
+    }
+    let (r, _) = conv::from_bigint(&(&n % &d));
+    Some((q, r))
+}
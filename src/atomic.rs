@@ -0,0 +1,299 @@
+//! Module containing an atomic 256-bit unsigned integer type.
+
+use crate::U256;
+use core::{fmt, sync::atomic::Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// A 256-bit unsigned integer type which can be safely shared between
+/// threads.
+///
+/// This type has the same API as the primitive atomic integer types in
+/// [`core::sync::atomic`], including the memory ordering parameters. However,
+/// there are no native 256-bit atomic instructions, so it is implemented with
+/// a lock and is **not** lock-free. Every operation is sequentially
+/// consistent regardless of the orderings that are passed in, although they
+/// are checked for validity in the same way as the primitive atomic types.
+///
+/// # Examples
+///
+/// ```
+/// # use ethnum::{AtomicU256, U256};
+/// use std::sync::atomic::Ordering;
+///
+/// let counter = AtomicU256::new(U256::ZERO);
+/// assert_eq!(counter.fetch_add(U256::ONE, Ordering::Relaxed), 0);
+/// assert_eq!(counter.load(Ordering::Relaxed), 1);
+/// ```
+#[derive(Default)]
+pub struct AtomicU256 {
+    value: Mutex<U256>,
+}
+
+impl AtomicU256 {
+    /// Creates a new atomic integer.
+    pub const fn new(value: U256) -> Self {
+        Self {
+            value: Mutex::new(value),
+        }
+    }
+
+    /// Locks the value. Operations never panic while holding the lock, so a
+    /// poisoned lock still holds a valid value and can be used.
+    fn lock(&self) -> MutexGuard<'_, U256> {
+        self.value.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns a mutable reference to the underlying integer.
+    ///
+    /// This is safe because the mutable reference guarantees that no other
+    /// threads are concurrently accessing the atomic data.
+    pub fn get_mut(&mut self) -> &mut U256 {
+        self.value.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Consumes the atomic and returns the contained value.
+    pub fn into_inner(self) -> U256 {
+        self.value
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Loads a value from the atomic integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`](Ordering::Release) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    pub fn load(&self, order: Ordering) -> U256 {
+        match order {
+            Ordering::Release => panic!("there is no such thing as a release load"),
+            Ordering::AcqRel => panic!("there is no such thing as an acquire-release load"),
+            _ => *self.lock(),
+        }
+    }
+
+    /// Stores a value into the atomic integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Acquire`](Ordering::Acquire) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    pub fn store(&self, value: U256, order: Ordering) {
+        match order {
+            Ordering::Acquire => panic!("there is no such thing as an acquire store"),
+            Ordering::AcqRel => panic!("there is no such thing as an acquire-release store"),
+            _ => *self.lock() = value,
+        }
+    }
+
+    /// Stores a value into the atomic integer, returning the previous value.
+    pub fn swap(&self, value: U256, order: Ordering) -> U256 {
+        let _ = order;
+        core::mem::replace(&mut *self.lock(), value)
+    }
+
+    /// Stores a value into the atomic integer if the current value is the
+    /// same as the `current` value.
+    ///
+    /// The return value is a result indicating whether the new value was
+    /// written and containing the previous value. On success this value is
+    /// guaranteed to be equal to `current`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `failure` is [`Release`](Ordering::Release) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    pub fn compare_exchange(
+        &self,
+        current: U256,
+        new: U256,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<U256, U256> {
+        let _ = success;
+        match failure {
+            Ordering::Release => panic!("there is no such thing as a release failure ordering"),
+            Ordering::AcqRel => {
+                panic!("there is no such thing as an acquire-release failure ordering")
+            }
+            _ => {}
+        }
+
+        let mut value = self.lock();
+        if *value == current {
+            *value = new;
+            Ok(current)
+        } else {
+            Err(*value)
+        }
+    }
+
+    /// Stores a value into the atomic integer if the current value is the
+    /// same as the `current` value.
+    ///
+    /// Unlike the primitive atomic types, this never fails spuriously and is
+    /// equivalent to [`AtomicU256::compare_exchange`].
+    pub fn compare_exchange_weak(
+        &self,
+        current: U256,
+        new: U256,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<U256, U256> {
+        self.compare_exchange(current, new, success, failure)
+    }
+
+    /// Adds to the current value, returning the previous value.
+    ///
+    /// This operation wraps around on overflow.
+    pub fn fetch_add(&self, value: U256, order: Ordering) -> U256 {
+        let _ = order;
+        let mut current = self.lock();
+        let previous = *current;
+        *current = previous.wrapping_add(value);
+        previous
+    }
+
+    /// Maximum with the current value, returning the previous value.
+    pub fn fetch_max(&self, value: U256, order: Ordering) -> U256 {
+        let _ = order;
+        let mut current = self.lock();
+        let previous = *current;
+        *current = previous.max(value);
+        previous
+    }
+}
+
+impl From<U256> for AtomicU256 {
+    fn from(value: U256) -> Self {
+        Self::new(value)
+    }
+}
+
+impl fmt::Debug for AtomicU256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::Arc, thread, vec::Vec};
+
+    const THREADS: u128 = 8;
+    const ITERATIONS: u128 = 1000;
+
+    fn spawn(count: u128, f: impl Fn(u128) + Send + Sync + 'static) -> Vec<thread::JoinHandle<()>> {
+        let f = Arc::new(f);
+        (0..count)
+            .map(|i| {
+                let f = f.clone();
+                thread::spawn(move || f(i))
+            })
+            .collect()
+    }
+
+    fn join(handles: Vec<thread::JoinHandle<()>>) {
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    /// Returns a value whose two words are equal, so that a torn read is
+    /// detectable.
+    fn mirrored(word: u128) -> U256 {
+        U256::from_words(word, word)
+    }
+
+    fn assert_not_torn(value: U256) {
+        let (hi, lo) = value.into_words();
+        assert_eq!(hi, lo, "torn read of {value:#x}");
+    }
+
+    #[test]
+    fn no_torn_reads() {
+        let atomic = Arc::new(AtomicU256::new(mirrored(0)));
+
+        let writers = spawn(THREADS, {
+            let atomic = atomic.clone();
+            move |i| {
+                for j in 0..ITERATIONS {
+                    let value = mirrored(!(i * ITERATIONS + j));
+                    match j % 3 {
+                        0 => atomic.store(value, Ordering::Release),
+                        1 => assert_not_torn(atomic.swap(value, Ordering::AcqRel)),
+                        _ => assert_not_torn(atomic.fetch_max(value, Ordering::AcqRel)),
+                    }
+                }
+            }
+        });
+        let readers = spawn(THREADS, {
+            let atomic = atomic.clone();
+            move |_| {
+                for _ in 0..ITERATIONS {
+                    assert_not_torn(atomic.load(Ordering::Acquire));
+                }
+            }
+        });
+
+        join(writers);
+        join(readers);
+        assert_not_torn(Arc::try_unwrap(atomic).unwrap().into_inner());
+    }
+
+    #[test]
+    fn concurrent_counters() {
+        // Start close to a word boundary so that additions carry across the
+        // two 128-bit words.
+        let start = U256::new(u128::MAX - ITERATIONS);
+        let atomic = Arc::new(AtomicU256::new(start));
+
+        join(spawn(THREADS, {
+            let atomic = atomic.clone();
+            move |i| {
+                for _ in 0..ITERATIONS {
+                    if i % 2 == 0 {
+                        atomic.fetch_add(U256::ONE, Ordering::Relaxed);
+                    } else {
+                        let mut current = atomic.load(Ordering::Relaxed);
+                        while let Err(actual) = atomic.compare_exchange_weak(
+                            current,
+                            current + 1,
+                            Ordering::Relaxed,
+                            Ordering::Relaxed,
+                        ) {
+                            current = actual;
+                        }
+                    }
+                }
+            }
+        }));
+
+        assert_eq!(atomic.load(Ordering::SeqCst), start + THREADS * ITERATIONS);
+    }
+
+    #[test]
+    fn operations() {
+        let mut atomic = AtomicU256::default();
+        assert_eq!(atomic.fetch_add(U256::MAX, Ordering::SeqCst), 0);
+        assert_eq!(atomic.fetch_add(U256::new(2), Ordering::SeqCst), U256::MAX);
+        assert_eq!(atomic.fetch_max(U256::ZERO, Ordering::SeqCst), 1);
+        assert_eq!(*atomic.get_mut(), 1);
+        assert_eq!(
+            atomic.compare_exchange(U256::ZERO, U256::MAX, Ordering::SeqCst, Ordering::SeqCst),
+            Err(U256::ONE)
+        );
+        assert_eq!(
+            atomic.compare_exchange(U256::ONE, U256::MAX, Ordering::SeqCst, Ordering::SeqCst),
+            Ok(U256::ONE)
+        );
+        assert_eq!(atomic.into_inner(), U256::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "there is no such thing as an acquire store")]
+    fn invalid_ordering() {
+        AtomicU256::default().store(U256::ONE, Ordering::Acquire);
+    }
+}
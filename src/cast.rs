@@ -0,0 +1,103 @@
+//! Module containing the target trait for generic narrowing casts.
+
+use crate::{I256, U256};
+
+pub(crate) mod sealed {
+    /// Sealing trait to prevent downstream implementations of
+    /// [`super::CastTarget`].
+    pub trait Sealed {}
+}
+
+/// Types that 256-bit integers can be narrowed to with
+/// [`U256::saturating_to`], [`U256::truncate_to`], [`I256::saturating_to`]
+/// and [`I256::truncate_to`].
+///
+/// This trait is sealed and implemented for all primitive integer types (and
+/// `cosmwasm_std::Uint128` when the `cosmos` feature is enabled).
+pub trait CastTarget: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    fn saturating_from_u256(value: U256) -> Self;
+    #[doc(hidden)]
+    fn saturating_from_i256(value: I256) -> Self;
+    #[doc(hidden)]
+    fn truncating_from_u256(value: U256) -> Self;
+    #[doc(hidden)]
+    fn truncating_from_i256(value: I256) -> Self;
+}
+
+macro_rules! impl_cast_target {
+    ($($t:ty),* $(,)?) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl CastTarget for $t {
+            #[inline]
+            fn saturating_from_u256(value: U256) -> Self {
+                <$t>::try_from(value).unwrap_or(<$t>::MAX)
+            }
+
+            #[inline]
+            fn saturating_from_i256(value: I256) -> Self {
+                <$t>::try_from(value).unwrap_or(if value.is_negative() {
+                    <$t>::MIN
+                } else {
+                    <$t>::MAX
+                })
+            }
+
+            #[inline]
+            fn truncating_from_u256(value: U256) -> Self {
+                value.as_u128() as _
+            }
+
+            #[inline]
+            fn truncating_from_i256(value: I256) -> Self {
+                value.as_u128() as _
+            }
+        }
+    )*};
+}
+
+impl_cast_target! {
+    i8, i16, i32, i64, i128,
+    u8, u16, u32, u64, u128,
+    isize, usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AsI256, AsU256};
+
+    macro_rules! assert_boundaries {
+        ($($t:ty),* $(,)?) => {$(
+            let max = <$t>::MAX;
+            let min = <$t>::MIN;
+
+            assert_eq!(max.as_u256().saturating_to::<$t>(), max);
+            assert_eq!((max.as_u256() + 1).saturating_to::<$t>(), max);
+            assert_eq!(U256::MAX.saturating_to::<$t>(), max);
+            assert_eq!(U256::ZERO.saturating_to::<$t>(), 0);
+
+            assert_eq!(max.as_i256().saturating_to::<$t>(), max);
+            assert_eq!((max.as_i256() + 1).saturating_to::<$t>(), max);
+            assert_eq!(min.as_i256().saturating_to::<$t>(), min);
+            assert_eq!((min.as_i256() - 1).saturating_to::<$t>(), min);
+            assert_eq!(I256::MIN.saturating_to::<$t>(), min);
+            assert_eq!(I256::MAX.saturating_to::<$t>(), max);
+
+            assert_eq!((max.as_u256() + 1).truncate_to::<$t>(), (max as u128).wrapping_add(1) as $t);
+            assert_eq!(U256::MAX.truncate_to::<$t>(), u128::MAX as $t);
+            assert_eq!(I256::MINUS_ONE.truncate_to::<$t>(), -1i128 as $t);
+            assert_eq!((min.as_i256() - 1).truncate_to::<$t>(), (min as i128).wrapping_sub(1) as $t);
+        )*};
+    }
+
+    #[test]
+    fn saturating_and_truncating_casts() {
+        assert_boundaries! {
+            i8, i16, i32, i64, i128,
+            u8, u16, u32, u64, u128,
+            isize, usize,
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Helpers for Cosmos SDK protobuf amount fields.
+//!
+//! Cosmos SDK messages encode big integer amounts (such as `cosmos.Int` and
+//! the `amount` of a `Coin`) as protobuf `string` fields containing a plain
+//! decimal number. This module provides strict parsing and rendering of those
+//! strings for the 256-bit integer types.
+//!
+//! Parsing only accepts ASCII digits, with a leading `-` for negative signed
+//! integers. In particular, empty strings, a leading `+`, whitespace and
+//! `_` separators are all rejected. Leading zeros are accepted, matching the
+//! Cosmos SDK's own parsing.
+//!
+//! The [`u256`] and [`i256`] modules contain free function wrappers around the
+//! parsing and rendering methods, which are convenient to point custom field
+//! mappings of generated `prost` messages at.
+//!
+//! # Examples
+//!
+//! ```
+//! # use ethnum::U256;
+//! let amount = U256::from_proto_string("1000000").unwrap();
+//! assert_eq!(amount, 1_000_000);
+//! assert_eq!(amount.to_proto_string(), "1000000");
+//!
+//! assert!(U256::from_proto_string("").is_err());
+//! assert!(U256::from_proto_string("+1").is_err());
+//! ```
+
+use crate::{error::pie, I256, U256};
+use alloc::string::{String, ToString as _};
+use core::{
+    fmt::{self, Write as _},
+    num::{IntErrorKind, ParseIntError},
+    str,
+};
+
+/// The buffer length needed to render any 256-bit integer as a protobuf
+/// amount string.
+pub const PROTO_STRING_LEN: usize = 78;
+
+/// Checks that `digits` is a non-empty string of ASCII digits.
+fn validate(digits: &str) -> Result<(), ParseIntError> {
+    if digits.is_empty() {
+        return Err(pie(IntErrorKind::Empty));
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(pie(IntErrorKind::InvalidDigit));
+    }
+    Ok(())
+}
+
+/// A formatter that writes into a fixed size stack buffer.
+struct BufferWriter<'a> {
+    buffer: &'a mut [u8; PROTO_STRING_LEN],
+    len: usize,
+}
+
+impl fmt::Write for BufferWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+macro_rules! impl_proto_string {
+    ($($t:ident => $m:ident),*) => {$(
+        impl $t {
+            /// Renders the integer as a protobuf amount string into a stack
+            /// buffer, returning the written string slice.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("# use ethnum::{", stringify!($t), ", cosmos_proto::PROTO_STRING_LEN};")]
+            /// let mut buffer = [0; PROTO_STRING_LEN];
+            #[doc = concat!("assert_eq!(", stringify!($t), "::new(42).to_proto_str(&mut buffer), \"42\");")]
+            /// ```
+            pub fn to_proto_str(self, buffer: &mut [u8; PROTO_STRING_LEN]) -> &str {
+                let mut writer = BufferWriter { buffer, len: 0 };
+                write!(writer, "{self}").expect("buffer too small for a 256-bit integer");
+                let len = writer.len;
+                // SAFETY: Only complete UTF-8 strings are copied into the
+                // buffer by the writer.
+                unsafe { str::from_utf8_unchecked(&buffer[..len]) }
+            }
+
+            /// Renders the integer as a protobuf amount string.
+            pub fn to_proto_string(self) -> String {
+                self.to_string()
+            }
+        }
+
+        /// Free function wrappers for
+        #[doc = concat!("[`", stringify!($t), "`]")]
+        /// protobuf amount strings.
+        pub mod $m {
+            use super::*;
+
+            /// Parses a protobuf amount string.
+            pub fn parse(s: &str) -> Result<$t, ParseIntError> {
+                $t::from_proto_string(s)
+            }
+
+            /// Renders a protobuf amount string.
+            pub fn format(value: &$t) -> String {
+                value.to_proto_string()
+            }
+        }
+    )*};
+}
+
+impl U256 {
+    /// Parses a protobuf amount string, consisting only of decimal digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is empty, contains anything other than
+    /// ASCII digits, or is out of range.
+    pub fn from_proto_string(s: &str) -> Result<Self, ParseIntError> {
+        validate(s)?;
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl I256 {
+    /// Parses a protobuf amount string, consisting only of decimal digits
+    /// with an optional leading `-` sign.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is empty, contains anything other than
+    /// ASCII digits after the optional sign, or is out of range.
+    pub fn from_proto_string(s: &str) -> Result<Self, ParseIntError> {
+        match s.strip_prefix('-') {
+            Some("") => return Err(pie(IntErrorKind::InvalidDigit)),
+            Some(digits) => validate(digits)?,
+            None => validate(s)?,
+        }
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl_proto_string!(U256 => u256, I256 => i256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_strictly() {
+        assert_eq!(U256::from_proto_string("0").unwrap(), U256::ZERO);
+        assert_eq!(U256::from_proto_string("00042").unwrap(), 42);
+        assert_eq!(
+            U256::from_proto_string(&U256::MAX.to_string()).unwrap(),
+            U256::MAX
+        );
+        assert_eq!(I256::from_proto_string("-42").unwrap(), -42);
+        assert_eq!(
+            I256::from_proto_string(&I256::MIN.to_string()).unwrap(),
+            I256::MIN
+        );
+
+        for (s, kind) in [
+            ("", IntErrorKind::Empty),
+            ("+1", IntErrorKind::InvalidDigit),
+            ("-1", IntErrorKind::InvalidDigit),
+            (" 1", IntErrorKind::InvalidDigit),
+            ("1_000", IntErrorKind::InvalidDigit),
+            ("0x1", IntErrorKind::InvalidDigit),
+            (
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+                IntErrorKind::PosOverflow,
+            ),
+        ] {
+            assert_eq!(U256::from_proto_string(s).unwrap_err(), pie(kind), "{s:?}");
+        }
+        for (s, kind) in [
+            ("", IntErrorKind::Empty),
+            ("-", IntErrorKind::InvalidDigit),
+            ("+1", IntErrorKind::InvalidDigit),
+            ("--1", IntErrorKind::InvalidDigit),
+            ("-+1", IntErrorKind::InvalidDigit),
+            (
+                "-57896044618658097711785492504343953926634992332820282019728792003956564819969",
+                IntErrorKind::NegOverflow,
+            ),
+        ] {
+            assert_eq!(I256::from_proto_string(s).unwrap_err(), pie(kind), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn renders() {
+        let mut buffer = [0; PROTO_STRING_LEN];
+        assert_eq!(U256::MAX.to_proto_str(&mut buffer), U256::MAX.to_string());
+        assert_eq!(I256::MIN.to_proto_str(&mut buffer), I256::MIN.to_string());
+        assert_eq!(I256::MINUS_ONE.to_proto_string(), "-1");
+
+        assert_eq!(u256::parse(&u256::format(&U256::MAX)).unwrap(), U256::MAX);
+        assert_eq!(i256::parse(&i256::format(&I256::MIN)).unwrap(), I256::MIN);
+    }
+}
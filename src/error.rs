@@ -2,6 +2,7 @@
 //! without public interfaces.
 
 use core::{
+    array::TryFromSliceError,
     mem,
     num::{IntErrorKind, ParseIntError, TryFromIntError},
 };
@@ -16,6 +17,11 @@ pub const fn tfie() -> TryFromIntError {
     unsafe { mem::transmute(()) }
 }
 
+/// Returns a `TryFromSliceError`.
+pub const fn tfse() -> TryFromSliceError {
+    unsafe { mem::transmute(()) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,4 +56,12 @@ mod tests {
     fn try_from_int_error() {
         assert_eq!(tfie(), u8::try_from(-1).unwrap_err());
     }
+
+    #[test]
+    fn try_from_slice_error() {
+        assert_eq!(
+            alloc::format!("{:?}", tfse()),
+            alloc::format!("{:?}", <[u8; 1]>::try_from([].as_slice()).unwrap_err()),
+        );
+    }
 }
@@ -0,0 +1,625 @@
+//! C ABI for the 256-bit integer operations.
+//!
+//! This module exports `extern "C"` functions, so the 256-bit arithmetic can be
+//! called from C and from generated code that calls into runtime helpers. A C
+//! header for these functions is generated with `cbindgen` when building with
+//! the `ffi` feature, and is written to `ethnum.h` in the build script's
+//! `OUT_DIR`.
+//!
+//! # ABI
+//!
+//! Integers are passed as pointers to [`FfiU256`] and [`FfiI256`] (named
+//! `ethnum_u256` and `ethnum_i256` in C), which are structs of four 64-bit
+//! limbs in little endian order: `limbs[0]` holds the least significant 64
+//! bits and `limbs[3]` the most significant. Signed integers use two's
+//! complement.
+//!
+//! Fallible functions return a `bool` flag, which is `true` if the operation
+//! overflowed or failed. Arithmetic results that overflow are still written,
+//! wrapped around at the boundary of the type, while outputs of operations
+//! that fail are left unchanged.
+//!
+//! # Safety
+//!
+//! All pointers passed to these functions must be non-null, properly aligned
+//! and valid for reads (or writes, for outputs) of the pointed-to type. Input
+//! and output pointers may alias.
+
+use crate::{I256, U256};
+use core::{
+    cmp::Ordering,
+    ffi::{c_char, CStr},
+    fmt::{self, Write as _},
+};
+
+#[cfg(feature = "builtins")]
+pub mod builtins;
+
+/// Maximum length of a decimal `ethnum_u256` string, including the trailing
+/// NUL character.
+pub const ETHNUM_U256_STR_LEN: usize = 79;
+
+/// Maximum length of a decimal `ethnum_i256` string, including the sign and
+/// the trailing NUL character.
+pub const ETHNUM_I256_STR_LEN: usize = 80;
+
+/// A 256-bit unsigned integer as four little endian 64-bit limbs.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct FfiU256 {
+    /// The limbs, least significant first.
+    pub limbs: [u64; 4],
+}
+
+/// A 256-bit signed two's complement integer as four little endian 64-bit
+/// limbs.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct FfiI256 {
+    /// The limbs, least significant first.
+    pub limbs: [u64; 4],
+}
+
+/// Converts between 256-bit integers and their C representation.
+trait Limbs: Copy {
+    type Value: Copy + Ord + fmt::Display;
+
+    fn get(self) -> Self::Value;
+    fn new(value: Self::Value) -> Self;
+}
+
+macro_rules! impl_limbs {
+    ($($ffi:ident => $t:ident),*) => {$(
+        impl Limbs for $ffi {
+            type Value = $t;
+
+            fn get(self) -> $t {
+                let mut bytes = [0; 32];
+                for (chunk, limb) in bytes.chunks_exact_mut(8).zip(self.limbs) {
+                    chunk.copy_from_slice(&limb.to_le_bytes());
+                }
+                $t::from_le_bytes(bytes)
+            }
+
+            fn new(value: $t) -> Self {
+                let bytes = value.to_le_bytes();
+                let mut limbs = [0; 4];
+                for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+                    *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+                }
+                Self { limbs }
+            }
+        }
+
+        impl From<$t> for $ffi {
+            fn from(value: $t) -> Self {
+                Limbs::new(value)
+            }
+        }
+
+        impl From<$ffi> for $t {
+            fn from(value: $ffi) -> Self {
+                value.get()
+            }
+        }
+    )*};
+}
+
+impl_limbs!(FfiU256 => U256, FfiI256 => I256);
+
+/// An overflowing operation, such as [`U256::overflowing_add`].
+type Overflowing<T, U> = fn(T, U) -> (T, bool);
+
+unsafe fn binop<T: Limbs>(
+    a: *const T,
+    b: *const T,
+    out: *mut T,
+    op: Overflowing<T::Value, T::Value>,
+) -> bool {
+    let (result, overflow) = op((*a).get(), (*b).get());
+    *out = T::new(result);
+    overflow
+}
+
+unsafe fn shift<T: Limbs>(
+    a: *const T,
+    rhs: u32,
+    out: *mut T,
+    op: Overflowing<T::Value, u32>,
+) -> bool {
+    let (result, overflow) = op((*a).get(), rhs);
+    *out = T::new(result);
+    overflow
+}
+
+unsafe fn cmp<T: Limbs>(a: *const T, b: *const T) -> i32 {
+    match (*a).get().cmp(&(*b).get()) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+/// A formatter that writes into a fixed size buffer, reserving space for a
+/// trailing NUL character.
+struct CStrWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for CStrWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end >= self.buffer.len() {
+            return Err(fmt::Error);
+        }
+        self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+unsafe fn to_string<T: Limbs>(a: *const T, buf: *mut c_char, len: usize) -> bool {
+    if len == 0 {
+        return true;
+    }
+    let buffer = core::slice::from_raw_parts_mut(buf.cast::<u8>(), len);
+    let mut writer = CStrWriter { buffer, len: 0 };
+    let failed = write!(writer, "{}", (*a).get()).is_err();
+    let end = if failed { 0 } else { writer.len };
+    writer.buffer[end] = 0;
+    failed
+}
+
+unsafe fn from_string<T: Limbs>(
+    s: *const c_char,
+    out: *mut T,
+    parse: fn(&str, u32) -> Result<T::Value, core::num::ParseIntError>,
+) -> bool {
+    match CStr::from_ptr(s).to_str().map(|s| parse(s, 10)) {
+        Ok(Ok(value)) => {
+            *out = T::new(value);
+            false
+        }
+        _ => true,
+    }
+}
+
+/// Computes `*out = a + b`, returning `true` on overflow.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_add(
+    a: *const FfiU256,
+    b: *const FfiU256,
+    out: *mut FfiU256,
+) -> bool {
+    binop(a, b, out, U256::overflowing_add)
+}
+
+/// Computes `*out = a - b`, returning `true` on overflow.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_sub(
+    a: *const FfiU256,
+    b: *const FfiU256,
+    out: *mut FfiU256,
+) -> bool {
+    binop(a, b, out, U256::overflowing_sub)
+}
+
+/// Computes `*out = a * b`, returning `true` on overflow.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_mul(
+    a: *const FfiU256,
+    b: *const FfiU256,
+    out: *mut FfiU256,
+) -> bool {
+    binop(a, b, out, U256::overflowing_mul)
+}
+
+/// Computes `*quot = a / b` and `*rem = a % b`, returning `true` if `b` is
+/// zero.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_divmod(
+    a: *const FfiU256,
+    b: *const FfiU256,
+    quot: *mut FfiU256,
+    rem: *mut FfiU256,
+) -> bool {
+    let (a, b) = ((*a).get(), (*b).get());
+    if b == U256::ZERO {
+        return true;
+    }
+    *quot = FfiU256::new(a / b);
+    *rem = FfiU256::new(a % b);
+    false
+}
+
+/// Compares `a` and `b`, returning `-1`, `0` or `1` if `a` is less than,
+/// equal to or greater than `b`.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_cmp(a: *const FfiU256, b: *const FfiU256) -> i32 {
+    cmp(a, b)
+}
+
+/// Computes `*out = a << (shift % 256)`, returning `true` if `shift` is
+/// 256 or more.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_shl(a: *const FfiU256, shift: u32, out: *mut FfiU256) -> bool {
+    self::shift(a, shift, out, U256::overflowing_shl)
+}
+
+/// Computes `*out = a >> (shift % 256)`, returning `true` if `shift` is
+/// 256 or more.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_shr(a: *const FfiU256, shift: u32, out: *mut FfiU256) -> bool {
+    self::shift(a, shift, out, U256::overflowing_shr)
+}
+
+/// Writes `a` as a NUL-terminated decimal string to the `len` byte buffer
+/// `buf`, returning `true` if the buffer is too small. A buffer of
+/// [`ETHNUM_U256_STR_LEN`] bytes is large enough for any value. On failure,
+/// an empty string is written if `len` is not zero.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety). `buf` must be valid for
+/// writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_to_string(
+    a: *const FfiU256,
+    buf: *mut c_char,
+    len: usize,
+) -> bool {
+    to_string(a, buf, len)
+}
+
+/// Parses the NUL-terminated decimal string `s` into `*out`, returning `true`
+/// if it is not a valid integer or is out of range.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety). `s` must point to a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_u256_from_string(s: *const c_char, out: *mut FfiU256) -> bool {
+    from_string(s, out, U256::from_str_radix)
+}
+
+/// Computes `*out = a + b`, returning `true` on overflow.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_add(
+    a: *const FfiI256,
+    b: *const FfiI256,
+    out: *mut FfiI256,
+) -> bool {
+    binop(a, b, out, I256::overflowing_add)
+}
+
+/// Computes `*out = a - b`, returning `true` on overflow.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_sub(
+    a: *const FfiI256,
+    b: *const FfiI256,
+    out: *mut FfiI256,
+) -> bool {
+    binop(a, b, out, I256::overflowing_sub)
+}
+
+/// Computes `*out = a * b`, returning `true` on overflow.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_mul(
+    a: *const FfiI256,
+    b: *const FfiI256,
+    out: *mut FfiI256,
+) -> bool {
+    binop(a, b, out, I256::overflowing_mul)
+}
+
+/// Computes `*quot = a / b` and `*rem = a % b`, rounding towards zero. Returns
+/// `true` if `b` is zero, leaving the outputs unchanged, or if the division
+/// overflows (`MIN / -1`), in which case `*quot = MIN` and `*rem = 0`.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_divmod(
+    a: *const FfiI256,
+    b: *const FfiI256,
+    quot: *mut FfiI256,
+    rem: *mut FfiI256,
+) -> bool {
+    let (a, b) = ((*a).get(), (*b).get());
+    if b == I256::ZERO {
+        return true;
+    }
+    let (q, overflow) = a.overflowing_div(b);
+    *quot = FfiI256::new(q);
+    *rem = FfiI256::new(a.overflowing_rem(b).0);
+    overflow
+}
+
+/// Compares `a` and `b`, returning `-1`, `0` or `1` if `a` is less than,
+/// equal to or greater than `b`.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_cmp(a: *const FfiI256, b: *const FfiI256) -> i32 {
+    cmp(a, b)
+}
+
+/// Computes `*out = a << (shift % 256)`, returning `true` if `shift` is
+/// 256 or more.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_shl(a: *const FfiI256, shift: u32, out: *mut FfiI256) -> bool {
+    self::shift(a, shift, out, I256::overflowing_shl)
+}
+
+/// Computes the arithmetic shift `*out = a >> (shift % 256)`, returning
+/// `true` if `shift` is 256 or more.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety).
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_shr(a: *const FfiI256, shift: u32, out: *mut FfiI256) -> bool {
+    self::shift(a, shift, out, I256::overflowing_shr)
+}
+
+/// Writes `a` as a NUL-terminated decimal string to the `len` byte buffer
+/// `buf`, returning `true` if the buffer is too small. A buffer of
+/// [`ETHNUM_I256_STR_LEN`] bytes is large enough for any value. On failure,
+/// an empty string is written if `len` is not zero.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety). `buf` must be valid for
+/// writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_to_string(
+    a: *const FfiI256,
+    buf: *mut c_char,
+    len: usize,
+) -> bool {
+    to_string(a, buf, len)
+}
+
+/// Parses the NUL-terminated decimal string `s` into `*out`, returning `true`
+/// if it is not a valid integer or is out of range.
+///
+/// # Safety
+///
+/// See the [module documentation](self#safety). `s` must point to a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ethnum_i256_from_string(s: *const c_char, out: *mut FfiI256) -> bool {
+    from_string(s, out, I256::from_str_radix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn u(value: U256) -> FfiU256 {
+        value.into()
+    }
+
+    fn i(value: I256) -> FfiI256 {
+        value.into()
+    }
+
+    #[test]
+    fn limbs_are_little_endian() {
+        let value = U256::from_words(0x0004_0000_0000_0000_0003, 0x0002_0000_0000_0000_0001);
+        assert_eq!(u(value).limbs, [1, 2, 3, 4]);
+        assert_eq!(
+            U256::from(FfiU256 {
+                limbs: [1, 2, 3, 4]
+            }),
+            value
+        );
+        assert_eq!(i(I256::MINUS_ONE).limbs, [u64::MAX; 4]);
+        assert_eq!(i(I256::MIN).limbs, [0, 0, 0, 1 << 63]);
+    }
+
+    #[test]
+    fn unsigned_arithmetic() {
+        let values = [
+            U256::ZERO,
+            U256::ONE,
+            U256::new(u64::MAX as u128),
+            U256::from_words(1, 2),
+            U256::MAX / 3,
+            U256::MAX,
+        ];
+        for a in values {
+            for b in values {
+                let mut out = FfiU256::default();
+                unsafe {
+                    let overflow = ethnum_u256_add(&u(a), &u(b), &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_add(b));
+                    let overflow = ethnum_u256_sub(&u(a), &u(b), &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_sub(b));
+                    let overflow = ethnum_u256_mul(&u(a), &u(b), &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_mul(b));
+                    assert_eq!(ethnum_u256_cmp(&u(a), &u(b)), a.cmp(&b) as i32);
+
+                    let (mut quot, mut rem) = (FfiU256::default(), FfiU256::default());
+                    let failed = ethnum_u256_divmod(&u(a), &u(b), &mut quot, &mut rem);
+                    assert_eq!(failed, b == 0);
+                    if !failed {
+                        assert_eq!((quot.get(), rem.get()), (a / b, a % b));
+                    }
+                }
+            }
+            for shift in [0, 1, 64, 255, 256, 300] {
+                let mut out = FfiU256::default();
+                unsafe {
+                    let overflow = ethnum_u256_shl(&u(a), shift, &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_shl(shift));
+                    let overflow = ethnum_u256_shr(&u(a), shift, &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_shr(shift));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn signed_arithmetic() {
+        let values = [
+            I256::MIN,
+            I256::new(-42),
+            I256::MINUS_ONE,
+            I256::ZERO,
+            I256::ONE,
+            I256::from_words(1, 2),
+            I256::MAX,
+        ];
+        for a in values {
+            for b in values {
+                let mut out = FfiI256::default();
+                unsafe {
+                    let overflow = ethnum_i256_add(&i(a), &i(b), &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_add(b));
+                    let overflow = ethnum_i256_sub(&i(a), &i(b), &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_sub(b));
+                    let overflow = ethnum_i256_mul(&i(a), &i(b), &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_mul(b));
+                    assert_eq!(ethnum_i256_cmp(&i(a), &i(b)), a.cmp(&b) as i32);
+
+                    let (mut quot, mut rem) = (FfiI256::default(), FfiI256::default());
+                    let failed = ethnum_i256_divmod(&i(a), &i(b), &mut quot, &mut rem);
+                    if b == 0 {
+                        assert!(failed);
+                    } else {
+                        let (q, overflow) = a.overflowing_div(b);
+                        assert_eq!(
+                            (quot.get(), rem.get(), failed),
+                            (q, a.overflowing_rem(b).0, overflow)
+                        );
+                    }
+                }
+            }
+            for shift in [0, 1, 128, 255, 256] {
+                let mut out = FfiI256::default();
+                unsafe {
+                    let overflow = ethnum_i256_shl(&i(a), shift, &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_shl(shift));
+                    let overflow = ethnum_i256_shr(&i(a), shift, &mut out);
+                    assert_eq!((out.get(), overflow), a.overflowing_shr(shift));
+                }
+            }
+        }
+
+        let (mut quot, mut rem) = (i(I256::ONE), i(I256::ONE));
+        unsafe {
+            assert!(ethnum_i256_divmod(
+                &i(I256::MIN),
+                &i(I256::MINUS_ONE),
+                &mut quot,
+                &mut rem
+            ));
+        }
+        assert_eq!((quot.get(), rem.get()), (I256::MIN, I256::ZERO));
+    }
+
+    #[test]
+    fn strings() {
+        let mut buf = [0x55 as c_char; ETHNUM_I256_STR_LEN];
+        unsafe {
+            assert!(!ethnum_u256_to_string(
+                &u(U256::MAX),
+                buf.as_mut_ptr(),
+                ETHNUM_U256_STR_LEN
+            ));
+            let s = CStr::from_ptr(buf.as_ptr());
+            assert_eq!(s.to_str().unwrap(), U256::MAX.to_string());
+
+            let mut out = FfiU256::default();
+            assert!(!ethnum_u256_from_string(s.as_ptr(), &mut out));
+            assert_eq!(out.get(), U256::MAX);
+
+            assert!(!ethnum_i256_to_string(
+                &i(I256::MIN),
+                buf.as_mut_ptr(),
+                buf.len()
+            ));
+            let s = CStr::from_ptr(buf.as_ptr());
+            assert_eq!(s.to_str().unwrap(), I256::MIN.to_string());
+
+            let mut out = FfiI256::default();
+            assert!(!ethnum_i256_from_string(s.as_ptr(), &mut out));
+            assert_eq!(out.get(), I256::MIN);
+
+            // Too small buffers write an empty string.
+            assert!(ethnum_u256_to_string(
+                &u(U256::MAX),
+                buf.as_mut_ptr(),
+                ETHNUM_U256_STR_LEN - 1
+            ));
+            assert_eq!(buf[0], 0);
+            assert!(ethnum_u256_to_string(&u(U256::ZERO), buf.as_mut_ptr(), 1));
+            assert!(!ethnum_u256_to_string(&u(U256::ZERO), buf.as_mut_ptr(), 2));
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_bytes(), b"0");
+            assert!(ethnum_u256_to_string(&u(U256::ZERO), buf.as_mut_ptr(), 0));
+
+            let mut out = FfiU256 { limbs: [7; 4] };
+            for s in [
+                c"",
+                c"-1",
+                c"0x10",
+                c"1.5",
+                c"115792089237316195423570985008687907853269984665640564039457584007913129639936",
+            ] {
+                assert!(ethnum_u256_from_string(s.as_ptr(), &mut out));
+            }
+            assert_eq!(out.limbs, [7; 4]);
+        }
+    }
+}
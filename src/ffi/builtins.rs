@@ -0,0 +1,361 @@
+//! Compiler runtime helpers for 256-bit integers.
+//!
+//! This module exports the 256-bit integer operations under the names that
+//! compiler-rt and libgcc would use for them, with the `oi` suffix of GCC's
+//! 256-bit `OImode` in place of the `ti` suffix of their 128-bit helpers
+//! (`__ashloi3` instead of `__ashlti3`, `__udivoi3` instead of `__udivti3`,
+//! and so on). This allows code generated with native 256-bit integer support,
+//! for example by a JIT, to call them as runtime helpers. The symbols are only
+//! exported with the `builtins` feature, since they could otherwise collide
+//! with a compiler runtime that provides them.
+//!
+//! # ABI
+//!
+//! Integers are passed and returned by value as [`FfiU256`] and [`FfiI256`],
+//! which have the same layout as C's `unsigned _BitInt(256)` and
+//! `_BitInt(256)`, and are passed the same way by the x86-64 System V and
+//! AArch64 calling conventions. The signedness of the types follows the
+//! `tu_int` and `ti_int` types of the corresponding compiler-rt helpers.
+//!
+//! Where the compiler-rt helpers have undefined behaviour, these functions
+//! are defined as follows:
+//! - Shifts only use the low 8 bits of the shift amount.
+//! - Signed division and multiplication wrap around on overflow.
+//! - Division by zero aborts the process, like the trap of a hardware division
+//!   instruction.
+//! - Float to integer conversions truncate towards zero and saturate, like
+//!   Rust's `as` casts.
+
+use super::{FfiI256, FfiU256, Limbs as _};
+use crate::{AsI256 as _, AsU256 as _};
+use core::cmp::Ordering;
+
+/// Converts an [`Ordering`] to the result of the compiler-rt comparison
+/// helpers, which is `0`, `1` or `2` for less than, equal and greater than.
+fn cmp2(ordering: Ordering) -> i32 {
+    ordering as i32 + 1
+}
+
+/// Computes `a * b`, wrapping around on overflow.
+#[no_mangle]
+pub extern "C" fn __muloi3(a: FfiI256, b: FfiI256) -> FfiI256 {
+    FfiI256::new(a.get().wrapping_mul(b.get()))
+}
+
+/// Computes the signed product `a * b`, setting `*overflow` to `1` if it
+/// overflowed and to `0` otherwise.
+///
+/// # Safety
+///
+/// `overflow` must be non-null, properly aligned and valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn __mulooi4(a: FfiI256, b: FfiI256, overflow: *mut i32) -> FfiI256 {
+    let (result, o) = a.get().overflowing_mul(b.get());
+    *overflow = o as i32;
+    FfiI256::new(result)
+}
+
+/// Computes the unsigned quotient `a / b`.
+#[no_mangle]
+pub extern "C" fn __udivoi3(a: FfiU256, b: FfiU256) -> FfiU256 {
+    FfiU256::new(a.get() / b.get())
+}
+
+/// Computes the unsigned remainder `a % b`.
+#[no_mangle]
+pub extern "C" fn __umodoi3(a: FfiU256, b: FfiU256) -> FfiU256 {
+    FfiU256::new(a.get() % b.get())
+}
+
+/// Computes the unsigned quotient `a / b`, writing the remainder to `*rem` if
+/// it is not null.
+///
+/// # Safety
+///
+/// `rem` must either be null, or be properly aligned and valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn __udivmodoi4(a: FfiU256, b: FfiU256, rem: *mut FfiU256) -> FfiU256 {
+    let (a, b) = (a.get(), b.get());
+    let quot = a / b;
+    if !rem.is_null() {
+        *rem = FfiU256::new(a % b);
+    }
+    FfiU256::new(quot)
+}
+
+/// Computes the signed quotient `a / b`, rounding towards zero.
+#[no_mangle]
+pub extern "C" fn __divoi3(a: FfiI256, b: FfiI256) -> FfiI256 {
+    FfiI256::new(a.get().wrapping_div(b.get()))
+}
+
+/// Computes the signed remainder `a % b`, with the sign of `a`.
+#[no_mangle]
+pub extern "C" fn __modoi3(a: FfiI256, b: FfiI256) -> FfiI256 {
+    FfiI256::new(a.get().wrapping_rem(b.get()))
+}
+
+/// Computes the signed quotient `a / b`, rounding towards zero and writing the
+/// remainder to `*rem`.
+///
+/// # Safety
+///
+/// `rem` must be non-null, properly aligned and valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn __divmodoi4(a: FfiI256, b: FfiI256, rem: *mut FfiI256) -> FfiI256 {
+    let (a, b) = (a.get(), b.get());
+    let quot = a.wrapping_div(b);
+    *rem = FfiI256::new(a.wrapping_rem(b));
+    FfiI256::new(quot)
+}
+
+/// Computes the left shift `a << b`.
+#[no_mangle]
+pub extern "C" fn __ashloi3(a: FfiI256, b: i32) -> FfiI256 {
+    FfiI256::new(a.get().wrapping_shl(b as u32))
+}
+
+/// Computes the logical right shift `a >> b`, shifting in zeros.
+#[no_mangle]
+pub extern "C" fn __lshroi3(a: FfiI256, b: i32) -> FfiI256 {
+    FfiI256::new(a.get().as_u256().wrapping_shr(b as u32).as_i256())
+}
+
+/// Computes the arithmetic right shift `a >> b`, shifting in the sign bit.
+#[no_mangle]
+pub extern "C" fn __ashroi3(a: FfiI256, b: i32) -> FfiI256 {
+    FfiI256::new(a.get().wrapping_shr(b as u32))
+}
+
+/// Compares the signed integers `a` and `b`, returning `0`, `1` or `2` if `a`
+/// is less than, equal to or greater than `b`.
+#[no_mangle]
+pub extern "C" fn __cmpoi2(a: FfiI256, b: FfiI256) -> i32 {
+    cmp2(a.get().cmp(&b.get()))
+}
+
+/// Compares the unsigned integers `a` and `b`, returning `0`, `1` or `2` if
+/// `a` is less than, equal to or greater than `b`.
+#[no_mangle]
+pub extern "C" fn __ucmpoi2(a: FfiU256, b: FfiU256) -> i32 {
+    cmp2(a.get().cmp(&b.get()))
+}
+
+/// Converts a signed integer to the nearest `f32`.
+#[no_mangle]
+pub extern "C" fn __floatoisf(a: FfiI256) -> f32 {
+    a.get().as_f32()
+}
+
+/// Converts a signed integer to the nearest `f64`.
+#[no_mangle]
+pub extern "C" fn __floatoidf(a: FfiI256) -> f64 {
+    a.get().as_f64()
+}
+
+/// Converts an unsigned integer to the nearest `f32`.
+#[no_mangle]
+pub extern "C" fn __floatunoisf(a: FfiU256) -> f32 {
+    a.get().as_f32()
+}
+
+/// Converts an unsigned integer to the nearest `f64`.
+#[no_mangle]
+pub extern "C" fn __floatunoidf(a: FfiU256) -> f64 {
+    a.get().as_f64()
+}
+
+/// Converts an `f32` to a signed integer.
+#[no_mangle]
+pub extern "C" fn __fixsfoi(a: f32) -> FfiI256 {
+    FfiI256::new(a.as_i256())
+}
+
+/// Converts an `f64` to a signed integer.
+#[no_mangle]
+pub extern "C" fn __fixdfoi(a: f64) -> FfiI256 {
+    FfiI256::new(a.as_i256())
+}
+
+/// Converts an `f32` to an unsigned integer.
+#[no_mangle]
+pub extern "C" fn __fixunssfoi(a: f32) -> FfiU256 {
+    FfiU256::new(a.as_u256())
+}
+
+/// Converts an `f64` to an unsigned integer.
+#[no_mangle]
+pub extern "C" fn __fixunsdfoi(a: f64) -> FfiU256 {
+    FfiU256::new(a.as_u256())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I256, U256};
+
+    /// Declarations of the exported symbols, so that the tests call them
+    /// through the C ABI by name.
+    mod sys {
+        use super::{FfiI256, FfiU256};
+
+        extern "C" {
+            pub fn __muloi3(a: FfiI256, b: FfiI256) -> FfiI256;
+            pub fn __mulooi4(a: FfiI256, b: FfiI256, overflow: *mut i32) -> FfiI256;
+            pub fn __udivoi3(a: FfiU256, b: FfiU256) -> FfiU256;
+            pub fn __umodoi3(a: FfiU256, b: FfiU256) -> FfiU256;
+            pub fn __udivmodoi4(a: FfiU256, b: FfiU256, rem: *mut FfiU256) -> FfiU256;
+            pub fn __divoi3(a: FfiI256, b: FfiI256) -> FfiI256;
+            pub fn __modoi3(a: FfiI256, b: FfiI256) -> FfiI256;
+            pub fn __divmodoi4(a: FfiI256, b: FfiI256, rem: *mut FfiI256) -> FfiI256;
+            pub fn __ashloi3(a: FfiI256, b: i32) -> FfiI256;
+            pub fn __lshroi3(a: FfiI256, b: i32) -> FfiI256;
+            pub fn __ashroi3(a: FfiI256, b: i32) -> FfiI256;
+            pub fn __cmpoi2(a: FfiI256, b: FfiI256) -> i32;
+            pub fn __ucmpoi2(a: FfiU256, b: FfiU256) -> i32;
+            pub fn __floatoisf(a: FfiI256) -> f32;
+            pub fn __floatoidf(a: FfiI256) -> f64;
+            pub fn __floatunoisf(a: FfiU256) -> f32;
+            pub fn __floatunoidf(a: FfiU256) -> f64;
+            pub fn __fixsfoi(a: f32) -> FfiI256;
+            pub fn __fixdfoi(a: f64) -> FfiI256;
+            pub fn __fixunssfoi(a: f32) -> FfiU256;
+            pub fn __fixunsdfoi(a: f64) -> FfiU256;
+        }
+    }
+
+    fn u(value: U256) -> FfiU256 {
+        value.into()
+    }
+
+    fn i(value: I256) -> FfiI256 {
+        value.into()
+    }
+
+    const SIGNED: [I256; 8] = [
+        I256::MIN,
+        I256::from_words(-2, 42),
+        I256::new(-42),
+        I256::MINUS_ONE,
+        I256::ZERO,
+        I256::ONE,
+        I256::from_words(1, 2),
+        I256::MAX,
+    ];
+
+    #[test]
+    fn multiplication() {
+        for a in SIGNED {
+            for b in SIGNED {
+                let mut overflow = -1;
+                unsafe {
+                    assert_eq!(sys::__muloi3(i(a), i(b)).get(), a.wrapping_mul(b));
+                    let result = sys::__mulooi4(i(a), i(b), &mut overflow).get();
+                    assert_eq!((result, overflow != 0), a.overflowing_mul(b));
+                }
+                assert!(overflow == 0 || overflow == 1);
+
+                // Wrapping multiplication is the same for unsigned integers.
+                let (a, b) = (a.as_u256(), b.as_u256());
+                unsafe {
+                    assert_eq!(
+                        sys::__muloi3(i(a.as_i256()), i(b.as_i256())).get(),
+                        a.wrapping_mul(b).as_i256()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn division() {
+        for a in SIGNED {
+            for b in SIGNED.into_iter().filter(|b| *b != 0) {
+                let mut rem = i(I256::ZERO);
+                unsafe {
+                    assert_eq!(sys::__divoi3(i(a), i(b)).get(), a.wrapping_div(b));
+                    assert_eq!(sys::__modoi3(i(a), i(b)).get(), a.wrapping_rem(b));
+                    let quot = sys::__divmodoi4(i(a), i(b), &mut rem).get();
+                    assert_eq!((quot, rem.get()), (a.wrapping_div(b), a.wrapping_rem(b)));
+                }
+
+                let (a, b) = (a.as_u256(), b.as_u256());
+                let mut rem = u(U256::ZERO);
+                unsafe {
+                    assert_eq!(sys::__udivoi3(u(a), u(b)).get(), a / b);
+                    assert_eq!(sys::__umodoi3(u(a), u(b)).get(), a % b);
+                    let quot = sys::__udivmodoi4(u(a), u(b), &mut rem).get();
+                    assert_eq!((quot, rem.get()), (a / b, a % b));
+                    let quot = sys::__udivmodoi4(u(a), u(b), core::ptr::null_mut()).get();
+                    assert_eq!(quot, a / b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shifts() {
+        for a in SIGNED {
+            for b in [0, 1, 64, 127, 128, 255, 256, 300, -1] {
+                unsafe {
+                    assert_eq!(sys::__ashloi3(i(a), b).get(), a.wrapping_shl(b as u32));
+                    assert_eq!(sys::__ashroi3(i(a), b).get(), a.wrapping_shr(b as u32));
+                    assert_eq!(
+                        sys::__lshroi3(i(a), b).get(),
+                        a.as_u256().wrapping_shr(b as u32).as_i256()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn comparisons() {
+        for a in SIGNED {
+            for b in SIGNED {
+                unsafe {
+                    assert_eq!(sys::__cmpoi2(i(a), i(b)), a.cmp(&b) as i32 + 1);
+                    let (a, b) = (a.as_u256(), b.as_u256());
+                    assert_eq!(sys::__ucmpoi2(u(a), u(b)), a.cmp(&b) as i32 + 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        for a in SIGNED {
+            unsafe {
+                assert_eq!(sys::__floatoisf(i(a)), a.as_f32());
+                assert_eq!(sys::__floatoidf(i(a)), a.as_f64());
+                assert_eq!(sys::__floatunoisf(u(a.as_u256())), a.as_u256().as_f32());
+                assert_eq!(sys::__floatunoidf(u(a.as_u256())), a.as_u256().as_f64());
+            }
+        }
+
+        for f in [
+            0.0,
+            -0.0,
+            0.5,
+            -1.5,
+            42.0,
+            1e18,
+            -1e38,
+            1e77,
+            -1e77,
+            1e78,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ] {
+            unsafe {
+                assert_eq!(sys::__fixdfoi(f).get(), f.as_i256());
+                assert_eq!(sys::__fixunsdfoi(f).get(), f.as_u256());
+                assert_eq!(sys::__fixsfoi(f as f32).get(), (f as f32).as_i256());
+                assert_eq!(sys::__fixunssfoi(f as f32).get(), (f as f32).as_u256());
+            }
+        }
+        assert_eq!(__fixdfoi(-1.5).get(), -1);
+        assert_eq!(__fixunsdfoi(1e78).get(), U256::MAX);
+    }
+}
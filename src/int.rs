@@ -8,22 +8,25 @@ mod iter;
 mod ops;
 mod parse;
 
-pub use self::convert::AsI256;
-use crate::uint::U256;
-use core::num::ParseIntError;
+pub use self::convert::{AsI256, TryAsI256};
+use crate::{
+    error::{tfie, tfse},
+    uint::U256,
+    CastTarget,
+};
+use core::{
+    array::TryFromSliceError,
+    num::{ParseIntError, TryFromIntError},
+};
 
 /// A 256-bit signed integer type.
-#[derive(
-    Clone,
-    Copy,
-    Default,
-    Eq,
-    Hash,
-    PartialEq,
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Numeric),
+    diesel(sql_type = diesel::sql_types::Binary)
 )]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(transparent)]
 pub struct I256(pub [i128; 2]);
 
@@ -71,6 +74,53 @@ impl I256 {
         }
     }
 
+    /// Creates a new 256-bit integer value from 64-bit limbs of its two's
+    /// complement representation.
+    ///
+    /// The limbs are in little endian order, that is, `limbs[0]` is the least
+    /// significant limb and `limbs[3]` the most significant one, regardless of
+    /// the target platform's endianness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::from_limbs([2, 0, 0, 0]), 2);
+    /// assert_eq!(I256::from_limbs([u64::MAX - 1, u64::MAX, u64::MAX, u64::MAX]), -2);
+    /// ```
+    #[inline]
+    pub const fn from_limbs(limbs: [u64; 4]) -> Self {
+        U256::from_limbs(limbs).as_i256()
+    }
+
+    /// Splits a 256-bit integer into 64-bit limbs of its two's complement
+    /// representation.
+    ///
+    /// The limbs are in little endian order, that is, the first limb is the
+    /// least significant one, regardless of the target platform's endianness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::new(42).into_limbs(), [42, 0, 0, 0]);
+    /// assert_eq!(I256::MINUS_ONE.into_limbs(), [u64::MAX; 4]);
+    /// assert_eq!(I256::MIN.into_limbs(), [0, 0, 0, 1 << 63]);
+    /// ```
+    #[inline]
+    pub const fn into_limbs(self) -> [u64; 4] {
+        self.as_u256().into_limbs()
+    }
+
+    /// Returns the 64-bit limbs of a 256-bit integer's two's complement
+    /// representation in little endian order.
+    ///
+    /// See [`I256::into_limbs`] for more details.
+    #[inline]
+    pub const fn as_limbs(&self) -> [u64; 4] {
+        self.into_limbs()
+    }
+
     /// Get the low 128-bit word for this signed integer.
     #[inline]
     pub fn low(&self) -> &i128 {
@@ -167,6 +217,240 @@ impl I256 {
         crate::parse::from_str_prefixed(src)
     }
 
+    /// Converts a string slice in base 16 to an integer, with an optional sign
+    /// followed by an optional `0x` or `0X` prefix.
+    ///
+    /// This is a more lenient version of [`I256::from_str_hex`] that accepts
+    /// the output of both `I256::to_hex_string` and unprefixed hexadecimal
+    /// strings in either case. Note that negative values are expected in sign
+    /// and magnitude form, and not as two's complement.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::from_hex("-0x2a"), Ok(I256::new(-42)));
+    /// assert_eq!(I256::from_hex("0X2A"), Ok(I256::new(42)));
+    /// assert_eq!(I256::from_hex("-2a"), Ok(I256::new(-42)));
+    /// assert!(I256::from_hex("0x-2a").is_err());
+    /// ```
+    pub fn from_hex(src: &str) -> Result<Self, ParseIntError> {
+        crate::parse::from_hex(src)
+    }
+
+    /// Returns the `0x`-prefixed lowercase hexadecimal representation of this
+    /// integer without any leading zeros.
+    ///
+    /// Negative values are rendered in sign and magnitude form as `-0x...`
+    /// (and not in two's complement like the `{:x}` format), so that the
+    /// output round-trips through [`I256::from_hex`] and
+    /// [`I256::from_str_hex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::new(42).to_hex_string(), "0x2a");
+    /// assert_eq!(I256::new(-42).to_hex_string(), "-0x2a");
+    /// assert_eq!(I256::ZERO.to_hex_string(), "0x0");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(&self) -> alloc::string::String {
+        let sign = if self.is_negative() { "-" } else { "" };
+        alloc::format!("{sign}{:#x}", self.unsigned_abs())
+    }
+
+    /// Returns the `0x`-prefixed lowercase hexadecimal representation of this
+    /// integer, with the magnitude zero-padded to the full 64 nibbles.
+    ///
+    /// Like [`I256::to_hex_string`], negative values are rendered in sign and
+    /// magnitude form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(
+    ///     I256::new(-42).to_hex_string_padded(),
+    ///     "-0x000000000000000000000000000000000000000000000000000000000000002a",
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string_padded(&self) -> alloc::string::String {
+        let sign = if self.is_negative() { "-" } else { "" };
+        alloc::format!("{sign}{:#066x}", self.unsigned_abs())
+    }
+
+    /// Writes the memory representation of this integer as a byte array in
+    /// big endian (network) byte order directly into a buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// let mut buf = [0; 32];
+    /// I256::new(-2).to_be_bytes_into(&mut buf);
+    /// assert_eq!(buf[30..], [0xff, 0xfe]);
+    /// assert_eq!(buf, I256::new(-2).to_be_bytes());
+    /// ```
+    #[inline]
+    pub fn to_be_bytes_into(&self, buf: &mut [u8; 32]) {
+        self.as_u256().to_be_bytes_into(buf)
+    }
+
+    /// Writes the memory representation of this integer as a byte array in
+    /// little endian byte order directly into a buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// let mut buf = [0; 32];
+    /// I256::new(-2).to_le_bytes_into(&mut buf);
+    /// assert_eq!(buf[..2], [0xfe, 0xff]);
+    /// assert_eq!(buf, I256::new(-2).to_le_bytes());
+    /// ```
+    #[inline]
+    pub fn to_le_bytes_into(&self, buf: &mut [u8; 32]) {
+        self.as_u256().to_le_bytes_into(buf)
+    }
+
+    /// Writes the big endian two's complement representation of this integer
+    /// into a buffer and returns the minimal-length suffix with all redundant
+    /// sign extension bytes stripped.
+    ///
+    /// The most significant bit of the returned bytes is always the sign bit,
+    /// so positive values may require an extra leading zero byte. Note that
+    /// zero is encoded as an empty slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// let mut buf = [0; 32];
+    /// assert_eq!(I256::new(127).to_be_bytes_trimmed(&mut buf), [0x7f]);
+    /// assert_eq!(I256::new(128).to_be_bytes_trimmed(&mut buf), [0x00, 0x80]);
+    /// assert_eq!(I256::new(-128).to_be_bytes_trimmed(&mut buf), [0x80]);
+    /// assert_eq!(I256::new(-129).to_be_bytes_trimmed(&mut buf), [0xff, 0x7f]);
+    /// assert_eq!(I256::ZERO.to_be_bytes_trimmed(&mut buf), [0u8; 0]);
+    /// ```
+    pub fn to_be_bytes_trimmed<'a>(&self, buf: &'a mut [u8; 32]) -> &'a [u8] {
+        self.to_be_bytes_into(buf);
+        let redundant = match self.is_negative() {
+            true => self.leading_ones() - 1,
+            false => self.leading_zeros().saturating_sub(1),
+        };
+        let start = match *self == 0 {
+            true => 32,
+            false => (redundant / 8) as usize,
+        };
+        &buf[start..]
+    }
+
+    /// Returns the big endian two's complement representation of this integer
+    /// with all redundant sign extension bytes stripped.
+    ///
+    /// See [`I256::to_be_bytes_trimmed`] for more details.
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes_trimmed_vec(&self) -> alloc::vec::Vec<u8> {
+        self.to_be_bytes_trimmed(&mut [0; 32]).to_vec()
+    }
+
+    /// Create an integer value from a big endian byte slice of up to 32 bytes.
+    ///
+    /// The slice is interpreted as a two's complement number with the width of
+    /// the slice, that is, shorter slices are sign-extended based on the most
+    /// significant bit of the first byte. An empty slice is `0`. Returns an
+    /// error if the slice is longer than 32 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::from_be_slice(&[0x01, 0x02]).ok(), Some(I256::new(0x0102)));
+    /// assert_eq!(I256::from_be_slice(&[0xff, 0xfe]).ok(), Some(I256::new(-2)));
+    /// assert_eq!(I256::from_be_slice(&[0x00, 0xfe]).ok(), Some(I256::new(0xfe)));
+    /// assert_eq!(I256::from_be_slice(&[]).ok(), Some(I256::ZERO));
+    /// assert!(I256::from_be_slice(&[0; 33]).is_err());
+    /// ```
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, TryFromSliceError> {
+        let offset = 32usize.checked_sub(bytes.len()).ok_or(tfse())?;
+        let extend = bytes.first().map(|&b| ((b as i8) >> 7) as u8);
+        let mut buf = [extend.unwrap_or_default(); 32];
+        buf[offset..].copy_from_slice(bytes);
+        Ok(Self::from_be_bytes(buf))
+    }
+
+    /// Create an integer value from a little endian byte slice of up to 32
+    /// bytes.
+    ///
+    /// The slice is interpreted as a two's complement number with the width of
+    /// the slice, that is, shorter slices are sign-extended based on the most
+    /// significant bit of the last byte. An empty slice is `0`. Returns an
+    /// error if the slice is longer than 32 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::from_le_slice(&[0x02, 0x01]).ok(), Some(I256::new(0x0102)));
+    /// assert_eq!(I256::from_le_slice(&[0xfe, 0xff]).ok(), Some(I256::new(-2)));
+    /// assert_eq!(I256::from_le_slice(&[]).ok(), Some(I256::ZERO));
+    /// assert!(I256::from_le_slice(&[0; 33]).is_err());
+    /// ```
+    pub fn from_le_slice(bytes: &[u8]) -> Result<Self, TryFromSliceError> {
+        if bytes.len() > 32 {
+            return Err(tfse());
+        }
+        let extend = bytes.last().map(|&b| ((b as i8) >> 7) as u8);
+        let mut buf = [extend.unwrap_or_default(); 32];
+        buf[..bytes.len()].copy_from_slice(bytes);
+        Ok(Self::from_le_bytes(buf))
+    }
+
+    /// Returns an order-preserving byte encoding of this integer, such that
+    /// comparing the encodings of two values lexicographically gives the same
+    /// result as comparing the values numerically. This makes it suitable for
+    /// use as a database or storage key.
+    ///
+    /// The encoding is the big endian two's complement representation with the
+    /// sign bit flipped (also known as offset binary), so that negative values
+    /// sort before positive ones. The encoding is stable and will not change
+    /// across versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert!(I256::new(-1).to_key_bytes() < I256::new(0).to_key_bytes());
+    /// assert_eq!(I256::MIN.to_key_bytes(), [0; 32]);
+    /// assert_eq!(I256::MAX.to_key_bytes(), [0xff; 32]);
+    /// ```
+    #[inline]
+    pub fn to_key_bytes(self) -> [u8; 32] {
+        let mut bytes = self.to_be_bytes();
+        bytes[0] ^= 0x80;
+        bytes
+    }
+
+    /// Decodes an integer from its order-preserving byte encoding, as created
+    /// by [`I256::to_key_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// let key = I256::new(-42).to_key_bytes();
+    /// assert_eq!(I256::from_key_bytes(key), -42);
+    /// ```
+    #[inline]
+    pub fn from_key_bytes(mut bytes: [u8; 32]) -> Self {
+        bytes[0] ^= 0x80;
+        Self::from_be_bytes(bytes)
+    }
+
     /// Cast to a primitive `i8`.
     pub const fn as_i8(self) -> i8 {
         let (_, lo) = self.into_words();
@@ -227,12 +511,66 @@ impl I256 {
         lo as _
     }
 
+    /// Converts to a primitive integer type, saturating at the target type's
+    /// numeric bounds instead of truncating like the `as_*` casts do. Negative
+    /// values saturate to the target's minimum, so to `0` for unsigned types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::new(-1000).saturating_to::<i8>(), -128);
+    /// assert_eq!(I256::new(-1).saturating_to::<u64>(), 0);
+    /// assert_eq!(I256::MAX.saturating_to::<u128>(), u128::MAX);
+    /// ```
+    #[inline]
+    pub fn saturating_to<T: CastTarget>(self) -> T {
+        T::saturating_from_i256(self)
+    }
+
+    /// Converts to a primitive integer type, keeping only the lowest bits
+    /// that fit in the target type. This is equivalent to an `as` cast.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::new(-1).truncate_to::<u8>(), 0xff);
+    /// assert_eq!(I256::new(0x180).truncate_to::<i8>(), -128);
+    /// ```
+    #[inline]
+    pub fn truncate_to<T: CastTarget>(self) -> T {
+        T::truncating_from_i256(self)
+    }
+
     /// Cast to a `U256`.
     pub const fn as_u256(self) -> U256 {
         let Self([a, b]) = self;
         U256([a as _, b as _])
     }
 
+    /// Converts to a `U256`, returning an error if the value is negative
+    /// instead of reinterpreting its two's complement representation like
+    /// [`I256::as_u256`] does.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::{I256, U256};
+    /// assert_eq!(I256::new(42).try_into_unsigned(), Ok(U256::new(42)));
+    /// assert_eq!(I256::MAX.try_into_unsigned(), Ok(I256::MAX.as_u256()));
+    /// assert!(I256::new(-1).try_into_unsigned().is_err());
+    /// ```
+    pub const fn try_into_unsigned(self) -> Result<U256, TryFromIntError> {
+        let (hi, _) = self.into_words();
+        if hi < 0 {
+            return Err(tfie());
+        }
+        Ok(self.as_u256())
+    }
+
     /// Cast to a primitive `isize`.
     pub const fn as_isize(self) -> isize {
         let (_, lo) = self.into_words();
@@ -247,7 +585,8 @@ impl I256 {
 
     /// Cast to a primitive `f32`.
     pub fn as_f32(self) -> f32 {
-        self.as_f64() as _
+        let sign = self.signum128() as f32;
+        self.unsigned_abs().as_f32() * sign
     }
 
     /// Cast to a primitive `f64`.
@@ -259,11 +598,173 @@ impl I256 {
 
 #[cfg(test)]
 mod tests {
-    use crate::I256;
+    use crate::{error::tfie, I256, U256};
 
     #[test]
     #[allow(clippy::float_cmp)]
     fn converts_to_f64() {
         assert_eq!((-I256::from_words(1, 0)).as_f64(), -(2.0f64.powi(128)))
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn converts_to_f32() {
+        assert_eq!(I256::new(-42).as_f32(), -42.0);
+        assert_eq!(I256::new(i128::MIN).as_f32(), i128::MIN as f32);
+        assert_eq!(I256::MIN.as_f32(), f32::NEG_INFINITY);
+        assert_eq!(I256::MAX.as_f32(), f32::INFINITY);
+
+        // Rounding to `f64` first would make this value an exact tie for `f32`
+        // and round it down to `2^100`.
+        let x = (I256::ONE << 100u32) + (I256::ONE << 76u32) + 1;
+        assert_eq!(x.as_f32(), x.as_i128() as f32);
+        assert_eq!((-x).as_f32(), -(x.as_i128() as f32));
+    }
+
+    #[test]
+    fn slice_round_trips() {
+        for x in [
+            I256::MIN,
+            I256::new(-129),
+            I256::MINUS_ONE,
+            I256::ZERO,
+            I256::MAX,
+        ] {
+            let be = x.to_be_bytes();
+            let le = x.to_le_bytes();
+            assert_eq!(I256::from_be_slice(&be).ok(), Some(x));
+            assert_eq!(I256::from_le_slice(&le).ok(), Some(x));
+
+            let mut buf = [0; 32];
+            x.to_be_bytes_into(&mut buf);
+            assert_eq!(buf, be);
+            x.to_le_bytes_into(&mut buf);
+            assert_eq!(buf, le);
+        }
+
+        assert_eq!(I256::from_be_slice(&[0x80]).ok(), Some(I256::new(-128)));
+        assert_eq!(I256::from_be_slice(&[0x7f]).ok(), Some(I256::new(127)));
+        assert_eq!(
+            I256::from_le_slice(&[0x00, 0x80]).ok(),
+            Some(I256::new(-0x8000))
+        );
+        assert!(I256::from_be_slice(&[0xff; 33]).is_err());
+        assert!(I256::from_le_slice(&[0xff; 33]).is_err());
+    }
+
+    #[test]
+    fn to_be_bytes_trimmed() {
+        let mut buf = [0; 32];
+        assert_eq!(I256::ZERO.to_be_bytes_trimmed(&mut buf), [0u8; 0]);
+        assert_eq!(I256::ONE.to_be_bytes_trimmed(&mut buf), [0x01]);
+        assert_eq!(I256::MINUS_ONE.to_be_bytes_trimmed(&mut buf), [0xff]);
+        assert_eq!(
+            I256::new(0x7fff).to_be_bytes_trimmed(&mut buf),
+            [0x7f, 0xff]
+        );
+        assert_eq!(
+            I256::new(0x8000).to_be_bytes_trimmed(&mut buf),
+            [0x00, 0x80, 0x00]
+        );
+        assert_eq!(
+            I256::new(-0x8000).to_be_bytes_trimmed(&mut buf),
+            [0x80, 0x00]
+        );
+        assert_eq!(
+            I256::new(-0x8001).to_be_bytes_trimmed(&mut buf),
+            [0xff, 0x7f, 0xff]
+        );
+        assert_eq!(
+            I256::MAX.to_be_bytes_trimmed(&mut buf),
+            I256::MAX.to_be_bytes()
+        );
+        assert_eq!(
+            I256::MIN.to_be_bytes_trimmed(&mut buf),
+            I256::MIN.to_be_bytes()
+        );
+
+        for i in 0..255u32 {
+            for x in [I256::ONE << i, -(I256::ONE << i), (I256::ONE << i) - 1] {
+                let trimmed = x.to_be_bytes_trimmed(&mut buf);
+                assert_eq!(I256::from_be_slice(trimmed).ok(), Some(x));
+            }
+        }
+    }
+
+    #[test]
+    fn limbs() {
+        for x in [
+            I256::MIN,
+            I256::new(-42),
+            I256::ZERO,
+            I256::new(42),
+            I256::MAX,
+        ] {
+            assert_eq!(I256::from_limbs(x.into_limbs()), x);
+            assert_eq!(x.as_limbs(), x.as_u256().into_limbs());
+        }
+        assert_eq!(
+            I256::MAX.into_limbs(),
+            [u64::MAX, u64::MAX, u64::MAX, u64::MAX >> 1]
+        );
+    }
+
+    #[test]
+    fn try_into_unsigned_boundary() {
+        assert_eq!(I256::MAX.try_into_unsigned(), Ok(U256::MAX >> 1));
+        assert_eq!(U256::try_from(I256::MAX), Ok(U256::MAX >> 1));
+        assert_eq!(I256::ZERO.try_into_unsigned(), Ok(U256::ZERO));
+        assert_eq!(I256::MINUS_ONE.try_into_unsigned(), Err(tfie()));
+        assert_eq!(U256::try_from(I256::MINUS_ONE), Err(tfie()));
+        assert_eq!(I256::MIN.try_into_unsigned(), Err(tfie()));
+    }
+
+    #[test]
+    fn key_bytes_preserve_order() {
+        let mut values = alloc::vec![
+            I256::new(1),
+            I256::MAX,
+            I256::new(-256),
+            I256::ZERO,
+            I256::MIN + 1,
+            I256::new(i128::MIN) - 1,
+            I256::MINUS_ONE,
+            I256::MIN,
+            I256::new(255),
+            I256::MAX - 1,
+            I256::new(-2),
+            I256::new(i128::MAX) + 1,
+        ];
+        let mut keys = values
+            .iter()
+            .map(|x| x.to_key_bytes())
+            .collect::<alloc::vec::Vec<_>>();
+        values.sort();
+        keys.sort();
+
+        let decoded = keys
+            .into_iter()
+            .map(I256::from_key_bytes)
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(decoded, values);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn hex_string_round_trip() {
+        for x in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            assert_eq!(I256::from_hex(&x.to_hex_string()), Ok(x));
+            assert_eq!(I256::from_str_hex(&x.to_hex_string()), Ok(x));
+            assert_eq!(I256::from_hex(&x.to_hex_string_padded()), Ok(x));
+        }
+        assert_eq!(
+            I256::MIN.to_hex_string(),
+            alloc::format!("-0x8{}", "0".repeat(63))
+        );
+        assert_eq!(
+            I256::MAX.to_hex_string(),
+            alloc::format!("0x7{}", "f".repeat(63))
+        );
+        assert_eq!(I256::MIN.to_hex_string_padded(), I256::MIN.to_hex_string());
+    }
 }
@@ -185,6 +185,26 @@ impl I256 {
         (!self).trailing_zeros()
     }
 
+    /// Returns the bit pattern of `self` reinterpreted as an unsigned integer
+    /// of the same size.
+    ///
+    /// This produces the same result as [`I256::as_u256`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::{I256, U256};
+    /// let n = I256::new(-1);
+    /// assert_eq!(n.cast_unsigned(), U256::MAX);
+    /// assert_eq!(I256::MIN.cast_unsigned(), U256::ONE << 255u32);
+    /// ```
+    #[inline]
+    pub const fn cast_unsigned(self) -> U256 {
+        self.as_u256()
+    }
+
     /// Shifts the bits to the left by a specified amount, `n`,
     /// wrapping the truncated bits to the end of the resulting integer.
     ///
@@ -731,7 +751,6 @@ impl I256 {
     /// assert_eq!(I256::MAX.saturating_add(I256::new(100)), I256::MAX);
     /// assert_eq!(I256::MIN.saturating_add(I256::new(-1)), I256::MIN);
     /// ```
-
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
     #[inline]
@@ -791,7 +810,6 @@ impl I256 {
     /// assert_eq!(I256::MIN.saturating_neg(), I256::MAX);
     /// assert_eq!(I256::MAX.saturating_neg(), I256::MIN + 1);
     /// ```
-
     #[inline]
     pub fn saturating_neg(self) -> Self {
         I256::ZERO.saturating_sub(self)
@@ -811,7 +829,6 @@ impl I256 {
     /// assert_eq!(I256::MIN.saturating_abs(), I256::MAX);
     /// assert_eq!((I256::MIN + 1).saturating_abs(), I256::MAX);
     /// ```
-
     #[inline]
     pub fn saturating_abs(self) -> Self {
         if self.is_negative() {
@@ -10,8 +10,18 @@
 //! assert!(I256::ONE > 0 && I256::ZERO == 0);
 //! assert!(0 < I256::ONE && 0 == I256::ZERO);
 //! ```
+//!
+//! Comparisons with `f32` and `f64` are exact, comparing the integer against
+//! the real value of the float without converting either side:
+//!
+//! ```
+//! # use ethnum::I256;
+//! assert!(I256::ZERO > -0.5 && I256::MINUS_ONE < -0.5);
+//! assert!(I256::MIN == -(2.0f64.powi(255)) && I256::MAX < 2.0f64.powi(255));
+//! assert!(I256::ZERO.partial_cmp(&f64::NAN).is_none());
+//! ```
 
-use super::I256;
+use super::{AsI256 as _, I256};
 use core::cmp::Ordering;
 
 impl Ord for I256 {
@@ -27,6 +37,33 @@ impl_cmp! {
     impl Cmp for I256 (i128);
 }
 
+/// Compares an integer with the exact value of a float.
+fn cmp_f64(a: &I256, b: f64) -> Option<Ordering> {
+    /// -2^255, the smallest 256-bit signed integer.
+    const MIN: f64 = f64::from_bits(0xcfe0000000000000);
+    /// 2^255, the first float that is larger than all 256-bit signed integers.
+    const MAX: f64 = f64::from_bits(0x4fe0000000000000);
+
+    if b.is_nan() {
+        return None;
+    }
+    if b < MIN {
+        return Some(Ordering::Greater);
+    }
+    if b >= MAX {
+        return Some(Ordering::Less);
+    }
+    // Converting the float truncates it exactly since it is in range, and
+    // its integer part converts back to a float exactly. The integers only
+    // compare equal if the float has no fractional part.
+    let int = b.as_i256();
+    Some(a.cmp(&int).then(int.as_f64().partial_cmp(&b)?))
+}
+
+impl_cmp_float! {
+    impl CmpFloat for I256 (cmp_f64);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,4 +91,45 @@ mod tests {
         assert!(I256::MAX > I256::MIN);
         assert!(I256::MIN < I256::MAX);
     }
+
+    #[test]
+    fn cmp_float() {
+        let pow2 = |n: i32| 2.0f64.powi(n);
+        let p53 = I256::ONE << 53u32;
+        for (int, float, ordering) in [
+            (I256::ZERO, -0.0, Some(Ordering::Equal)),
+            (I256::ZERO, -0.5, Some(Ordering::Greater)),
+            (I256::MINUS_ONE, -0.5, Some(Ordering::Less)),
+            (I256::MINUS_ONE, -1.5, Some(Ordering::Greater)),
+            (I256::ONE, 0.5, Some(Ordering::Greater)),
+            (I256::MIN, f64::NEG_INFINITY, Some(Ordering::Greater)),
+            (I256::MAX, f64::INFINITY, Some(Ordering::Less)),
+            (I256::MIN, f64::NAN, None),
+            (p53 + 1, pow2(53), Some(Ordering::Greater)),
+            (-p53 - 1, -pow2(53), Some(Ordering::Less)),
+            (-p53 - 1, -pow2(53) - 2.0, Some(Ordering::Greater)),
+            (-p53 + 1, -pow2(53) + 1.0, Some(Ordering::Equal)),
+            (I256::new(-3), -2.5, Some(Ordering::Less)),
+            (I256::MIN, -pow2(255), Some(Ordering::Equal)),
+            (I256::MIN + 1, -pow2(255), Some(Ordering::Greater)),
+            (I256::MIN, -pow2(255) - pow2(203), Some(Ordering::Greater)),
+            (I256::MAX, pow2(255), Some(Ordering::Less)),
+            (I256::MAX, I256::MAX.as_f64(), Some(Ordering::Less)),
+            (I256::MAX, pow2(255) - pow2(202), Some(Ordering::Greater)),
+            (I256::MIN, f64::MIN, Some(Ordering::Greater)),
+        ] {
+            assert_eq!(int.partial_cmp(&float), ordering, "{int} <=> {float}");
+            assert_eq!(
+                float.partial_cmp(&int),
+                ordering.map(Ordering::reverse),
+                "{float} <=> {int}"
+            );
+            assert_eq!(int == float, ordering == Some(Ordering::Equal));
+            assert_eq!(float == int, ordering == Some(Ordering::Equal));
+        }
+
+        assert!(I256::new(-1) < -0.5f32);
+        assert!(I256::MIN < f32::MIN && I256::MAX > f32::MAX);
+        assert!(I256::MAX < f32::INFINITY);
+    }
 }
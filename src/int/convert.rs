@@ -1,8 +1,16 @@
 //! Module contains conversions for [`I256`] to and from primimitive types.
 
 use super::I256;
-use crate::{error::tfie, uint::U256};
-use core::num::TryFromIntError;
+use crate::{
+    error::{tfie, tfse},
+    uint::U256,
+};
+use core::{
+    array::TryFromSliceError,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, TryFromIntError,
+    },
+};
 
 macro_rules! impl_from {
     ($($t:ty),* $(,)?) => {$(
@@ -25,10 +33,7 @@ impl TryFrom<U256> for I256 {
     type Error = TryFromIntError;
 
     fn try_from(value: U256) -> Result<Self, Self::Error> {
-        if value > I256::MAX.as_u256() {
-            return Err(tfie());
-        }
-        Ok(value.as_i256())
+        value.try_into_signed()
     }
 }
 
@@ -141,7 +146,7 @@ macro_rules! impl_as_i256_float {
                     let mantissa = (bits & MAN_MASK) | MAN_ONE;
                     if exponent <= M {
                         (I256::from(mantissa >> (M - exponent))) * sign
-                    } else if exponent < 255 {
+                    } else if exponent < 255 && abs.is_finite() {
                         (I256::from(mantissa) << (exponent - M)) * sign
                     } else if sign > 0 {
                         I256::MAX
@@ -160,6 +165,139 @@ impl_as_i256_float! {
     f32[u32], f64[u64],
 }
 
+/// This trait defines fallible conversions from primitive types to
+/// [`I256`].
+///
+/// Unlike [`AsI256`], which mirrors the truncating and saturating semantics of
+/// `as` casts, these conversions return an error when the value can't be
+/// represented exactly as a [`I256`]. For floating point values this means that
+/// `NaN`, infinities and values outside of the [`I256`] range are rejected,
+/// while the fractional part is truncated.
+///
+/// [`I256`]: struct.I256.html
+///
+/// # Examples
+///
+/// ```
+/// # use ethnum::{I256, TryAsI256, U256};
+/// assert_eq!((-1i32).try_as_i256(), Ok(I256::MINUS_ONE));
+/// assert!(U256::MAX.try_as_i256().is_err());
+///
+/// assert_eq!((-1.5f64).try_as_i256(), Ok(I256::MINUS_ONE));
+/// assert!(f64::NEG_INFINITY.try_as_i256().is_err());
+/// assert!(2.0f64.powi(255).try_as_i256().is_err());
+/// assert!(f64::NAN.try_as_i256().is_err());
+/// ```
+pub trait TryAsI256 {
+    /// Perform a checked conversion to a [`I256`].
+    ///
+    /// [`I256`]: struct.I256.html
+    #[allow(clippy::wrong_self_convention)]
+    fn try_as_i256(self) -> Result<I256, TryFromIntError>;
+}
+
+impl TryAsI256 for I256 {
+    #[inline]
+    fn try_as_i256(self) -> Result<I256, TryFromIntError> {
+        Ok(self)
+    }
+}
+
+macro_rules! impl_try_as_i256 {
+    (
+        infallible: $($i:ty),* ;
+        fallible: $($f:ty),* $(,)?
+    ) => {
+        $(
+            impl TryAsI256 for $i {
+                #[inline]
+                fn try_as_i256(self) -> Result<I256, TryFromIntError> {
+                    Ok(self.as_i256())
+                }
+            }
+        )*
+        $(
+            impl TryAsI256 for $f {
+                #[inline]
+                fn try_as_i256(self) -> Result<I256, TryFromIntError> {
+                    I256::try_from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_as_i256! {
+    infallible:
+    i8, i16, i32, i64, i128,
+    u8, u16, u32, u64, u128,
+    isize, usize;
+    fallible:
+    U256, f32, f64,
+}
+
+impl TryFrom<&[u8]> for I256 {
+    type Error = TryFromSliceError;
+
+    /// Converts a big endian two's complement byte slice of arbitrary length
+    /// to a `I256`.
+    ///
+    /// Slices shorter than 32 bytes are sign-extended, and slices longer than
+    /// 32 bytes are accepted as long as all extra leading bytes are redundant
+    /// sign extension bytes. The only possible error is the value not fitting
+    /// in 256 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// assert_eq!(I256::try_from([0x2a].as_slice()).ok(), Some(I256::new(42)));
+    /// assert_eq!(I256::try_from([0xd6].as_slice()).ok(), Some(I256::new(-42)));
+    /// assert_eq!(I256::try_from([0xff; 40].as_slice()).ok(), Some(I256::new(-1)));
+    /// assert!(I256::try_from([0x01; 33].as_slice()).is_err());
+    /// ```
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let start = bytes.len().saturating_sub(32);
+        let (extra, bytes) = bytes.split_at(start);
+        if let Some(&first) = extra.first() {
+            let extend = ((first as i8) >> 7) as u8;
+            if extra.iter().any(|&b| b != extend) || (bytes[0] ^ extend) & 0x80 != 0 {
+                return Err(tfse());
+            }
+        }
+        I256::from_be_slice(bytes)
+    }
+}
+
+impl TryFrom<f64> for I256 {
+    type Error = TryFromIntError;
+
+    /// Converts a floating point value to a `I256`, truncating any fractional
+    /// part. Returns an error for `NaN` and infinite values, as well as values
+    /// outside of the range `[-2^255, 2^255)`.
+    #[inline]
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        // NOTE: Binary representation of 2**255.
+        const MAX: u64 = 0x4fe0000000000000;
+        if (-f64::from_bits(MAX)..f64::from_bits(MAX)).contains(&value) {
+            Ok(value.as_i256())
+        } else {
+            Err(tfie())
+        }
+    }
+}
+
+impl TryFrom<f32> for I256 {
+    type Error = TryFromIntError;
+
+    /// Converts a floating point value to a `I256`, truncating any fractional
+    /// part. Returns an error for `NaN` and infinite values.
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        I256::try_from(value as f64)
+    }
+}
+
 macro_rules! impl_try_into {
     ($($t:ty),* $(,)?) => {$(
         impl TryFrom<I256> for $t {
@@ -167,7 +305,7 @@ macro_rules! impl_try_into {
 
             #[inline]
             fn try_from(x: I256) -> Result<Self, Self::Error> {
-                if x <= <$t>::MAX.as_i256() {
+                if (<$t>::MIN.as_i256()..=<$t>::MAX.as_i256()).contains(&x) {
                     Ok(*x.low() as _)
                 } else {
                     Err(tfie())
@@ -183,6 +321,35 @@ impl_try_into! {
     isize, usize,
 }
 
+macro_rules! impl_nonzero {
+    ($($nz:ident => $t:ty),* $(,)?) => {$(
+        impl From<$nz> for I256 {
+            #[inline]
+            fn from(value: $nz) -> Self {
+                value.get().as_i256()
+            }
+        }
+
+        impl TryFrom<I256> for $nz {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(x: I256) -> Result<Self, Self::Error> {
+                <$t>::try_from(x).ok().and_then($nz::new).ok_or_else(tfie)
+            }
+        }
+    )*};
+}
+
+impl_nonzero! {
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize,
+}
+
 macro_rules! impl_into_float {
     ($($t:ty => $f:ident),* $(,)?) => {$(
         impl From<I256> for $t {
@@ -197,3 +364,161 @@ macro_rules! impl_into_float {
 impl_into_float! {
     f32 => as_f32, f64 => as_f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonzero() {
+        assert_eq!(I256::from(NonZeroI128::new(i128::MIN).unwrap()), i128::MIN);
+        assert_eq!(I256::from(NonZeroI8::new(-1).unwrap()), -1);
+        assert_eq!(
+            NonZeroI64::try_from(I256::new(i64::MIN as _))
+                .unwrap()
+                .get(),
+            i64::MIN
+        );
+        assert!(NonZeroI64::try_from(I256::new(i64::MIN as i128 - 1)).is_err());
+        assert!(NonZeroI128::try_from(I256::ZERO).is_err());
+        assert!(NonZeroIsize::try_from(I256::MAX).is_err());
+    }
+
+    #[test]
+    fn try_as_i256() {
+        fn try_as<T: TryAsI256>(x: T) -> Option<I256> {
+            x.try_as_i256().ok()
+        }
+
+        assert_eq!(try_as(i128::MIN), Some(I256::new(i128::MIN)));
+        assert_eq!(try_as(u128::MAX), Some(u128::MAX.as_i256()));
+        assert_eq!(try_as(isize::MIN), Some(I256::new(isize::MIN as _)));
+        assert_eq!(try_as(usize::MAX), Some(I256::new(usize::MAX as _)));
+        assert_eq!(try_as(I256::MIN), Some(I256::MIN));
+        assert_eq!(try_as(I256::MAX.as_u256()), Some(I256::MAX));
+        assert_eq!(try_as(U256::MAX), None);
+
+        assert_eq!(try_as(-0.5f32), Some(I256::ZERO));
+        assert_eq!(try_as(f64::from_bits(0xcfe0000000000000)), Some(I256::MIN));
+        assert_eq!(try_as(f64::from_bits(0x4fe0000000000000)), None);
+        assert_eq!(try_as(f64::NEG_INFINITY), None);
+        assert_eq!(try_as(f32::NAN), None);
+    }
+
+    #[test]
+    fn try_into_primitive_boundaries() {
+        macro_rules! assert_boundaries {
+            ($($t:ty),* $(,)?) => {$(
+                let (min, max) = (<$t>::MIN.as_i256(), <$t>::MAX.as_i256());
+                assert_eq!(<$t>::try_from(I256::ZERO), Ok(0));
+                assert_eq!(<$t>::try_from(min), Ok(<$t>::MIN));
+                assert_eq!(<$t>::try_from(max), Ok(<$t>::MAX));
+                assert_eq!(<$t>::try_from(min - 1), Err(tfie()));
+                assert_eq!(<$t>::try_from(max + 1), Err(tfie()));
+                assert_eq!(<$t>::try_from(I256::MIN), Err(tfie()));
+                assert_eq!(<$t>::try_from(I256::MAX), Err(tfie()));
+            )*};
+        }
+
+        assert_boundaries! {
+            i8, i16, i32, i64, i128,
+            u8, u16, u32, u64, u128,
+            isize, usize,
+        }
+    }
+
+    #[test]
+    fn try_into_primitive_rejects_negatives_for_unsigned() {
+        assert_eq!(u8::try_from(I256::MINUS_ONE), Err(tfie()));
+        assert_eq!(u64::try_from(I256::new(-42)), Err(tfie()));
+        assert_eq!(u128::try_from(I256::MINUS_ONE), Err(tfie()));
+        assert_eq!(usize::try_from(I256::MIN), Err(tfie()));
+    }
+
+    #[test]
+    fn try_into_i128_sign_boundary() {
+        let min = I256::new(i128::MIN);
+        assert_eq!(i128::try_from(min), Ok(i128::MIN));
+        assert_eq!(i128::try_from(min - 1), Err(tfie()));
+        assert_eq!(i128::try_from(I256::from_words(-2, 0)), Err(tfie()));
+        assert_eq!(i128::try_from(I256::from_words(0, i128::MIN)), Err(tfie()));
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let convert = |bytes: &[u8]| I256::try_from(bytes).ok();
+
+        assert_eq!(convert(&[]), Some(I256::ZERO));
+        assert_eq!(convert(&[0x80]), Some(I256::new(-128)));
+        assert_eq!(convert(&[0x00, 0x80]), Some(I256::new(128)));
+        assert_eq!(convert(&I256::MIN.to_be_bytes()), Some(I256::MIN));
+        assert_eq!(convert(&I256::MAX.to_be_bytes()), Some(I256::MAX));
+
+        let mut padded = [0x00; 33];
+        padded[1..].copy_from_slice(&I256::MAX.to_be_bytes());
+        assert_eq!(convert(&padded), Some(I256::MAX));
+        padded[1..].copy_from_slice(&I256::MIN.to_be_bytes());
+        assert_eq!(convert(&padded), None);
+        padded[0] = 0xff;
+        assert_eq!(convert(&padded), Some(I256::MIN));
+        padded[1..].copy_from_slice(&I256::MAX.to_be_bytes());
+        assert_eq!(convert(&padded), None);
+
+        assert_eq!(convert(&[0x01; 33]), None);
+        assert_eq!(convert(&[0xfe; 33]), None);
+    }
+
+    #[test]
+    fn as_i256_float_saturates() {
+        assert_eq!(f64::NAN.as_i256(), I256::ZERO);
+        assert_eq!(f32::NAN.as_i256(), I256::ZERO);
+        assert_eq!(f64::INFINITY.as_i256(), I256::MAX);
+        assert_eq!(f32::INFINITY.as_i256(), I256::MAX);
+        assert_eq!(f64::NEG_INFINITY.as_i256(), I256::MIN);
+        assert_eq!(f32::NEG_INFINITY.as_i256(), I256::MIN);
+
+        assert_eq!((-0.0f64).as_i256(), I256::ZERO);
+        assert_eq!((-0.5f64).as_i256(), I256::ZERO);
+        assert_eq!((-2.5f64).as_i256(), I256::new(-2));
+        assert_eq!(2.5f32.as_i256(), I256::new(2));
+        assert_eq!(f32::MIN.as_i256(), -(f32::MAX as u128).as_i256());
+
+        assert_eq!((-(2.0f64.powi(255))).as_i256(), I256::MIN);
+        assert_eq!(2.0f64.powi(255).as_i256(), I256::MAX);
+        assert_eq!((-(2.0f64.powi(256))).as_i256(), I256::MIN);
+        assert_eq!(f64::MAX.as_i256(), I256::MAX);
+        assert_eq!(f64::MIN.as_i256(), I256::MIN);
+    }
+
+    #[test]
+    fn try_from_float() {
+        for i in 0..255 {
+            let x = I256::ONE << i;
+            assert_eq!(I256::try_from(2.0f64.powi(i)), Ok(x));
+            assert_eq!(I256::try_from(-(2.0f64.powi(i))), Ok(-x));
+        }
+        for i in 0..128 {
+            let x = I256::ONE << i;
+            assert_eq!(I256::try_from(2.0f32.powi(i)), Ok(x));
+            assert_eq!(I256::try_from(-(2.0f32.powi(i))), Ok(-x));
+        }
+
+        assert_eq!(I256::try_from(-(2.0f64.powi(255))), Ok(I256::MIN));
+        assert_eq!(I256::try_from(0.0f64), Ok(I256::ZERO));
+        assert_eq!(I256::try_from(-0.0f64), Ok(I256::ZERO));
+        assert_eq!(I256::try_from(-0.99f64), Ok(I256::ZERO));
+        assert_eq!(I256::try_from(42.9f64), Ok(I256::new(42)));
+        assert_eq!(I256::try_from(-42.9f64), Ok(I256::new(-42)));
+        assert_eq!(I256::try_from(-1337.5f32), Ok(I256::new(-1337)));
+
+        assert_eq!(I256::try_from(2.0f64.powi(255)), Err(tfie()));
+        assert_eq!(I256::try_from(-(2.0f64.powi(256))), Err(tfie()));
+        assert_eq!(I256::try_from(f64::MAX), Err(tfie()));
+        assert_eq!(I256::try_from(f64::MIN), Err(tfie()));
+        assert_eq!(I256::try_from(f64::INFINITY), Err(tfie()));
+        assert_eq!(I256::try_from(f64::NEG_INFINITY), Err(tfie()));
+        assert_eq!(I256::try_from(f64::NAN), Err(tfie()));
+        assert_eq!(I256::try_from(f32::NEG_INFINITY), Err(tfie()));
+        assert_eq!(I256::try_from(f32::NAN), Err(tfie()));
+    }
+}
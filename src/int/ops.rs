@@ -323,4 +323,22 @@ mod tests {
 
         assert_ops::<I256>();
     }
+
+    impl_shift_overflow_tests! {
+        for I256 {
+            rhs_u16: 300u16 => 44;
+            rhs_u32: 300u32 => 44;
+            rhs_u64: 0x1_0000_012cu64 => 44;
+            rhs_u128: u128::MAX => 255;
+            rhs_usize: 300usize => 44;
+            rhs_i8: -1i8 => 255;
+            rhs_i16: 300i16 => 44;
+            rhs_i32: -212i32 => 44;
+            rhs_i64: 300i64 => 44;
+            rhs_i128: i128::MIN + 300 => 44;
+            rhs_isize: -1isize => 255;
+            rhs_u256: U256::from_words(1, 300) => 44;
+            rhs_i256: I256::new(-212) => 44;
+        }
+    }
 }
@@ -16,11 +16,13 @@ mod llvm;
 #[cfg(not(feature = "llvm-intrinsics"))]
 mod native;
 pub mod signed;
+mod wide;
 
 #[cfg(feature = "llvm-intrinsics")]
 pub use self::llvm::*;
 #[cfg(not(feature = "llvm-intrinsics"))]
 pub use self::native::*;
+pub use self::wide::*;
 
 #[cfg(test)]
 mod tests {
@@ -12,6 +12,11 @@ macro_rules! cast {
         unsafe { &mut *($x).as_mut_ptr().cast::<MaybeUninit<$crate::uint::U256>>() }
     };
     (optuninit: $x:expr) => {
-        unsafe { ::core::mem::transmute(::core::ptr::read(&$x as *const _)) }
+        unsafe {
+            ::core::mem::transmute::<
+                Option<&mut MaybeUninit<$crate::int::I256>>,
+                Option<&mut MaybeUninit<$crate::uint::U256>>,
+            >(::core::ptr::read(&$x as *const _))
+        }
     };
 }
@@ -7,7 +7,7 @@
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 
 #[macro_use]
@@ -24,15 +24,27 @@ mod macros {
     pub mod parse;
 }
 
+#[cfg(feature = "std")]
+mod atomic;
+mod cast;
+#[cfg(feature = "cosmos-proto")]
+pub mod cosmos_proto;
 mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod fmt;
 mod int;
 pub mod intrinsics;
+mod nonzero;
+pub mod padded;
 mod parse;
-#[cfg(feature = "original-serde")]
+mod range;
+#[cfg(feature = "serde")]
 pub mod serde;
 mod support;
 mod uint;
+mod words;
+mod wrapping;
 
 /// Macro for 256-bit signed integer literal.
 ///
@@ -132,14 +144,30 @@ pub mod internal {
 
 /// Convenience re-export of 256-integer types and as- conversion traits.
 pub mod prelude {
-    pub use crate::{AsI256, AsU256, I256, U256};
+    pub use crate::{AsI256, AsU256, TryAsI256, TryAsU256, I256, U256};
 }
 
+#[cfg(feature = "std")]
+pub use crate::atomic::AtomicU256;
 pub use crate::{
-    int::{AsI256, I256},
-    uint::{AsU256, U256},
+    cast::CastTarget,
+    int::{AsI256, TryAsI256, I256},
+    nonzero::{NonZeroI256, NonZeroU256},
+    range::{I256Range, U256Range},
+    uint::{AsU256, TryAsU256, U256},
+    words::BeWords,
+    wrapping::{SaturatingI256, SaturatingU256, WrappingI256, WrappingU256},
 };
 
+#[cfg(feature = "scale")]
+pub use crate::support::CompactU256;
+#[cfg(feature = "rkyv")]
+pub use crate::support::{ArchivedI256, ArchivedU256};
+#[cfg(feature = "cosmos")]
+pub use crate::support::{CheckedMultiplyFractionError, DivisionError, Ratio256};
+#[cfg(feature = "rand")]
+pub use crate::support::{UniformI256, UniformU256};
+
 /// A 256-bit signed integer type.
 #[allow(non_camel_case_types)]
 pub type i256 = I256;
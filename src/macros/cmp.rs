@@ -40,3 +40,41 @@ macro_rules! impl_cmp {
         }
     };
 }
+
+macro_rules! impl_cmp_float {
+    (
+        impl CmpFloat for $int:ident ($cmp:ident);
+    ) => {
+        impl_cmp_float! { __impl $int ($cmp) f64 }
+        impl_cmp_float! { __impl $int ($cmp) f32 }
+    };
+    (__impl $int:ident ($cmp:ident) $float:ident) => {
+        impl PartialEq<$float> for $int {
+            #[inline]
+            fn eq(&self, other: &$float) -> bool {
+                $cmp(self, *other as f64) == Some(::core::cmp::Ordering::Equal)
+            }
+        }
+
+        impl PartialEq<$int> for $float {
+            #[inline]
+            fn eq(&self, other: &$int) -> bool {
+                $cmp(other, *self as f64) == Some(::core::cmp::Ordering::Equal)
+            }
+        }
+
+        impl PartialOrd<$float> for $int {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$float) -> Option<::core::cmp::Ordering> {
+                $cmp(self, *rhs as f64)
+            }
+        }
+
+        impl PartialOrd<$int> for $float {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$int) -> Option<::core::cmp::Ordering> {
+                $cmp(rhs, *self as f64).map(::core::cmp::Ordering::reverse)
+            }
+        }
+    };
+}
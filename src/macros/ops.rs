@@ -262,11 +262,16 @@ macro_rules! __impl_ops_shift {
             type Output = $int;
 
             #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
             fn $method(self, rhs: u32) -> Self::Output {
                 #[cfg(debug_assertions)]
                 if rhs > 0xff {
                     panic!(concat!("attempt to ", $msg));
                 }
+                // Like primitive integers, only the low bits of the shift
+                // amount are used in release builds.
+                #[cfg(not(debug_assertions))]
+                let rhs = rhs & 0xff;
 
                 let mut result = ::core::mem::MaybeUninit::uninit();
                 $op3(&mut result, self, rhs);
@@ -454,10 +459,11 @@ macro_rules! __impl_ops_shift_assign {
     ) => {$(
         impl ::core::ops::$op<u32> for $int {
             #[inline]
+            #[allow(clippy::suspicious_op_assign_impl)]
             fn $method(&mut self, rhs: u32) {
                 #[cfg(not(debug_assertions))]
                 {
-                    $op2(self, rhs);
+                    $op2(self, rhs & 0xff);
                 }
                 #[cfg(debug_assertions)]
                 {
@@ -540,3 +546,183 @@ macro_rules! __impl_ops_bitwise_assign {
         }
     )*};
 }
+
+macro_rules! impl_ops_wrapper {
+    (
+        for $wrapper:ident | $prim:ident {$(
+            impl $op:ident, $opa:ident {
+                $x:tt $method:ident, $xa:tt $methoda:ident => |$a:ident, $b:ident| $impl:expr;
+            }
+        )*}
+    ) => {$(
+        impl ::core::ops::$op for &'_ $wrapper {
+            type Output = $wrapper;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                let ($a, $b) = (self.0, rhs.0);
+                $wrapper($impl)
+            }
+        }
+
+        __impl_ops_binop_extra_variants! {
+            impl $op for $wrapper | $prim { $method = $x }
+        }
+
+        impl ::core::ops::$opa<&'_ $wrapper> for $wrapper {
+            #[inline]
+            fn $methoda(&mut self, rhs: &'_ $wrapper) {
+                *self = &*self $x rhs;
+            }
+        }
+
+        __impl_ops_binop_assign_extra_variants! {
+            impl $opa for $wrapper | $prim { $methoda = $xa }
+        }
+    )*};
+}
+
+macro_rules! impl_ops_wrapper_shift {
+    (
+        for $wrapper:ident {$(
+            impl $op:ident, $opa:ident {
+                $x:tt $method:ident, $xa:tt $methoda:ident => |$a:ident, $b:ident| $impl:expr;
+            }
+        )*}
+    ) => {$(
+        impl ::core::ops::$op<u32> for &'_ $wrapper {
+            type Output = $wrapper;
+
+            #[inline]
+            fn $method(self, rhs: u32) -> Self::Output {
+                let ($a, $b) = (self.0, rhs);
+                $wrapper($impl)
+            }
+        }
+
+        __impl_ops_binop_ref! {
+            impl $op for $wrapper {
+                $method(a: &'_ $wrapper, b: &'_ u32) {  a $x *b };
+                $method(a:     $wrapper, b: &'_ u32) { &a $x *b };
+                $method(a:     $wrapper, b:     u32) { &a $x  b };
+            }
+        }
+
+        impl ::core::ops::$opa<u32> for $wrapper {
+            #[inline]
+            fn $methoda(&mut self, rhs: u32) {
+                *self = &*self $x rhs;
+            }
+        }
+
+        __impl_ops_binop_assign_ref! {
+            impl $opa for $wrapper {
+                $methoda(a, b: &'_ u32) { *a $xa *b };
+            }
+        }
+    )*};
+}
+
+#[cfg(test)]
+macro_rules! impl_shift_overflow_tests {
+    (
+        for $int:ident {$(
+            $name:ident: $rhs:expr => $masked:expr;
+        )*}
+    ) => {
+        mod shift_overflow {
+            use super::*;
+
+            $(
+                mod $name {
+                    use super::*;
+
+                    pub(super) fn value() -> $int {
+                        $int::from_words(0x0123_4567_89ab_cdef, -0x7654_3210_fedc_ba98_i128 as _)
+                    }
+
+                    #[cfg(debug_assertions)]
+                    #[test]
+                    #[should_panic(expected = "attempt to shift left with overflow")]
+                    fn shl() {
+                        let _ = value() << $rhs;
+                    }
+
+                    #[cfg(debug_assertions)]
+                    #[test]
+                    #[should_panic(expected = "attempt to shift left with overflow")]
+                    fn shl_ref() {
+                        let _ = &value() << &$rhs;
+                    }
+
+                    #[cfg(debug_assertions)]
+                    #[test]
+                    #[should_panic(expected = "attempt to shift right with overflow")]
+                    fn shr() {
+                        let _ = value() >> $rhs;
+                    }
+
+                    #[cfg(debug_assertions)]
+                    #[test]
+                    #[should_panic(expected = "attempt to shift right with overflow")]
+                    fn shr_ref() {
+                        let _ = &value() >> &$rhs;
+                    }
+
+                    #[cfg(debug_assertions)]
+                    #[test]
+                    #[should_panic(expected = "attempt to shift left with overflow")]
+                    fn shl_assign() {
+                        let mut x = value();
+                        x <<= $rhs;
+                    }
+
+                    #[cfg(debug_assertions)]
+                    #[test]
+                    #[should_panic(expected = "attempt to shift right with overflow")]
+                    fn shr_assign() {
+                        let mut x = value();
+                        x >>= $rhs;
+                    }
+
+                    #[cfg(not(debug_assertions))]
+                    #[test]
+                    fn masks() {
+                        let x = value();
+                        assert_eq!(x << $rhs, x << $masked);
+                        assert_eq!(&x << &$rhs, x.wrapping_shl($masked));
+                        assert_eq!(x >> $rhs, x >> $masked);
+                        assert_eq!(&x >> &$rhs, x.wrapping_shr($masked));
+
+                        let mut y = x;
+                        y <<= $rhs;
+                        assert_eq!(y, x << $masked);
+                        let mut y = x;
+                        y >>= $rhs;
+                        assert_eq!(y, x >> $masked);
+                    }
+                }
+            )*
+
+            #[test]
+            fn methods_agree_with_operators() {
+                let x = rhs_u32::value();
+                for rhs in [256, 300, 511, 0x1_00ff, u32::MAX] {
+                    let masked = rhs & 0xff;
+                    assert_eq!(x.wrapping_shl(rhs), x << masked);
+                    assert_eq!(x.wrapping_shr(rhs), x >> masked);
+                    assert_eq!(x.overflowing_shl(rhs), (x << masked, true));
+                    assert_eq!(x.overflowing_shr(rhs), (x >> masked, true));
+                    assert_eq!(x.checked_shl(rhs), None);
+                    assert_eq!(x.checked_shr(rhs), None);
+                }
+                for rhs in [0, 1, 128, 255] {
+                    assert_eq!(x.wrapping_shl(rhs), x << rhs);
+                    assert_eq!(x.overflowing_shl(rhs), (x << rhs, false));
+                    assert_eq!(x.checked_shl(rhs), Some(x << rhs));
+                    assert_eq!(x.checked_shr(rhs), Some(x >> rhs));
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,458 @@
+//! Module containing 256-bit integer types that are known not to equal zero.
+//!
+//! These mirror the `core::num::NonZero*` types. Note that, unlike the
+//! primitive non-zero types, the compiler is not told about the invalid zero
+//! value so there is no layout niche: `Option<NonZeroU256>` is larger than
+//! `NonZeroU256`. The semantics of `Option<NonZeroU256>` are otherwise the
+//! same, with `None` standing in for zero.
+
+use crate::{error::tfie, I256, U256};
+use core::{
+    fmt,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
+        NonZeroU32, NonZeroU64, NonZeroU8, TryFromIntError,
+    },
+    ops::{BitOr, BitOrAssign, Div, DivAssign, Rem, RemAssign},
+};
+
+macro_rules! impl_nonzero {
+    (
+        $(#[$attr:meta])*
+        $nz:ident($int:ident, $intname:literal); $($prim:ident),*
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(into = $intname, try_from = $intname)
+        )]
+        #[repr(transparent)]
+        pub struct $nz($int);
+
+        impl $nz {
+            /// The largest value that can be represented by this non-zero
+            /// integer type.
+            pub const MAX: Self = unsafe { Self::new_unchecked($int::MAX) };
+
+            /// Creates a non-zero if the given value is not zero.
+            #[inline]
+            pub const fn new(n: $int) -> Option<Self> {
+                let (hi, lo) = n.into_words();
+                if hi == 0 && lo == 0 {
+                    None
+                } else {
+                    Some(Self(n))
+                }
+            }
+
+            /// Creates a non-zero without checking whether the value is
+            /// non-zero. This results in undefined behaviour if the value is
+            /// zero.
+            ///
+            /// # Safety
+            ///
+            /// The value must not be zero.
+            #[inline]
+            pub const unsafe fn new_unchecked(n: $int) -> Self {
+                Self(n)
+            }
+
+            /// Returns the value as a primitive type.
+            #[inline]
+            pub const fn get(self) -> $int {
+                self.0
+            }
+
+            /// Returns the number of leading zeros in the binary
+            /// representation of `self`.
+            #[inline]
+            pub fn leading_zeros(self) -> u32 {
+                self.0.leading_zeros()
+            }
+
+            /// Returns the number of trailing zeros in the binary
+            /// representation of `self`.
+            #[inline]
+            pub fn trailing_zeros(self) -> u32 {
+                self.0.trailing_zeros()
+            }
+
+            /// Multiplies two non-zero integers together. Checks for overflow
+            /// and returns `None` on overflow. As a consequence, the result
+            /// cannot wrap to zero.
+            #[inline]
+            pub fn checked_mul(self, other: Self) -> Option<Self> {
+                // NOTE: The product of two non-zero integers is non-zero unless
+                // it overflows.
+                self.0.checked_mul(other.0).map(Self)
+            }
+
+            /// Multiplies two non-zero integers together, saturating at the
+            /// numeric bounds instead of overflowing.
+            #[inline]
+            pub fn saturating_mul(self, other: Self) -> Self {
+                Self(self.0.saturating_mul(other.0))
+            }
+
+            /// Raises non-zero value to an integer power. Checks for overflow
+            /// and returns `None` on overflow. As a consequence, the result
+            /// cannot wrap to zero.
+            #[inline]
+            pub fn checked_pow(self, exp: u32) -> Option<Self> {
+                self.0.checked_pow(exp).map(Self)
+            }
+        }
+
+        impl From<$nz> for $int {
+            #[inline]
+            fn from(value: $nz) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<$int> for $nz {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or_else(tfie)
+            }
+        }
+
+        $(
+            impl From<$prim> for $nz {
+                #[inline]
+                fn from(value: $prim) -> Self {
+                    Self($int::from(value.get()))
+                }
+            }
+        )*
+
+        impl BitOr for $nz {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl BitOr<$int> for $nz {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: $int) -> Self {
+                Self(self.0 | rhs)
+            }
+        }
+
+        impl BitOr<$nz> for $int {
+            type Output = $nz;
+
+            #[inline]
+            fn bitor(self, rhs: $nz) -> $nz {
+                $nz(self | rhs.0)
+            }
+        }
+
+        impl BitOrAssign for $nz {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl BitOrAssign<$int> for $nz {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: $int) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl_nonzero!(@fmt $nz: Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex);
+
+        #[cfg(feature = "borsh")]
+        impl borsh::BorshSerialize for $nz {
+            #[inline]
+            fn serialize<W: borsh::maybestd::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> borsh::maybestd::io::Result<()> {
+                borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        #[cfg(feature = "borsh")]
+        impl borsh::BorshDeserialize for $nz {
+            #[inline]
+            fn deserialize_reader<R: borsh::maybestd::io::Read>(
+                reader: &mut R,
+            ) -> borsh::maybestd::io::Result<Self> {
+                Self::new(borsh::BorshDeserialize::deserialize_reader(reader)?).ok_or_else(|| {
+                    borsh::maybestd::io::Error::new(
+                        borsh::maybestd::io::ErrorKind::InvalidData,
+                        "Expected a non-zero value",
+                    )
+                })
+            }
+        }
+
+        #[cfg(feature = "borsh-v1")]
+        impl borsh_v1::BorshSerialize for $nz {
+            #[inline]
+            fn serialize<W: borsh_v1::io::Write>(&self, writer: &mut W) -> borsh_v1::io::Result<()> {
+                borsh_v1::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        #[cfg(feature = "borsh-v1")]
+        impl borsh_v1::BorshDeserialize for $nz {
+            #[inline]
+            fn deserialize_reader<R: borsh_v1::io::Read>(reader: &mut R) -> borsh_v1::io::Result<Self> {
+                Self::new(borsh_v1::BorshDeserialize::deserialize_reader(reader)?).ok_or_else(|| {
+                    borsh_v1::io::Error::new(
+                        borsh_v1::io::ErrorKind::InvalidData,
+                        "Expected a non-zero value",
+                    )
+                })
+            }
+        }
+    };
+
+    (@fmt $nz:ident: $($trait:ident),*) => {$(
+        impl fmt::$trait for $nz {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::$trait::fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+impl_nonzero! {
+    /// A 256-bit unsigned integer that is known not to equal zero.
+    NonZeroU256(U256, "U256"); NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128
+}
+
+impl_nonzero! {
+    /// A 256-bit signed integer that is known not to equal zero.
+    NonZeroI256(I256, "I256"); NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128
+}
+
+impl NonZeroU256 {
+    /// The smallest value that can be represented by this non-zero integer
+    /// type, i.e. `1`.
+    pub const MIN: Self = Self(U256::ONE);
+    /// Adds an unsigned integer to a non-zero value. Checks for overflow and
+    /// returns `None` on overflow. As a consequence, the result cannot wrap to
+    /// zero.
+    #[inline]
+    pub fn checked_add(self, other: U256) -> Option<Self> {
+        self.0.checked_add(other).map(Self)
+    }
+
+    /// Adds an unsigned integer to a non-zero value, saturating at
+    /// `NonZeroU256::MAX` instead of overflowing.
+    #[inline]
+    pub fn saturating_add(self, other: U256) -> Self {
+        Self(self.0.saturating_add(other))
+    }
+
+    /// Returns `true` if and only if `self == (1 << k)` for some `k`.
+    #[inline]
+    pub fn is_power_of_two(self) -> bool {
+        self.0.is_power_of_two()
+    }
+}
+
+impl Div<NonZeroU256> for U256 {
+    type Output = U256;
+
+    /// This operation rounds towards zero, truncating any fractional part of
+    /// the exact result, and cannot panic.
+    #[inline]
+    fn div(self, other: NonZeroU256) -> U256 {
+        self / other.0
+    }
+}
+
+impl DivAssign<NonZeroU256> for U256 {
+    /// This operation rounds towards zero, truncating any fractional part of
+    /// the exact result, and cannot panic.
+    #[inline]
+    fn div_assign(&mut self, other: NonZeroU256) {
+        *self = *self / other;
+    }
+}
+
+impl Rem<NonZeroU256> for U256 {
+    type Output = U256;
+
+    /// This operation satisfies `n % d == n - (n / d) * d`, and cannot panic.
+    #[inline]
+    fn rem(self, other: NonZeroU256) -> U256 {
+        self % other.0
+    }
+}
+
+impl RemAssign<NonZeroU256> for U256 {
+    /// This operation satisfies `n % d == n - (n / d) * d`, and cannot panic.
+    #[inline]
+    fn rem_assign(&mut self, other: NonZeroU256) {
+        *self = *self % other;
+    }
+}
+
+impl NonZeroI256 {
+    /// The smallest value that can be represented by this non-zero integer
+    /// type.
+    pub const MIN: Self = Self(I256::MIN);
+    /// Computes the absolute value of self. See [`I256::abs`] for
+    /// documentation on overflow behaviour.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Checked absolute value. Checks for overflow and returns `None` if
+    /// `self == NonZeroI256::MIN`. The result cannot be zero.
+    #[inline]
+    pub fn checked_abs(self) -> Option<Self> {
+        self.0.checked_abs().map(Self)
+    }
+
+    /// Computes the absolute value of self without any wrapping or panicking.
+    #[inline]
+    pub fn unsigned_abs(self) -> NonZeroU256 {
+        NonZeroU256(self.0.unsigned_abs())
+    }
+
+    /// Checked negation. Computes `-self`, returning `None` if
+    /// `self == NonZeroI256::MIN`.
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Returns `true` if `self` is negative and `false` if the number is
+    /// positive.
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Returns `true` if `self` is positive and `false` if the number is
+    /// negative.
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        !self.0.is_negative()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::size_of;
+
+    #[test]
+    fn new() {
+        assert_eq!(NonZeroU256::new(U256::ZERO), None);
+        assert_eq!(NonZeroI256::new(I256::ZERO), None);
+        assert_eq!(NonZeroU256::new(U256::ONE).unwrap().get(), 1);
+        assert_eq!(NonZeroI256::new(I256::MINUS_ONE).unwrap().get(), -1);
+        assert_eq!(
+            NonZeroU256::new(U256::ONE << 128u32).unwrap().get(),
+            U256::ONE << 128u32
+        );
+
+        assert_eq!(NonZeroU256::MIN.get(), 1);
+        assert_eq!(NonZeroU256::MAX.get(), U256::MAX);
+        assert_eq!(NonZeroI256::MIN.get(), I256::MIN);
+        assert_eq!(NonZeroI256::MAX.get(), I256::MAX);
+
+        assert!(NonZeroU256::try_from(U256::ZERO).is_err());
+        assert_eq!(
+            U256::from(NonZeroU256::try_from(U256::MAX).unwrap()),
+            U256::MAX
+        );
+
+        assert_eq!(size_of::<NonZeroU256>(), size_of::<U256>());
+    }
+
+    #[test]
+    fn from_primitive() {
+        assert_eq!(
+            NonZeroU256::from(NonZeroU128::new(u128::MAX).unwrap()).get(),
+            u128::MAX
+        );
+        assert_eq!(NonZeroU256::from(NonZeroU8::new(42).unwrap()).get(), 42);
+        assert_eq!(
+            NonZeroI256::from(NonZeroI128::new(i128::MIN).unwrap()).get(),
+            i128::MIN
+        );
+        assert_eq!(NonZeroI256::from(NonZeroI8::new(-1).unwrap()).get(), -1);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let two = NonZeroU256::new(U256::new(2)).unwrap();
+        let big = NonZeroU256::new(U256::ONE << 255u32).unwrap();
+        assert_eq!(two.checked_mul(two).unwrap().get(), 4);
+        assert_eq!(two.checked_mul(big), None);
+        assert_eq!(two.saturating_mul(big), NonZeroU256::MAX);
+        assert_eq!(two.checked_pow(255).unwrap(), big);
+        assert_eq!(two.checked_pow(256), None);
+        assert_eq!(NonZeroU256::MAX.checked_add(U256::ONE), None);
+        assert_eq!(NonZeroU256::MAX.saturating_add(U256::ONE), NonZeroU256::MAX);
+        assert!(big.is_power_of_two());
+        assert_eq!(big.leading_zeros(), 0);
+        assert_eq!(big.trailing_zeros(), 255);
+
+        assert_eq!(U256::new(7) / two, 3);
+        assert_eq!(U256::new(7) % two, 1);
+        let mut x = U256::new(7);
+        x /= two;
+        assert_eq!(x, 3);
+        x %= two;
+        assert_eq!(x, 1);
+
+        assert_eq!((U256::ZERO | two).get(), 2);
+        assert_eq!((two | big).get(), big.get() + 2);
+
+        let min = NonZeroI256::MIN;
+        assert_eq!(min.checked_abs(), None);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(min.unsigned_abs().get(), I256::MIN.unsigned_abs());
+        assert!(min.is_negative());
+        assert!(NonZeroI256::MAX.is_positive());
+        assert_eq!(NonZeroI256::new(I256::new(-3)).unwrap().abs().get(), 3);
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh() {
+        use borsh::{BorshDeserialize, BorshSerialize};
+
+        let x = NonZeroU256::new(U256::new(42)).unwrap();
+        let bytes = x.try_to_vec().unwrap();
+        assert_eq!(bytes, U256::new(42).try_to_vec().unwrap());
+        assert_eq!(NonZeroU256::try_from_slice(&bytes).unwrap(), x);
+
+        let zero = I256::ZERO.try_to_vec().unwrap();
+        assert!(NonZeroI256::try_from_slice(&zero).is_err());
+    }
+
+    #[cfg(feature = "borsh-v1")]
+    #[test]
+    fn borsh_v1() {
+        let x = NonZeroU256::new(U256::new(42)).unwrap();
+        let bytes = borsh_v1::to_vec(&x).unwrap();
+        assert_eq!(bytes, borsh_v1::to_vec(&U256::new(42)).unwrap());
+        assert_eq!(borsh_v1::from_slice::<NonZeroU256>(&bytes).unwrap(), x);
+
+        let zero = borsh_v1::to_vec(&I256::ZERO).unwrap();
+        assert!(borsh_v1::from_slice::<NonZeroI256>(&zero).is_err());
+    }
+}
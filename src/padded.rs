@@ -0,0 +1,259 @@
+//! Module containing fixed width decimal strings of 256-bit integers.
+//!
+//! Some databases and search engines can only sort keys as strings. Padding
+//! the decimal representation with leading zeros to [`PADDED_LEN`] digits,
+//! enough for any [`U256`], makes string order the same as numeric order.
+//!
+//! Signed integers are offset by 2<sup>255</sup> before being padded, so
+//! [`I256::MIN`] is rendered as all zeros, [`I256::ZERO`] as the padded
+//! decimal representation of 2<sup>255</sup> and [`I256::MAX`] as that of
+//! [`U256::MAX`]. This keeps the strings free of sign characters, which
+//! would otherwise sort negative values in the wrong order.
+//!
+//! # Examples
+//!
+//! ```
+//! # use ethnum::{padded::PADDED_LEN, I256};
+//! let mut keys = [I256::new(10), I256::new(-1), I256::new(9), I256::MIN];
+//! keys.sort_by(|a, b| {
+//!     let (mut x, mut y) = ([0; PADDED_LEN], [0; PADDED_LEN]);
+//!     a.write_padded(&mut x).cmp(b.write_padded(&mut y))
+//! });
+//! assert_eq!(keys, [I256::MIN, I256::new(-1), I256::new(9), I256::new(10)]);
+//! ```
+
+use crate::{error::pie, I256, U256};
+use core::{
+    fmt::{self, Write as _},
+    num::{IntErrorKind, ParseIntError},
+    str,
+};
+
+/// The length of a padded decimal string, which is the number of decimal
+/// digits of [`U256::MAX`].
+pub const PADDED_LEN: usize = 78;
+
+/// A formatter that writes into a fixed size stack buffer.
+struct PaddedWriter<'a> {
+    buffer: &'a mut [u8; PADDED_LEN],
+    len: usize,
+}
+
+impl fmt::Write for PaddedWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes an unsigned integer as a padded decimal string.
+fn write_padded(value: U256, buffer: &mut [u8; PADDED_LEN]) -> &str {
+    let mut writer = PaddedWriter { buffer, len: 0 };
+    write!(writer, "{value:0PADDED_LEN$}").expect("buffer too small for a 256-bit integer");
+    // SAFETY: The buffer is filled with ASCII decimal digits.
+    unsafe { str::from_utf8_unchecked(&buffer[..]) }
+}
+
+/// Parses a padded decimal string into an unsigned integer.
+fn from_padded_str(src: &str) -> Result<U256, ParseIntError> {
+    if src.is_empty() {
+        return Err(pie(IntErrorKind::Empty));
+    }
+    if src.len() != PADDED_LEN || !src.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(pie(IntErrorKind::InvalidDigit));
+    }
+    U256::from_str_radix(src, 10)
+}
+
+/// Offsets a signed integer by 2^255, preserving its order.
+fn to_offset(value: I256) -> U256 {
+    let (hi, lo) = value.into_words();
+    U256::from_words(hi as u128 ^ (1 << 127), lo as u128)
+}
+
+/// Reverts [`to_offset`].
+fn from_offset(value: U256) -> I256 {
+    let (hi, lo) = value.into_words();
+    I256::from_words((hi ^ (1 << 127)) as i128, lo as i128)
+}
+
+impl U256 {
+    /// Writes the integer as a decimal string left-padded with zeros to
+    /// [`PADDED_LEN`] digits, returning the written string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::{padded::PADDED_LEN, U256};
+    /// let mut buffer = [0; PADDED_LEN];
+    /// let padded = U256::new(42).write_padded(&mut buffer);
+    /// assert_eq!(padded.len(), PADDED_LEN);
+    /// assert!(padded.starts_with("000") && padded.ends_with("042"));
+    /// ```
+    pub fn write_padded(self, buffer: &mut [u8; PADDED_LEN]) -> &str {
+        write_padded(self, buffer)
+    }
+
+    /// Returns the integer as a decimal string left-padded with zeros to
+    /// [`PADDED_LEN`] digits.
+    #[cfg(feature = "alloc")]
+    pub fn to_padded_string(self) -> alloc::string::String {
+        self.write_padded(&mut [0; PADDED_LEN]).into()
+    }
+
+    /// Parses a decimal string left-padded with zeros to [`PADDED_LEN`]
+    /// digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not exactly [`PADDED_LEN`] ASCII
+    /// digits, or is out of range.
+    pub fn from_padded_str(src: &str) -> Result<Self, ParseIntError> {
+        from_padded_str(src)
+    }
+}
+
+impl I256 {
+    /// Writes the integer offset by 2<sup>255</sup> as a decimal string
+    /// left-padded with zeros to [`PADDED_LEN`] digits, returning the written
+    /// string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::{padded::PADDED_LEN, I256};
+    /// let mut buffer = [0; PADDED_LEN];
+    /// assert_eq!(I256::MIN.write_padded(&mut buffer), "0".repeat(PADDED_LEN));
+    /// ```
+    pub fn write_padded(self, buffer: &mut [u8; PADDED_LEN]) -> &str {
+        write_padded(to_offset(self), buffer)
+    }
+
+    /// Returns the integer offset by 2<sup>255</sup> as a decimal string
+    /// left-padded with zeros to [`PADDED_LEN`] digits.
+    #[cfg(feature = "alloc")]
+    pub fn to_padded_string(self) -> alloc::string::String {
+        self.write_padded(&mut [0; PADDED_LEN]).into()
+    }
+
+    /// Parses a decimal string of the integer offset by 2<sup>255</sup> and
+    /// left-padded with zeros to [`PADDED_LEN`] digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not exactly [`PADDED_LEN`] ASCII
+    /// digits, or is out of range.
+    pub fn from_padded_str(src: &str) -> Result<Self, ParseIntError> {
+        from_padded_str(src).map(from_offset)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::{string::String, vec::Vec};
+    use rand::{rngs::StdRng, Rng as _, SeedableRng as _};
+
+    #[test]
+    fn round_trips() {
+        let mut buffer = [0; PADDED_LEN];
+        assert_eq!(
+            U256::MAX.write_padded(&mut buffer),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(
+            I256::ZERO.write_padded(&mut buffer),
+            "057896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+        assert_eq!(
+            I256::MAX.write_padded(&mut buffer),
+            U256::MAX.to_padded_string()
+        );
+
+        for value in [U256::ZERO, U256::ONE, U256::MAX] {
+            assert_eq!(U256::from_padded_str(&value.to_padded_string()), Ok(value));
+        }
+        for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            assert_eq!(I256::from_padded_str(&value.to_padded_string()), Ok(value));
+        }
+    }
+
+    #[test]
+    fn strict_parsing() {
+        let zeros = "0".repeat(PADDED_LEN);
+        assert_eq!(U256::from_padded_str(&zeros), Ok(U256::ZERO));
+        assert_eq!(U256::from_padded_str(""), Err(pie(IntErrorKind::Empty)));
+        for invalid in [
+            "42".into(),
+            String::from("+") + &zeros[1..],
+            String::from("-") + &zeros[1..],
+            zeros.clone() + "0",
+            zeros[1..].into(),
+        ] {
+            assert_eq!(
+                U256::from_padded_str(&invalid),
+                Err(pie(IntErrorKind::InvalidDigit)),
+                "{invalid}"
+            );
+        }
+        assert_eq!(
+            I256::from_padded_str(&"9".repeat(PADDED_LEN)),
+            Err(pie(IntErrorKind::PosOverflow))
+        );
+    }
+
+    #[test]
+    fn string_order_is_numeric_order() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut values = (0..1000)
+            .map(|i| {
+                let value = I256::from_words(rng.gen(), rng.gen());
+                // Shift some values down so that the set contains small
+                // integers on either side of zero.
+                match i % 3 {
+                    0 => value,
+                    1 => value >> rng.gen_range(128..256u32),
+                    _ => value >> 250u32,
+                }
+            })
+            .chain([I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::ONE, I256::MAX])
+            .collect::<Vec<_>>();
+
+        let mut strings = values
+            .iter()
+            .map(|value| value.to_padded_string())
+            .collect::<Vec<_>>();
+        strings.sort();
+        values.sort();
+        assert_eq!(
+            strings,
+            values
+                .iter()
+                .map(|value| value.to_padded_string())
+                .collect::<Vec<_>>()
+        );
+
+        let mut unsigned = values
+            .iter()
+            .map(|value| value.as_u256())
+            .collect::<Vec<_>>();
+        let mut strings = unsigned
+            .iter()
+            .map(|value| value.to_padded_string())
+            .collect::<Vec<_>>();
+        strings.sort();
+        unsigned.sort();
+        assert_eq!(
+            strings,
+            unsigned
+                .iter()
+                .map(|value| value.to_padded_string())
+                .collect::<Vec<_>>()
+        );
+    }
+}
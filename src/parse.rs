@@ -129,3 +129,9 @@ pub(crate) fn from_str_radix<T: FromStrRadixHelper>(
 pub(crate) fn from_str_prefixed<T: FromStrRadixHelper>(src: &str) -> Result<T, ParseIntError> {
     from_str_radix(src, 16, Some("0x")).or_else(|_| from_str_radix(src, 10, None))
 }
+
+pub(crate) fn from_hex<T: FromStrRadixHelper>(src: &str) -> Result<T, ParseIntError> {
+    let unsigned = src.strip_prefix(['+', '-']).unwrap_or(src);
+    let prefix = ["0x", "0X"].into_iter().find(|p| unsigned.starts_with(p));
+    from_str_radix(src, 16, prefix)
+}
@@ -0,0 +1,525 @@
+//! Module containing iterators over ranges of 256-bit integers.
+//!
+//! The standard library range types can't be iterated for the 256-bit integer
+//! types, as the `core::iter::Step` trait they rely on is unstable. Instead,
+//! [`U256::range`] and [`I256::range`] convert any range expression into an
+//! iterator that yields every integer in it.
+
+use crate::{AsU256 as _, I256, U256};
+use core::{
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds, RangeInclusive},
+};
+
+macro_rules! impl_range {
+    (
+        $(#[$attr:meta])*
+        $range:ident($int:ident) | $as_int:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        pub struct $range {
+            start: $int,
+            end: $int,
+            step: U256,
+            exhausted: bool,
+        }
+
+        impl $range {
+            fn new(range: impl RangeBounds<$int>) -> Self {
+                let empty = Self {
+                    start: $int::ONE,
+                    end: $int::ZERO,
+                    step: U256::ONE,
+                    exhausted: true,
+                };
+                let start = match range.start_bound() {
+                    Bound::Included(start) => *start,
+                    Bound::Excluded(start) => match start.checked_add($int::ONE) {
+                        Some(start) => start,
+                        None => return empty,
+                    },
+                    Bound::Unbounded => $int::MIN,
+                };
+                let end = match range.end_bound() {
+                    Bound::Included(end) => *end,
+                    Bound::Excluded(end) => match end.checked_sub($int::ONE) {
+                        Some(end) => end,
+                        None => return empty,
+                    },
+                    Bound::Unbounded => $int::MAX,
+                };
+                Self {
+                    start,
+                    end,
+                    step: U256::ONE,
+                    exhausted: start > end,
+                }
+            }
+
+            /// Returns an iterator that yields the first remaining value and
+            /// then every `step`-th value after it.
+            ///
+            /// Unlike [`Iterator::step_by`], the step is a 256-bit integer.
+            /// Stepping an already stepped range multiplies the steps, and
+            /// the range is exhausted after its first value if the product
+            /// overflows.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `step` is zero.
+            pub fn step_by(self, step: U256) -> Self {
+                assert!(step != 0, "assertion failed: step != 0");
+                let Some(step) = self.step.checked_mul(step) else {
+                    // NOTE: No two values of the range are this far apart, so
+                    // only the first one is left.
+                    return Self {
+                        end: self.start,
+                        step: U256::MAX,
+                        ..self
+                    };
+                };
+                let Some(distance) = self.distance() else {
+                    return Self { step, ..self };
+                };
+                // NOTE: Move the end to the last value that is actually
+                // yielded, so that iterating from the back lines up with
+                // iterating from the front.
+                let distance = distance - distance % step;
+                Self {
+                    end: self.start.wrapping_add(distance.$as_int()),
+                    step,
+                    ..self
+                }
+            }
+
+            /// Returns the number of remaining values, or `None` if it
+            /// doesn't fit in a `usize`.
+            pub fn try_len(&self) -> Option<usize> {
+                match self.steps() {
+                    Some(steps) => usize::try_from(steps).ok()?.checked_add(1),
+                    None => Some(0),
+                }
+            }
+
+            /// Returns `true` if `value` is yielded by the remaining range.
+            pub fn contains(&self, value: &$int) -> bool {
+                !self.exhausted
+                    && (self.start..=self.end).contains(value)
+                    && value.wrapping_sub(self.start).as_u256() % self.step == 0
+            }
+
+            /// Returns the difference between the last and first remaining
+            /// values, or `None` if the range is exhausted.
+            fn distance(&self) -> Option<U256> {
+                (!self.exhausted).then(|| self.end.wrapping_sub(self.start).as_u256())
+            }
+
+            /// Returns the number of remaining values minus one, or `None` if
+            /// the range is exhausted.
+            fn steps(&self) -> Option<U256> {
+                Some(self.distance()? / self.step)
+            }
+
+            /// Skips `n` values from the front, returning `false` and
+            /// exhausting the range if there are not enough values.
+            fn advance(&mut self, n: usize) -> bool {
+                match self.steps() {
+                    Some(steps) if n.as_u256() <= steps => {
+                        let offset = n.as_u256() * self.step;
+                        self.start = self.start.wrapping_add(offset.$as_int());
+                        true
+                    }
+                    _ => {
+                        self.exhausted = true;
+                        false
+                    }
+                }
+            }
+
+            /// Skips `n` values from the back, returning `false` and
+            /// exhausting the range if there are not enough values.
+            fn advance_back(&mut self, n: usize) -> bool {
+                match self.steps() {
+                    Some(steps) if n.as_u256() <= steps => {
+                        let offset = n.as_u256() * self.step;
+                        self.end = self.end.wrapping_sub(offset.$as_int());
+                        true
+                    }
+                    _ => {
+                        self.exhausted = true;
+                        false
+                    }
+                }
+            }
+        }
+
+        impl Iterator for $range {
+            type Item = $int;
+
+            #[inline]
+            fn next(&mut self) -> Option<$int> {
+                let steps = self.steps()?;
+                let value = self.start;
+                if steps == 0 {
+                    self.exhausted = true;
+                } else {
+                    self.start = self.start.wrapping_add(self.step.$as_int());
+                }
+                Some(value)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.try_len();
+                (len.unwrap_or(usize::MAX), len)
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<$int> {
+                if self.advance(n) {
+                    self.next()
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn last(mut self) -> Option<$int> {
+                self.next_back()
+            }
+
+            #[inline]
+            fn min(mut self) -> Option<$int> {
+                self.next()
+            }
+
+            #[inline]
+            fn max(mut self) -> Option<$int> {
+                self.next_back()
+            }
+        }
+
+        impl DoubleEndedIterator for $range {
+            #[inline]
+            fn next_back(&mut self) -> Option<$int> {
+                let steps = self.steps()?;
+                let value = self.end;
+                if steps == 0 {
+                    self.exhausted = true;
+                } else {
+                    self.end = self.end.wrapping_sub(self.step.$as_int());
+                }
+                Some(value)
+            }
+
+            #[inline]
+            fn nth_back(&mut self, n: usize) -> Option<$int> {
+                if self.advance_back(n) {
+                    self.next_back()
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl FusedIterator for $range {}
+    };
+}
+
+impl U256 {
+    /// Returns an iterator over all `U256` values in a range.
+    ///
+    /// Any range expression is accepted, including ones with excluded or
+    /// unbounded ends. Unlike iterating primitive integer ranges, this works
+    /// for ranges whose length exceeds `usize::MAX`: the `size_hint` saturates
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let values = U256::range(U256::new(1)..U256::new(4))
+    ///     .map(|x| x.as_u32())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(values, [1, 2, 3]);
+    /// assert_eq!(U256::range(..).next_back(), Some(U256::MAX));
+    /// ```
+    pub fn range(range: impl RangeBounds<U256>) -> U256Range {
+        U256Range::new(range)
+    }
+
+    /// Returns an iterator over an inclusive range of `U256` values.
+    ///
+    /// Inclusive ranges can end at [`U256::MAX`], which can't be expressed
+    /// with an exclusive end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let range = U256::iter_range(U256::MAX - 4..=U256::MAX).step_by(U256::new(2));
+    /// assert!(range.contains(&(U256::MAX - 2)));
+    /// assert!(!range.contains(&(U256::MAX - 1)));
+    /// assert_eq!(range.try_len(), Some(3));
+    /// assert_eq!(range.last(), Some(U256::MAX));
+    /// ```
+    pub fn iter_range(range: RangeInclusive<U256>) -> U256Range {
+        U256Range::new(range)
+    }
+}
+
+impl I256 {
+    /// Returns an iterator over all `I256` values in a range.
+    ///
+    /// See [`U256::range`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::I256;
+    /// let values = I256::range(I256::new(-1)..=I256::new(1))
+    ///     .map(|x| x.as_i32())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(values, [-1, 0, 1]);
+    /// assert_eq!(I256::range(..).next(), Some(I256::MIN));
+    /// ```
+    pub fn range(range: impl RangeBounds<I256>) -> I256Range {
+        I256Range::new(range)
+    }
+
+    /// Returns an iterator over an inclusive range of `I256` values.
+    ///
+    /// See [`U256::iter_range`] for more details.
+    pub fn iter_range(range: RangeInclusive<I256>) -> I256Range {
+        I256Range::new(range)
+    }
+}
+
+impl_range! {
+    /// An iterator over a range of [`U256`] values, created with
+    /// [`U256::range`] or [`U256::iter_range`].
+    U256Range(U256) | as_u256
+}
+
+impl_range! {
+    /// An iterator over a range of [`I256`] values, created with
+    /// [`I256::range`] or [`I256::iter_range`].
+    I256Range(I256) | as_i256
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn small_ranges() {
+        let values = U256::range(U256::new(3)..U256::new(7)).collect::<Vec<_>>();
+        assert_eq!(values, [3, 4, 5, 6]);
+        let values = U256::range(U256::new(3)..=U256::new(7))
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(values, [7, 6, 5, 4, 3]);
+        let values = I256::range(I256::new(-2)..=I256::new(2)).collect::<Vec<_>>();
+        assert_eq!(values, [-2, -1, 0, 1, 2]);
+
+        let mut range = I256::range(..I256::MIN + 3);
+        assert_eq!(range.size_hint(), (3, Some(3)));
+        assert_eq!(range.next(), Some(I256::MIN));
+        assert_eq!(range.next_back(), Some(I256::MIN + 2));
+        assert_eq!(range.size_hint(), (1, Some(1)));
+        assert_eq!(range.next(), Some(I256::MIN + 1));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+
+        let values = U256::range((Bound::Excluded(U256::ONE), Bound::Excluded(U256::new(4))))
+            .collect::<Vec<_>>();
+        assert_eq!(values, [2, 3]);
+    }
+
+    #[test]
+    fn empty_ranges() {
+        let five = U256::new(5);
+        assert_eq!(U256::range(five..five).next(), None);
+        assert_eq!(U256::range(five..five).size_hint(), (0, Some(0)));
+        assert_eq!(U256::range(five..=U256::ONE).next_back(), None);
+        assert_eq!(U256::range(..U256::ZERO).next(), None);
+        assert_eq!(
+            U256::range((Bound::Excluded(U256::MAX), Bound::Unbounded)).next(),
+            None
+        );
+        assert_eq!(I256::range(..I256::MIN).next_back(), None);
+        assert_eq!(I256::range(I256::ONE..I256::MINUS_ONE).count(), 0);
+    }
+
+    #[test]
+    fn ranges_at_the_limits() {
+        let values = U256::range(U256::MAX - 1..=U256::MAX).collect::<Vec<_>>();
+        assert_eq!(values, [U256::MAX - 1, U256::MAX]);
+        let values = I256::range(I256::MAX - 1..).rev().collect::<Vec<_>>();
+        assert_eq!(values, [I256::MAX, I256::MAX - 1]);
+    }
+
+    #[test]
+    fn ranges_longer_than_usize() {
+        let mut range = U256::range(..);
+        assert_eq!(range.size_hint(), (usize::MAX, None));
+        assert_eq!(range.next(), Some(U256::ZERO));
+        assert_eq!(range.next_back(), Some(U256::MAX));
+        assert_eq!(
+            range.nth(usize::MAX),
+            Some(U256::new(usize::MAX as u128 + 1))
+        );
+        assert_eq!(range.nth_back(1), Some(U256::MAX - 2));
+
+        let mut range = I256::range(I256::MIN..=I256::MAX);
+        assert_eq!(range.size_hint(), (usize::MAX, None));
+        assert_eq!(range.clone().last(), Some(I256::MAX));
+        assert_eq!(range.nth(2), Some(I256::MIN + 2));
+
+        // Exactly `usize::MAX + 1` values.
+        let range = U256::range(..=U256::new(usize::MAX as u128));
+        assert_eq!(range.size_hint(), (usize::MAX, None));
+        let range = U256::range(..U256::new(usize::MAX as u128));
+        assert_eq!(range.size_hint(), (usize::MAX, Some(usize::MAX)));
+    }
+
+    #[test]
+    fn nth() {
+        let mut range = I256::range(I256::new(-10)..I256::new(10));
+        assert_eq!(range.next(), Some(I256::new(-10)));
+        assert_eq!(range.nth(5), Some(I256::new(-4)));
+        assert_eq!(range.nth_back(3), Some(I256::new(6)));
+        assert_eq!(range.size_hint(), (9, Some(9)));
+        assert_eq!(range.nth(9), None);
+        assert_eq!(range.next(), None);
+
+        let mut range = U256::range(U256::ZERO..U256::new(3));
+        assert_eq!(range.nth(2), Some(U256::new(2)));
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn inclusive_ranges_ending_at_max() {
+        let values = U256::iter_range(U256::MAX - 2..=U256::MAX).collect::<Vec<_>>();
+        assert_eq!(values, [U256::MAX - 2, U256::MAX - 1, U256::MAX]);
+        let mut range = U256::iter_range(U256::MAX..=U256::MAX);
+        assert_eq!(range.next(), Some(U256::MAX));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+
+        let values = U256::iter_range(U256::MAX - 3..=U256::MAX)
+            .step_by(U256::new(3))
+            .collect::<Vec<_>>();
+        assert_eq!(values, [U256::MAX - 3, U256::MAX]);
+        let values = I256::iter_range(I256::MAX - 5..=I256::MAX)
+            .step_by(U256::new(2))
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(values, [I256::MAX - 1, I256::MAX - 3, I256::MAX - 5]);
+
+        let values = U256::iter_range(U256::ZERO..=U256::MAX)
+            .step_by(U256::ONE << 255u32)
+            .collect::<Vec<_>>();
+        assert_eq!(values, [U256::ZERO, U256::ONE << 255u32]);
+        let values = I256::iter_range(I256::MIN..=I256::MAX)
+            .step_by(U256::MAX)
+            .collect::<Vec<_>>();
+        assert_eq!(values, [I256::MIN, I256::MAX]);
+    }
+
+    #[test]
+    fn step_by() {
+        let range = U256::iter_range(U256::new(10)..=U256::new(20)).step_by(U256::new(4));
+        assert_eq!(range.clone().collect::<Vec<_>>(), [10, 14, 18]);
+        assert_eq!(range.clone().rev().collect::<Vec<_>>(), [18, 14, 10]);
+        assert_eq!(range.size_hint(), (3, Some(3)));
+        assert_eq!(range.clone().nth(1), Some(U256::new(14)));
+        assert_eq!(range.clone().nth(3), None);
+        assert_eq!(range.clone().nth_back(2), Some(U256::new(10)));
+
+        let range = range.step_by(U256::new(2));
+        assert_eq!(range.collect::<Vec<_>>(), [10, 18]);
+
+        let stride = U256::ONE << 200u32;
+        let mut range = U256::range(..).step_by(stride);
+        assert_eq!(range.try_len(), Some(1 << 56));
+        assert_eq!(range.next(), Some(U256::ZERO));
+        assert_eq!(range.next_back(), Some(U256::MAX - (stride - 1)));
+
+        let values = U256::range(..U256::new(10))
+            .step_by(U256::MAX)
+            .step_by(U256::MAX)
+            .collect::<Vec<_>>();
+        assert_eq!(values, [0]);
+        let range = U256::range(..)
+            .step_by(U256::new(3))
+            .step_by(U256::ONE << 255u32);
+        assert_eq!(range.try_len(), Some(1));
+        assert_eq!(range.clone().collect::<Vec<_>>(), [0]);
+        assert_eq!(range.rev().collect::<Vec<_>>(), [0]);
+        let range = I256::range(..).step_by(U256::MAX).step_by(U256::new(2));
+        assert!(!range.contains(&I256::MAX));
+        assert_eq!(range.collect::<Vec<_>>(), [I256::MIN]);
+        let mut range = I256::iter_range(I256::new(-6)..=I256::new(6)).step_by(U256::new(5));
+        assert_eq!(range.next_back(), Some(I256::new(4)));
+        assert_eq!(range.next(), Some(I256::new(-6)));
+        assert_eq!(range.next(), Some(I256::new(-1)));
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "step != 0")]
+    fn step_by_zero() {
+        let _ = U256::range(..).step_by(U256::ZERO);
+    }
+
+    #[test]
+    fn try_len() {
+        assert_eq!(U256::iter_range(U256::ONE..=U256::ZERO).try_len(), Some(0));
+        assert_eq!(U256::iter_range(U256::ZERO..=U256::ZERO).try_len(), Some(1));
+        assert_eq!(I256::range(I256::new(-5)..I256::new(5)).try_len(), Some(10));
+        assert_eq!(U256::range(..).try_len(), None);
+        assert_eq!(
+            U256::range(..U256::new(usize::MAX as u128)).try_len(),
+            Some(usize::MAX)
+        );
+        assert_eq!(
+            U256::range(..=U256::new(usize::MAX as u128)).try_len(),
+            None
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let mut range = I256::iter_range(I256::new(-10)..=I256::new(10)).step_by(U256::new(5));
+        for value in [-10, -5, 0, 5, 10] {
+            assert!(range.contains(&I256::new(value)), "{value}");
+        }
+        for value in [-11, -9, 1, 11, 15] {
+            assert!(!range.contains(&I256::new(value)), "{value}");
+        }
+        range.next();
+        assert!(!range.contains(&I256::new(-10)));
+        assert!(range.contains(&I256::new(-5)));
+        range.by_ref().for_each(drop);
+        assert!(!range.contains(&I256::new(10)));
+
+        assert!(U256::range(..).contains(&U256::MAX));
+        assert!(!U256::range(..U256::MAX).contains(&U256::MAX));
+        assert!(!U256::iter_range(U256::ONE..=U256::ZERO).contains(&U256::ZERO));
+    }
+
+    #[test]
+    fn fused() {
+        fn assert_fused<T: FusedIterator>(_: &T) {}
+        let mut range = U256::iter_range(U256::ZERO..=U256::ONE);
+        assert_fused(&range);
+        assert_eq!(range.by_ref().count(), 2);
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+        assert_fused(&I256::range(..));
+    }
+}
@@ -1,9 +1,20 @@
 //! Serde serialization implementation for 256-bit integer types.
 //!
-//! This implementation is very JSON-centric in that it serializes the integer
-//! types as `QUANTITIES` as specified in the Ethereum RPC. That is, integers
-//! are encoded as `"0x"` prefixed strings without extrenuous leading `0`s. For
-//! negative signed integers, the string is prefixed with a `"-"` sign.
+//! By default, the integer types are serialized as decimal strings, with a
+//! `"-"` sign prefix for negative signed integers. This matches the
+//! representation used by the `cosmwasm-std` integer types. Deserialization
+//! additionally accepts native integers, such as JSON numbers or MessagePack
+//! integers, as long as they are in range for the target type. This includes
+//! large JSON numbers when `serde_json` is built with its `arbitrary_precision`
+//! feature.
+//!
+//! When the `serde-binary` feature is enabled, the default implementations
+//! instead serialize integers as exactly 32 little endian bytes (the same as
+//! [`bytes::le`]) for formats that are not human-readable, as indicated by
+//! [`Serializer::is_human_readable`]. This is a lot more compact for binary
+//! formats, such as `bincode` and `postcard`, while human-readable formats,
+//! such as JSON, still use decimal strings. This representation is stable,
+//! but it is opt-in since it changes the wire format of binary encodings.
 //!
 //! Note that this module contains alternative serialization schemes that can
 //! be used with `#[serde(with = "...")]`.
@@ -15,23 +26,41 @@
 //! ```text
 //! #[derive(Deserialize, Serialize)]
 //! struct Example {
-//!     a: U256, // "0x2a"
+//!     a: U256, // "42"
 //!     #[serde(with = "ethnum::serde::decimal")]
 //!     b: I256, // "-42"
 //!     #[serde(with = "ethnum::serde::prefixed")]
 //!     c: U256, // "0x2a" or "42"
 //!     #[serde(with = "ethnum::serde::permissive")]
 //!     d: I256, // "-0x2a" or "-42" or -42
+//!     #[serde(with = "ethnum::serde::quantity")]
+//!     e: U256, // "0x2a"
 //!     #[serde(with = "ethnum::serde::bytes::be")]
-//!     e: U256, // [0x2a, 0x00, ..., 0x00]
+//!     f: U256, // [0x2a, 0x00, ..., 0x00]
 //!     #[serde(with = "ethnum::serde::bytes::le")]
-//!     f: I256, // [0xd6, 0xff, ..., 0xff]
+//!     g: I256, // [0xd6, 0xff, ..., 0xff]
 //!     #[serde(with = "ethnum::serde::compressed_bytes::be")]
-//!     g: U256, // [0x2a]
+//!     h: U256, // [0x2a]
 //!     #[serde(with = "ethnum::serde::compressed_bytes::le")]
-//!     h: I256, // [0xd6]
+//!     i: I256, // [0xd6]
+//!     #[serde(default, with = "ethnum::serde::decimal::option")]
+//!     j: Option<U256>, // "42" or null
+//!     #[serde(with = "ethnum::serde::hex::padded")]
+//!     k: I256, // "0xffff...ffd6"
+//!     #[serde(with = "ethnum::serde::base64::be")]
+//!     l: U256, // "AAAA...ACo="
+//!     #[serde(with = "ethnum::serde::decimal_fraction")]
+//!     m: U256, // "0.000000000000000042"
+//!     #[serde(with = "ethnum::serde::bson_binary")]
+//!     n: U256, // BSON Binary [0x00, ..., 0x2a]
+//!     #[serde(with = "ethnum::serde::words")]
+//!     o: U256, // [42, 0]
 //! }
 //! ```
+//!
+//! Each of the serialization modules also has an `option` submodule for use
+//! with `Option` fields, as well as a `vec` submodule for use with `Vec` fields
+//! when the `alloc` feature is enabled.
 
 use crate::{int::I256, uint::U256};
 use core::{
@@ -44,14 +73,194 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
+/// Generates the `option` and `vec` submodules for a serialization module.
+macro_rules! submodules {
+    ($($bound:tt)*) => {
+        option_module!($($bound)*);
+        #[cfg(feature = "alloc")]
+        vec_module!($($bound)*);
+    };
+}
+
+/// Generates an `option` submodule for a serialization module, which allows it
+/// to be used with `Option` fields.
+macro_rules! option_module {
+    ($($bound:tt)*) => {
+        /// Module for serializing optional 256-bit integers in the same format
+        /// as the parent module, for use with `Option` fields.
+        ///
+        /// `None` values are serialized as `none` (`null` in JSON). In order to
+        /// also allow missing fields, add `#[serde(default)]` to the field.
+        pub mod option {
+            use super::*;
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            #[doc(hidden)]
+            pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: $($bound)*,
+                S: Serializer,
+            {
+                struct Wrapped<'a, T>(&'a T);
+
+                impl<T> Serialize for Wrapped<'_, T>
+                where
+                    T: $($bound)*,
+                {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        super::serialize(self.0, serializer)
+                    }
+                }
+
+                match value {
+                    Some(value) => serializer.serialize_some(&Wrapped(value)),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            #[doc(hidden)]
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+            where
+                T: $($bound)*,
+                D: Deserializer<'de>,
+            {
+                struct Wrapped<T>(T);
+
+                impl<'de, T> Deserialize<'de> for Wrapped<T>
+                where
+                    T: $($bound)*,
+                {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        super::deserialize(deserializer).map(Wrapped)
+                    }
+                }
+
+                Ok(Option::<Wrapped<T>>::deserialize(deserializer)?.map(|value| value.0))
+            }
+        }
+    };
+}
+
+/// Generates a `vec` submodule for a serialization module, which allows it to
+/// be used with `Vec` fields.
+#[cfg(feature = "alloc")]
+macro_rules! vec_module {
+    ($($bound:tt)*) => {
+        /// Module for serializing sequences of 256-bit integers where each
+        /// element uses the same format as the parent module, for use with
+        /// `Vec` fields.
+        pub mod vec {
+            use super::*;
+            use alloc::vec::Vec;
+            use core::{
+                fmt::{self, Formatter},
+                marker::PhantomData,
+                mem,
+            };
+            use serde::{
+                de::{self, Visitor},
+                ser::SerializeSeq as _,
+                Deserialize, Deserializer, Serialize, Serializer,
+            };
+
+            #[doc(hidden)]
+            pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: $($bound)*,
+                S: Serializer,
+            {
+                struct Wrapped<'a, T>(&'a T);
+
+                impl<T> Serialize for Wrapped<'_, T>
+                where
+                    T: $($bound)*,
+                {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        super::serialize(self.0, serializer)
+                    }
+                }
+
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(&Wrapped(value))?;
+                }
+                seq.end()
+            }
+
+            struct Wrapped<T>(T);
+
+            impl<'de, T> Deserialize<'de> for Wrapped<T>
+            where
+                T: $($bound)*,
+            {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    super::deserialize(deserializer).map(Wrapped)
+                }
+            }
+
+            struct VecVisitor<T>(PhantomData<T>);
+
+            impl<'de, T> Visitor<'de> for VecVisitor<T>
+            where
+                T: $($bound)*,
+            {
+                type Value = Vec<T>;
+
+                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                    f.write_str("a sequence of 256-bit integers")
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: de::SeqAccess<'de>,
+                {
+                    // Don't trust size hints blindly, and cap the preallocation
+                    // to 1 MiB, same as `serde` does for its own `Vec` impl.
+                    const MAX_PREALLOCATION: usize = 1024 * 1024 / mem::size_of::<[u8; 32]>();
+                    let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATION);
+
+                    let mut values = Vec::with_capacity(capacity);
+                    while let Some(Wrapped(value)) = seq.next_element()? {
+                        values.push(value);
+                    }
+                    Ok(values)
+                }
+            }
+
+            #[doc(hidden)]
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+            where
+                T: $($bound)*,
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_seq(VecVisitor(PhantomData))
+            }
+        }
+    };
+}
+
 impl Serialize for I256 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut f = FormatBuffer::hex();
-        write!(f, "{self:-#x}").expect("unexpected formatting failure");
-        serializer.serialize_str(f.as_str())
+        #[cfg(feature = "serde-binary")]
+        if !serializer.is_human_readable() {
+            return bytes::le::serialize(self, serializer);
+        }
+        decimal::serialize(self, serializer)
     }
 }
 
@@ -60,9 +269,11 @@ impl Serialize for U256 {
     where
         S: Serializer,
     {
-        let mut f = FormatBuffer::hex();
-        write!(f, "{self:#x}").expect("unexpected formatting failure");
-        serializer.serialize_str(f.as_str())
+        #[cfg(feature = "serde-binary")]
+        if !serializer.is_human_readable() {
+            return bytes::le::serialize(self, serializer);
+        }
+        decimal::serialize(self, serializer)
     }
 }
 
@@ -71,7 +282,11 @@ impl<'de> Deserialize<'de> for I256 {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(FormatVisitor(Self::from_str_hex))
+        #[cfg(feature = "serde-binary")]
+        if !deserializer.is_human_readable() {
+            return bytes::le::deserialize(deserializer);
+        }
+        decimal::deserialize(deserializer)
     }
 }
 
@@ -80,7 +295,11 @@ impl<'de> Deserialize<'de> for U256 {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(FormatVisitor(Self::from_str_hex))
+        #[cfg(feature = "serde-binary")]
+        if !deserializer.is_human_readable() {
+            return bytes::le::deserialize(deserializer);
+        }
+        decimal::deserialize(deserializer)
     }
 }
 
@@ -88,10 +307,11 @@ impl<'de> Deserialize<'de> for U256 {
 /// decimal string serialization for 256-bit integer types.
 pub mod decimal {
     use super::*;
-    use core::num::ParseIntError;
+    use crate::AsI256 as _;
+    use core::{marker::PhantomData, num::ParseIntError};
 
     #[doc(hidden)]
-    pub trait Decimal: Sized {
+    pub trait Decimal: TryFrom<I256> {
         fn from_str_decimal(src: &str) -> Result<Self, ParseIntError>;
         fn write_decimal(&self, f: &mut impl Write);
     }
@@ -125,27 +345,203 @@ pub mod decimal {
         serializer.serialize_str(f.as_str())
     }
 
+    struct DecimalVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for DecimalVisitor<T>
+    where
+        T: Decimal,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("integer or decimal string")
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            cast(v.as_i256())
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            cast(v.as_i256())
+        }
+
+        fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            cast(v.as_i256())
+        }
+
+        fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            cast(v.as_i256())
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            FormatVisitor(T::from_str_decimal).visit_str(v)
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            FormatVisitor(T::from_str_decimal).visit_bytes(v)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            visit_json_number(map, &self, T::from_str_decimal)
+        }
+    }
+
+    /// Deserializes a decimal string.
+    ///
+    /// Native integer values are also accepted for formats that support them,
+    /// such as JSON numbers or MessagePack integers. Human-readable formats are
+    /// deserialized with `deserialize_any`, while other formats are asked for
+    /// a string, since binary formats like `bincode` are not self-describing.
     #[doc(hidden)]
     pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
     where
         T: Decimal,
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(FormatVisitor(T::from_str_decimal))
+        let visitor = DecimalVisitor(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_str(visitor)
+        }
+    }
+
+    submodules!(Decimal);
+}
+
+/// Module for use with `#[serde(with = "ethnum::serde::decimal_fraction")]` to
+/// specify fixed-point decimal string serialization for 256-bit unsigned
+/// integers holding atomics with 18 fractional digits.
+///
+/// This matches the representation of `cosmwasm_std::Decimal256`, where the
+/// value is rendered as a decimal number with its trailing fractional zeros
+/// removed. For example, an atomics value of `1_500_000_000_000_000_000` is
+/// serialized as `"1.5"` and `1` is serialized as `"0.000000000000000001"`.
+///
+/// Deserialization accepts the same format with at most 18 fractional digits,
+/// and fails if the resulting atomics value would overflow.
+pub mod decimal_fraction {
+    use super::*;
+
+    /// The number of fractional digits.
+    const DECIMAL_PLACES: u32 = 18;
+
+    #[doc(hidden)]
+    pub trait DecimalFraction: Sized {
+        fn from_atomics(value: U256) -> Self;
+        fn to_atomics(&self) -> U256;
+    }
+
+    impl DecimalFraction for U256 {
+        fn from_atomics(value: U256) -> Self {
+            value
+        }
+        fn to_atomics(&self) -> U256 {
+            *self
+        }
+    }
+
+    /// Parses a string of decimal digits without a sign.
+    fn parse_digits(digits: &str) -> Option<U256> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        U256::from_str_radix(digits, 10).ok()
+    }
+
+    /// Parses a fixed-point decimal string into its atomics.
+    fn from_str_decimal_fraction(src: &str) -> Result<U256, &'static str> {
+        let (whole, fractional) = match src.split_once('.') {
+            Some((_, fractional)) if fractional.contains('.') => {
+                return Err("Unexpected number of dots")
+            }
+            Some((whole, fractional)) => (whole, Some(fractional)),
+            None => (src, None),
+        };
+
+        let whole = parse_digits(whole).ok_or("Error parsing whole")?;
+        let atomics = U256::from_units(whole, DECIMAL_PLACES).ok_or("Value too big")?;
+        let Some(fractional) = fractional else {
+            return Ok(atomics);
+        };
+
+        let exp = DECIMAL_PLACES
+            .checked_sub(fractional.len() as _)
+            .ok_or("Cannot parse more than 18 fractional digits")?;
+        let fractional = parse_digits(fractional).ok_or("Error parsing fractional")?;
+        let fractional = U256::from_units(fractional, exp).ok_or("Value too big")?;
+
+        atomics.checked_add(fractional).ok_or("Value too big")
+    }
+
+    #[doc(hidden)]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: DecimalFraction,
+        S: Serializer,
+    {
+        let (whole, fractional) = value.to_atomics().to_units(DECIMAL_PLACES);
+
+        let mut f = FormatBuffer::decimal_fraction();
+        write!(f, "{whole}").expect("unexpected formatting error");
+        if fractional != 0 {
+            let (mut fractional, mut width) = (fractional.as_u64(), DECIMAL_PLACES as usize);
+            while fractional % 10 == 0 {
+                fractional /= 10;
+                width -= 1;
+            }
+            write!(f, ".{fractional:0width$}").expect("unexpected formatting error");
+        }
+        serializer.serialize_str(f.as_str())
+    }
+
+    #[doc(hidden)]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: DecimalFraction,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FormatVisitor(|src: &str| {
+            from_str_decimal_fraction(src).map(T::from_atomics)
+        }))
     }
+
+    submodules!(DecimalFraction);
 }
 
 /// Module for use with `#[serde(with = "ethnum::serde::prefixed")]` to specify
 /// prefixed string serialization for 256-bit integer types.
 ///
-/// This allows serialization to look for an optional `0x` prefix to determine
-/// if it is a hexadecimal string or decimal string.
+/// This allows deserialization to look for an optional `0x` prefix to
+/// determine if it is a hexadecimal string or decimal string. Values are
+/// always serialized as decimal strings, same as the default implementation.
 pub mod prefixed {
     use super::*;
     use core::num::ParseIntError;
 
     #[doc(hidden)]
-    pub trait Prefixed: Serialize + Sized {
+    pub trait Prefixed: decimal::Decimal {
         fn from_str_prefixed(src: &str) -> Result<Self, ParseIntError>;
     }
 
@@ -167,7 +563,7 @@ pub mod prefixed {
         T: Prefixed,
         S: Serializer,
     {
-        value.serialize(serializer)
+        decimal::serialize(value, serializer)
     }
 
     #[doc(hidden)]
@@ -178,16 +574,26 @@ pub mod prefixed {
     {
         deserializer.deserialize_str(FormatVisitor(T::from_str_prefixed))
     }
+
+    submodules!(Prefixed);
 }
 
 /// Module for use with `#[serde(with = "ethnum::serde::permissive")]` to
 /// specify extremely permissive serialization for 256-bit integer types.
 ///
-/// This allows serialization to also accept standard numerical types as values
-/// in addition to prefixed strings.
+/// This allows deserialization to also accept standard numerical types as
+/// values in addition to prefixed strings. Values are always serialized as
+/// decimal strings, same as the default implementation.
+///
+/// Floating point numbers, such as `1e18` or `42.0` in JSON, are accepted if
+/// they are integers in the range of the target type, and are converted to the
+/// exact integer they represent. Note that floating point numbers can't
+/// represent every integer larger than 2^53, so use strings for large values
+/// that need to be exact.
 pub mod permissive {
-    use super::{prefixed::Prefixed, FormatVisitor};
+    use super::{cast, prefixed::Prefixed, visit_json_number, FormatVisitor};
     use crate::{AsI256 as _, I256, U256};
+    use core::cmp::Ordering;
     use core::fmt::{self, Formatter};
     use core::marker::PhantomData;
     use serde::{
@@ -196,21 +602,11 @@ pub mod permissive {
     };
 
     #[doc(hidden)]
-    pub trait Permissive: Prefixed {
-        fn cast(value: I256) -> Self;
-    }
+    pub trait Permissive: Prefixed + TryFrom<I256> + TryFrom<U256> {}
 
-    impl Permissive for I256 {
-        fn cast(value: I256) -> Self {
-            value
-        }
-    }
+    impl Permissive for I256 {}
 
-    impl Permissive for U256 {
-        fn cast(value: I256) -> Self {
-            value.as_u256()
-        }
-    }
+    impl Permissive for U256 {}
 
     #[doc(hidden)]
     pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
@@ -218,7 +614,77 @@ pub mod permissive {
         T: Permissive,
         S: Serializer,
     {
-        value.serialize(serializer)
+        super::decimal::serialize(value, serializer)
+    }
+
+    /// Converts a floating point number to an integer exactly, returning an
+    /// error if it is not an integer or is out of range.
+    ///
+    /// The conversion decomposes the number into its mantissa and exponent, so
+    /// large numbers such as `1e18` convert to the exact integer they
+    /// represent, without any rounding.
+    fn from_f64<T, E>(v: f64) -> Result<T, E>
+    where
+        T: Permissive,
+        E: de::Error,
+    {
+        if !v.is_finite() {
+            return Err(E::custom(format_args!(
+                "invalid conversion from non-finite floating point number {v} \
+                 to 256-bit integer"
+            )));
+        }
+
+        // The number is `mantissa * 2^exponent`, where subnormal numbers have
+        // the smallest exponent and no implicit leading one.
+        let bits = v.to_bits();
+        let (mantissa, exponent) = match ((bits >> 52) & 0x7ff) as i32 {
+            0 => (bits & ((1 << 52) - 1), -1074),
+            e => ((bits & ((1 << 52) - 1)) | (1 << 52), e - 1075),
+        };
+        let magnitude = if mantissa == 0 {
+            U256::ZERO
+        } else if exponent >= 0 {
+            if (64 - mantissa.leading_zeros()) as i32 + exponent > 256 {
+                return Err(out_of_range(v));
+            }
+            U256::from(mantissa) << exponent as u32
+        } else {
+            let shift = exponent.unsigned_abs();
+            if shift >= 64 || mantissa & ((1 << shift) - 1) != 0 {
+                return Err(E::custom(format_args!(
+                    "invalid conversion from floating point number {v} \
+                     with fractional part to 256-bit integer"
+                )));
+            }
+            U256::from(mantissa >> shift)
+        };
+
+        if v.is_sign_negative() && magnitude != 0 {
+            let value = match magnitude.cmp(&(U256::ONE << 255u32)) {
+                Ordering::Less => -magnitude.as_i256(),
+                Ordering::Equal => I256::MIN,
+                Ordering::Greater => return Err(out_of_range(v)),
+            };
+            T::try_from(value).map_err(|_| {
+                E::custom(format_args!(
+                    "invalid conversion from negative floating point number {v} \
+                     to unsigned 256-bit integer"
+                ))
+            })
+        } else {
+            T::try_from(magnitude).map_err(|_| out_of_range(v))
+        }
+    }
+
+    fn out_of_range<E>(v: f64) -> E
+    where
+        E: de::Error,
+    {
+        E::custom(format_args!(
+            "invalid conversion from floating point number {v:e} \
+             outside of the range of 256-bit integer"
+        ))
     }
 
     struct PermissiveVisitor<T>(PhantomData<T>);
@@ -237,42 +703,35 @@ pub mod permissive {
         where
             E: de::Error,
         {
-            Ok(T::cast(v.as_i256()))
+            cast(v.as_i256())
         }
 
         fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            Ok(T::cast(v.as_i256()))
+            cast(v.as_i256())
         }
 
         fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            Ok(T::cast(v.as_i256()))
+            cast(v.as_i256())
         }
 
         fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            Ok(T::cast(v.as_i256()))
+            cast(v.as_i256())
         }
 
         fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            const N: f32 = (1_u64 << 24) as _;
-            if !(-N..N).contains(&v) {
-                return Err(de::Error::custom(
-                    "invalid conversion from single precision floating point \
-                     number outside of valid integer range (-2^24, 2^24)",
-                ));
-            }
-
+            // Widening to `f64` is exact, so this accepts the same values.
             self.visit_f64(v as _)
         }
 
@@ -280,26 +739,7 @@ pub mod permissive {
         where
             E: de::Error,
         {
-            const N: f64 = (1_u64 << 53) as _;
-            if !(-N..N).contains(&v) {
-                return Err(de::Error::custom(
-                    "invalid conversion from double precision floating point \
-                     number outside of valid integer range (-2^53, 2^53)",
-                ));
-            }
-
-            // SOUNDNESS: `#[no_std]` does not have `f64::fract`, so work around
-            // it by casting to and from an integer type. This is sound because
-            // we already verified that the `f64` is within a "safe" range.
-            let i = v as i64;
-            if i as f64 != v {
-                return Err(de::Error::custom(
-                    "invalid conversion from floating point number \
-                     with fractional part to 256-bit integer",
-                ));
-            }
-
-            Ok(T::cast(i.as_i256()))
+            from_f64(v)
         }
 
         fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -308,6 +748,18 @@ pub mod permissive {
         {
             FormatVisitor(T::from_str_prefixed).visit_str(v)
         }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            visit_json_number(map, &self, |v| match v.parse::<f64>() {
+                Ok(f) if v.contains(['.', 'e', 'E']) => {
+                    PermissiveVisitor(PhantomData).visit_f64::<A::Error>(f)
+                }
+                _ => T::from_str_prefixed(v).map_err(de::Error::custom),
+            })
+        }
     }
 
     #[doc(hidden)]
@@ -318,34 +770,271 @@ pub mod permissive {
     {
         deserializer.deserialize_any(PermissiveVisitor(PhantomData))
     }
+
+    submodules!(Permissive);
 }
 
-/// Serde byte serialization for 256-bit integer types.
-pub mod bytes {
-    macro_rules! endianness {
-        ($name:literal; $to:ident, $from:ident) => {
-            use crate::{I256, U256};
-            use core::{
-                fmt::{self, Formatter},
-                marker::PhantomData,
-            };
-            use serde::{
-                de::{self, Deserializer, Visitor},
-                Serializer,
-            };
+/// Module for use with `#[serde(with = "ethnum::serde::quantity")]` to
+/// specify Ethereum JSON-RPC `QUANTITY` serialization for 256-bit integer
+/// types.
+///
+/// Values are serialized as `0x` prefixed hexadecimal strings without any
+/// extraneous leading zeros, with zero being encoded as `"0x0"`. Negative
+/// signed integers are additionally prefixed with a `-` sign.
+///
+/// Deserialization is strict and only accepts this exact format, so inputs
+/// such as `"0x"`, `"0x0123"`, `"123"` or the empty string are rejected. Both
+/// lowercase and uppercase hexadecimal digits are accepted.
+pub mod quantity {
+    use super::*;
+    use core::num::ParseIntError;
 
-            #[doc(hidden)]
-            pub trait Bytes: Sized + Copy {
-                fn to_bytes(self) -> [u8; 32];
-                fn from_bytes(bytes: [u8; 32]) -> Self;
-            }
+    #[doc(hidden)]
+    pub trait Quantity: Sized {
+        const SIGNED: bool;
+        fn from_str_hex(src: &str) -> Result<Self, ParseIntError>;
+        fn write_quantity(&self, f: &mut impl Write);
+    }
 
-            impl Bytes for I256 {
-                fn to_bytes(self) -> [u8; 32] {
-                    self.$to()
-                }
+    impl Quantity for I256 {
+        const SIGNED: bool = true;
+        fn from_str_hex(src: &str) -> Result<Self, ParseIntError> {
+            Self::from_str_hex(src)
+        }
+        fn write_quantity(&self, f: &mut impl Write) {
+            write!(f, "{self:-#x}").expect("unexpected formatting error")
+        }
+    }
 
-                fn from_bytes(bytes: [u8; 32]) -> Self {
+    impl Quantity for U256 {
+        const SIGNED: bool = false;
+        fn from_str_hex(src: &str) -> Result<Self, ParseIntError> {
+            Self::from_str_hex(src)
+        }
+        fn write_quantity(&self, f: &mut impl Write) {
+            write!(f, "{self:#x}").expect("unexpected formatting error")
+        }
+    }
+
+    /// Parses a strictly formatted `QUANTITY` string.
+    fn from_str_quantity<T, E>(src: &str) -> Result<T, E>
+    where
+        T: Quantity,
+        E: de::Error,
+    {
+        let (negative, unsigned) = match src.strip_prefix('-') {
+            Some(unsigned) if T::SIGNED => (true, unsigned),
+            _ => (false, src),
+        };
+        let valid = match unsigned.strip_prefix("0x").map(str::as_bytes) {
+            Some([b'0']) => !negative,
+            Some([b'0', ..]) | Some([]) | None => false,
+            Some(digits) => digits.iter().all(u8::is_ascii_hexdigit),
+        };
+        if !valid {
+            return Err(E::invalid_value(
+                de::Unexpected::Str(src),
+                &"a `0x` prefixed hexadecimal quantity without leading zeros",
+            ));
+        }
+
+        T::from_str_hex(src).map_err(E::custom)
+    }
+
+    #[doc(hidden)]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Quantity,
+        S: Serializer,
+    {
+        let mut f = FormatBuffer::hex();
+        value.write_quantity(&mut f);
+        serializer.serialize_str(f.as_str())
+    }
+
+    #[doc(hidden)]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Quantity,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FormatVisitor(from_str_quantity::<T, D::Error>))
+    }
+
+    submodules!(Quantity);
+}
+
+/// Serde fixed-width hexadecimal serialization for 256-bit integer types.
+///
+/// Values are serialized as `0x` followed by exactly 64 lowercase hexadecimal
+/// digits, the same way 32-byte words such as storage slots and hashes are
+/// represented in the Ethereum JSON-RPC. Signed integers are encoded in two's
+/// complement, matching how storage slots hold signed values, so `-1` is
+/// serialized as `"0xffff...ffff"`.
+///
+/// The [`hex::padded`] module only accepts this exact format when
+/// deserializing, while the [`hex::lenient`] module also accepts fewer digits
+/// and an optional `0x` or `0X` prefix. Both lowercase and uppercase digits
+/// are accepted.
+pub mod hex {
+    use super::*;
+
+    #[doc(hidden)]
+    pub trait Hex: Sized {
+        fn from_u256(value: U256) -> Self;
+        fn write_padded(&self, f: &mut impl Write);
+    }
+
+    impl Hex for I256 {
+        fn from_u256(value: U256) -> Self {
+            value.as_i256()
+        }
+        fn write_padded(&self, f: &mut impl Write) {
+            write!(f, "{:#066x}", self.as_u256()).expect("unexpected formatting error")
+        }
+    }
+
+    impl Hex for U256 {
+        fn from_u256(value: U256) -> Self {
+            value
+        }
+        fn write_padded(&self, f: &mut impl Write) {
+            write!(f, "{self:#066x}").expect("unexpected formatting error")
+        }
+    }
+
+    fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Hex,
+        S: Serializer,
+    {
+        let mut f = FormatBuffer::hex();
+        value.write_padded(&mut f);
+        serializer.serialize_str(f.as_str())
+    }
+
+    /// Parses a two's complement hexadecimal string.
+    fn from_str_hex<T, E>(src: &str, padded: bool) -> Result<T, E>
+    where
+        T: Hex,
+        E: de::Error,
+    {
+        let digits = match (padded, src.strip_prefix("0x")) {
+            (_, Some(digits)) => Some(digits),
+            (true, None) => None,
+            (false, None) => Some(src.strip_prefix("0X").unwrap_or(src)),
+        };
+        let valid = digits.filter(|digits| match padded {
+            true => digits.len() == 64,
+            false => (1..=64).contains(&digits.len()),
+        });
+        let digits = valid
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| {
+                E::invalid_value(
+                    de::Unexpected::Str(src),
+                    &match padded {
+                        true => "a `0x` prefixed string of 64 hexadecimal digits",
+                        false => "a string of at most 64 hexadecimal digits",
+                    },
+                )
+            })?;
+
+        U256::from_str_radix(digits, 16)
+            .map(T::from_u256)
+            .map_err(E::custom)
+    }
+
+    /// Module for use with `#[serde(with = "ethnum::serde::hex::padded")]` to
+    /// specify strict fixed-width hexadecimal serialization for 256-bit
+    /// integer types.
+    pub mod padded {
+        use super::*;
+
+        #[doc(hidden)]
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Hex,
+            S: Serializer,
+        {
+            super::serialize(value, serializer)
+        }
+
+        #[doc(hidden)]
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: Hex,
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(FormatVisitor(|src: &str| {
+                from_str_hex::<T, D::Error>(src, true)
+            }))
+        }
+
+        submodules!(Hex);
+    }
+
+    /// Module for use with `#[serde(with = "ethnum::serde::hex::lenient")]`
+    /// to specify fixed-width hexadecimal serialization for 256-bit integer
+    /// types, that also accepts unpadded and unprefixed hexadecimal strings.
+    pub mod lenient {
+        use super::*;
+
+        #[doc(hidden)]
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Hex,
+            S: Serializer,
+        {
+            super::serialize(value, serializer)
+        }
+
+        #[doc(hidden)]
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: Hex,
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(FormatVisitor(|src: &str| {
+                from_str_hex::<T, D::Error>(src, false)
+            }))
+        }
+
+        submodules!(Hex);
+    }
+}
+
+/// Serde byte serialization for 256-bit integer types.
+///
+/// Values are serialized as exactly 32 bytes using `serialize_bytes`, with
+/// signed integers in two's complement. Deserialization accepts either a byte
+/// buffer or a sequence of 32 `u8` values, since some formats (such as JSON)
+/// represent bytes as arrays.
+pub mod bytes {
+    macro_rules! endianness {
+        ($name:literal; $to:ident, $from:ident) => {
+            use crate::{I256, U256};
+            use core::{
+                fmt::{self, Formatter},
+                marker::PhantomData,
+            };
+            use serde::{
+                de::{self, Deserializer, Visitor},
+                Serializer,
+            };
+
+            #[doc(hidden)]
+            pub trait Bytes: Sized + Copy {
+                fn to_bytes(self) -> [u8; 32];
+                fn from_bytes(bytes: [u8; 32]) -> Self;
+            }
+
+            impl Bytes for I256 {
+                fn to_bytes(self) -> [u8; 32] {
+                    self.$to()
+                }
+
+                fn from_bytes(bytes: [u8; 32]) -> Self {
                     I256::$from(bytes)
                 }
             }
@@ -392,6 +1081,23 @@ pub mod bytes {
 
                     Ok(T::from_bytes(bytes))
                 }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: de::SeqAccess<'de>,
+                {
+                    let mut bytes = [0; 32];
+                    for (i, byte) in bytes.iter_mut().enumerate() {
+                        *byte = seq
+                            .next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                    }
+                    if seq.next_element::<u8>()?.is_some() {
+                        return Err(de::Error::invalid_length(33, &self));
+                    }
+
+                    Ok(T::from_bytes(bytes))
+                }
             }
 
             #[doc(hidden)]
@@ -402,6 +1108,8 @@ pub mod bytes {
             {
                 deserializer.deserialize_bytes(BytesVisitor(PhantomData))
             }
+
+            submodules!(Bytes);
         };
     }
 
@@ -420,17 +1128,203 @@ pub mod bytes {
     /// Module for use with `#[serde(with = "ethnum::serde::bytes::ne")]` to
     /// specify native endian byte serialization for 256-bit integer types.
     pub mod ne {
+        #[cfg(all(target_endian = "little", feature = "alloc"))]
+        #[doc(hidden)]
+        pub use super::le::vec;
         #[cfg(target_endian = "little")]
         #[doc(hidden)]
-        pub use super::le::{deserialize, serialize};
+        pub use super::le::{deserialize, option, serialize};
 
+        #[cfg(all(target_endian = "big", feature = "alloc"))]
+        #[doc(hidden)]
+        pub use super::be::vec;
         #[cfg(target_endian = "big")]
         #[doc(hidden)]
-        pub use super::be::{deserialize, serialize};
+        pub use super::be::{deserialize, option, serialize};
+    }
+}
+
+/// Module for use with `#[serde(with = "ethnum::serde::bson_binary")]` to
+/// specify BSON `Binary` serialization for 256-bit integer types.
+///
+/// MongoDB has no native 256-bit integer type, so this stores values as
+/// 32 big endian bytes, which the `bson` crate emits as a `Binary` with the
+/// generic subtype. Signed integers are in two's complement. Since MongoDB
+/// compares binary data of the same length byte by byte, the stored values of
+/// unsigned integers sort in numeric order.
+///
+/// This is the same format as [`bytes::be`]. Values stored this way must also
+/// be deserialized with this module: the default implementations only read
+/// bytes as a UTF-8 encoded decimal string, and never guess whether bytes are
+/// binary or decimal, since 32 ASCII digits are valid as both.
+pub mod bson_binary {
+    #[cfg(feature = "alloc")]
+    #[doc(hidden)]
+    pub use super::bytes::be::vec;
+    #[doc(hidden)]
+    pub use super::bytes::be::{deserialize, option, serialize};
+}
+
+/// Serde base64 serialization for 256-bit integer types.
+///
+/// Values are serialized as their 32-byte representation, encoded as a padded
+/// base64 string using the standard alphabet, as used for binary data in
+/// Cosmos and Tendermint JSON APIs. Signed integers are in two's complement.
+///
+/// Deserialization accepts both padded and unpadded strings, and fails if the
+/// decoded data is not exactly 32 bytes long.
+pub mod base64 {
+    /// The length of an encoded 32-byte value, including padding.
+    const ENCODED_LEN: usize = 44;
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    /// Encodes 32 bytes as a padded base64 string.
+    fn encode<'a>(bytes: &[u8; 32], buffer: &'a mut [u8; ENCODED_LEN]) -> &'a str {
+        let mut chunks = buffer.chunks_exact_mut(4);
+        for (input, output) in bytes.chunks(3).zip(&mut chunks) {
+            let word = input
+                .iter()
+                .enumerate()
+                .fold(0, |word, (i, &byte)| word | ((byte as u32) << (16 - 8 * i)));
+            for (i, char) in output.iter_mut().enumerate() {
+                *char = match i <= input.len() {
+                    true => ALPHABET[(word >> (18 - 6 * i)) as usize & 0x3f],
+                    false => b'=',
+                };
+            }
+        }
+
+        // SAFETY: The buffer only ever contains ASCII characters.
+        unsafe { core::str::from_utf8_unchecked(buffer) }
+    }
+
+    /// Decodes a padded or unpadded base64 string, returning `None` if it is
+    /// not valid base64 with canonical trailing bits. On success, returns the
+    /// decoded length, which may be larger than the buffer.
+    fn decode(src: &str, buffer: &mut [u8; 32]) -> Option<usize> {
+        let src = src.as_bytes();
+        let unpadded = match src {
+            [rest @ .., b'=', b'='] | [rest @ .., b'='] if src.len().is_multiple_of(4) => rest,
+            _ => src,
+        };
+        if unpadded.len() % 4 == 1 {
+            return None;
+        }
+
+        let mut len = 0;
+        for chunk in unpadded.chunks(4) {
+            let mut word = 0u32;
+            for (i, &char) in chunk.iter().enumerate() {
+                let sextet = ALPHABET.iter().position(|&c| c == char)?;
+                word |= (sextet as u32) << (18 - 6 * i);
+            }
+
+            let bytes = chunk.len() - 1;
+            if word & (0xffffff >> (8 * bytes)) != 0 {
+                return None;
+            }
+            for i in 0..bytes {
+                if let Some(byte) = buffer.get_mut(len) {
+                    *byte = (word >> (16 - 8 * i)) as u8;
+                }
+                len += 1;
+            }
+        }
+        Some(len)
+    }
+
+    macro_rules! endianness {
+        ($name:literal; $parent:ident) => {
+            use super::{decode, encode, ENCODED_LEN};
+            use crate::serde::bytes::$parent::Bytes;
+            use core::{
+                fmt::{self, Formatter},
+                marker::PhantomData,
+            };
+            use serde::{
+                de::{self, Deserializer, Visitor},
+                Serializer,
+            };
+
+            #[doc(hidden)]
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Bytes,
+                S: Serializer,
+            {
+                let mut buffer = [0; ENCODED_LEN];
+                serializer.serialize_str(encode(&value.to_bytes(), &mut buffer))
+            }
+
+            struct Base64Visitor<T>(PhantomData<T>);
+
+            impl<'de, T> Visitor<'de> for Base64Visitor<T>
+            where
+                T: Bytes,
+            {
+                type Value = T;
+
+                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                    f.write_str(concat!("base64 encoded 32 bytes in ", $name, " endian"))
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    let mut bytes = [0; 32];
+                    match decode(v, &mut bytes) {
+                        Some(32) => Ok(T::from_bytes(bytes)),
+                        Some(len) => Err(E::invalid_length(len, &self)),
+                        None => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+                    }
+                }
+            }
+
+            #[doc(hidden)]
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: Bytes,
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_str(Base64Visitor(PhantomData))
+            }
+
+            submodules!(Bytes);
+        };
+    }
+
+    /// Module for use with `#[serde(with = "ethnum::serde::base64::le")]` to
+    /// specify base64 encoded little endian byte serialization for 256-bit
+    /// integer types.
+    pub mod le {
+        endianness!("little"; le);
+    }
+
+    /// Module for use with `#[serde(with = "ethnum::serde::base64::be")]` to
+    /// specify base64 encoded big endian byte serialization for 256-bit
+    /// integer types.
+    pub mod be {
+        endianness!("big"; be);
     }
 }
 
 /// Serde compressed byte serialization for 256-bit integer types.
+///
+/// Integers are serialized with only their significant bytes, so small values
+/// take up as little space as possible. Unsigned integers drop all leading zero
+/// bytes, while signed integers keep the minimal two's complement form where
+/// the most significant serialized byte still carries the sign bit. For
+/// example, `128` serializes as `[0x80]` for `U256`, but as `[0x00, 0x80]` (in
+/// big endian) for `I256`, since `[0x80]` would decode as `-128`.
+///
+/// Zero is serialized as an empty byte sequence.
+///
+/// Deserialization accepts any number of bytes up to 32, sign extending signed
+/// integers from their most significant byte, so non-minimal encodings (such
+/// as `[0x00]` for zero) are accepted as well. Byte sequences that are longer
+/// than 32 bytes are rejected.
 pub mod compressed_bytes {
     use crate::{I256, U256};
 
@@ -444,7 +1338,8 @@ pub mod compressed_bytes {
         fn leading_bits(&self) -> u32 {
             match self.is_negative() {
                 true => self.leading_ones() - 1,
-                false => self.leading_zeros(),
+                false if *self == I256::ZERO => 256,
+                false => self.leading_zeros() - 1,
             }
         }
 
@@ -464,7 +1359,7 @@ pub mod compressed_bytes {
     }
 
     macro_rules! endianness {
-        ($name:literal; $parent:ident, |$tb:ident| $to:block, |$fb:ident| $from:block) => {
+        ($name:literal; $parent:ident, $msb:ident, |$tb:ident| $to:block, |$fb:ident| $from:block) => {
             use super::CompressedBytes;
             use crate::serde::bytes::$parent::Bytes;
             use core::{
@@ -497,7 +1392,7 @@ pub mod compressed_bytes {
                 type Value = T;
 
                 fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-                    f.write_str(concat!("bytes in ", $name, " endian"))
+                    f.write_str(concat!("at most 32 bytes in ", $name, " endian"))
                 }
 
                 fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
@@ -508,7 +1403,7 @@ pub mod compressed_bytes {
                         return Err(E::invalid_length(v.len(), &self));
                     }
 
-                    let extend = T::extend(v.last().copied().unwrap_or_default());
+                    let extend = T::extend(v.$msb().copied().unwrap_or_default());
                     let mut bytes = [extend; 32];
                     let $fb = v.len();
                     let index = { $from };
@@ -516,6 +1411,23 @@ pub mod compressed_bytes {
 
                     Ok(T::from_bytes(bytes))
                 }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: de::SeqAccess<'de>,
+                {
+                    let mut bytes = [0; 32];
+                    let mut len = 0;
+                    while let Some(byte) = seq.next_element()? {
+                        if len == bytes.len() {
+                            return Err(de::Error::invalid_length(len + 1, &self));
+                        }
+                        bytes[len] = byte;
+                        len += 1;
+                    }
+
+                    self.visit_bytes(&bytes[..len])
+                }
             }
 
             #[doc(hidden)]
@@ -526,6 +1438,8 @@ pub mod compressed_bytes {
             {
                 deserializer.deserialize_bytes(CompressedBytesVisitor(PhantomData))
             }
+
+            submodules!(Bytes + CompressedBytes);
         };
     }
 
@@ -534,7 +1448,7 @@ pub mod compressed_bytes {
     /// integer types. This will serialize integer types with as few bytes as
     /// possible.
     pub mod le {
-        endianness!("little"; le, |l| { ..32 - l }, |l| { ..l });
+        endianness!("little"; le, last, |l| { ..32 - l }, |l| { ..l });
     }
 
     /// Module for `#[serde(with = "ethnum::serde::compressed_bytes::be")]`
@@ -542,7 +1456,7 @@ pub mod compressed_bytes {
     /// integer types. This will serialize integer types with as few bytes as
     /// possible.
     pub mod be {
-        endianness!("big"; be, |l| { l.. }, |l| { 32 - l.. });
+        endianness!("big"; be, first, |l| { l.. }, |l| { 32 - l.. });
     }
 
     /// Module for `#[serde(with = "ethnum::serde::compressed_bytes::ne")]`
@@ -550,56 +1464,246 @@ pub mod compressed_bytes {
     /// integer types. This will serialize integer types with as few bytes as
     /// possible.
     pub mod ne {
+        #[cfg(all(target_endian = "little", feature = "alloc"))]
+        #[doc(hidden)]
+        pub use super::le::vec;
         #[cfg(target_endian = "little")]
         #[doc(hidden)]
-        pub use super::le::{deserialize, serialize};
+        pub use super::le::{deserialize, option, serialize};
 
+        #[cfg(all(target_endian = "big", feature = "alloc"))]
+        #[doc(hidden)]
+        pub use super::be::vec;
         #[cfg(target_endian = "big")]
         #[doc(hidden)]
-        pub use super::be::{deserialize, serialize};
+        pub use super::be::{deserialize, option, serialize};
     }
 }
 
-/// Internal visitor struct implementation to facilitate implementing different
-/// serialization formats.
-struct FormatVisitor<F>(F);
+/// Module for use with `#[serde(with = "ethnum::serde::words")]` to specify
+/// the serialization used by the derived implementations of previous
+/// versions.
+///
+/// Integers are serialized as a newtype struct named `"U256"` or `"I256"`
+/// containing the two 128-bit words of the internal representation in memory
+/// order, that is `[low, high]` on little endian targets (and `[high, low]` on
+/// big endian targets). For example, 42 is serialized as `[42, 0]` in JSON.
+///
+/// This is only meant for reading and writing data stored in this legacy
+/// format, the default implementations should be preferred otherwise.
+pub mod words {
+    use super::*;
+    use core::marker::PhantomData;
 
-impl<'de, T, E, F> Visitor<'de> for FormatVisitor<F>
-where
-    E: Display,
-    F: FnOnce(&str) -> Result<T, E>,
-{
-    type Value = T;
+    #[doc(hidden)]
+    pub trait Words: Sized {
+        const NAME: &'static str;
+        type Word: Serialize + for<'de> Deserialize<'de>;
+        fn words(&self) -> &[Self::Word; 2];
+        fn from_words(words: [Self::Word; 2]) -> Self;
+    }
 
-    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-        f.write_str("a formatted 256-bit integer")
+    impl Words for I256 {
+        const NAME: &'static str = "I256";
+        type Word = i128;
+        fn words(&self) -> &[i128; 2] {
+            &self.0
+        }
+        fn from_words(words: [i128; 2]) -> Self {
+            Self(words)
+        }
     }
 
-    fn visit_str<E_>(self, v: &str) -> Result<Self::Value, E_>
-    where
-        E_: de::Error,
-    {
-        self.0(v).map_err(de::Error::custom)
+    impl Words for U256 {
+        const NAME: &'static str = "U256";
+        type Word = u128;
+        fn words(&self) -> &[u128; 2] {
+            &self.0
+        }
+        fn from_words(words: [u128; 2]) -> Self {
+            Self(words)
+        }
     }
 
-    fn visit_bytes<E_>(self, v: &[u8]) -> Result<Self::Value, E_>
+    #[doc(hidden)]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
     where
-        E_: de::Error,
+        T: Words,
+        S: Serializer,
     {
-        let string = str::from_utf8(v)
-            .map_err(|_| de::Error::invalid_value(de::Unexpected::Bytes(v), &self))?;
-        self.visit_str(string)
+        serializer.serialize_newtype_struct(T::NAME, value.words())
     }
-}
 
-/// A stack-allocated buffer that can be used for writing formatted strings.
-///
-/// This allows us to leverage existing `fmt` implementations on integer types
-/// without requiring heap allocations (i.e. writing to a `String` buffer).
-struct FormatBuffer<const N: usize> {
-    offset: usize,
-    buffer: [MaybeUninit<u8>; N],
-}
+    struct WordsVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for WordsVisitor<T>
+    where
+        T: Words,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("two 128-bit words")
+        }
+
+        fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Deserialize::deserialize(deserializer).map(T::from_words)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let words = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            Ok(T::from_words(words))
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Words,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(T::NAME, WordsVisitor(PhantomData))
+    }
+
+    submodules!(Words);
+}
+
+/// Casts a native integer value, returning an error if it is out of range for
+/// the target type.
+fn cast<T, E>(value: I256) -> Result<T, E>
+where
+    T: TryFrom<I256>,
+    E: de::Error,
+{
+    T::try_from(value).map_err(|_| {
+        E::custom(format_args!(
+            "invalid conversion from negative integer {value} to unsigned 256-bit integer"
+        ))
+    })
+}
+
+/// Internal visitor struct implementation to facilitate implementing different
+/// serialization formats.
+struct FormatVisitor<F>(F);
+
+impl<'de, T, E, F> Visitor<'de> for FormatVisitor<F>
+where
+    E: Display,
+    F: FnOnce(&str) -> Result<T, E>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a formatted 256-bit integer")
+    }
+
+    fn visit_str<E_>(self, v: &str) -> Result<Self::Value, E_>
+    where
+        E_: de::Error,
+    {
+        self.0(v).map_err(de::Error::custom)
+    }
+
+    fn visit_bytes<E_>(self, v: &[u8]) -> Result<Self::Value, E_>
+    where
+        E_: de::Error,
+    {
+        let string = str::from_utf8(v)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Bytes(v), &self))?;
+        self.visit_str(string)
+    }
+}
+
+impl<'de, T, E, F> de::DeserializeSeed<'de> for FormatVisitor<F>
+where
+    E: Display,
+    F: FnOnce(&str) -> Result<T, E>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+/// The map key that `serde_json` uses for passing numbers as strings when its
+/// `arbitrary_precision` feature is enabled.
+const JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+/// Visits an arbitrary precision `serde_json` number.
+///
+/// With the `arbitrary_precision` feature, `serde_json` represents numbers as
+/// a single entry map from a private token to the number's digits, which
+/// allows deserializing integers that don't fit in 64 bits from unquoted JSON
+/// numbers.
+fn visit_json_number<'de, A, T, E, F>(
+    mut map: A,
+    expected: &dyn de::Expected,
+    parse: F,
+) -> Result<T, A::Error>
+where
+    A: de::MapAccess<'de>,
+    E: Display,
+    F: FnOnce(&str) -> Result<T, E>,
+{
+    if map.next_key_seed(JsonNumberKey)?.is_none() {
+        return Err(de::Error::invalid_type(de::Unexpected::Map, expected));
+    }
+    map.next_value_seed(FormatVisitor(parse))
+}
+
+/// Deserialization seed and visitor that only accepts the `serde_json`
+/// arbitrary precision number token as a map key.
+struct JsonNumberKey;
+
+impl<'de> de::DeserializeSeed<'de> for JsonNumberKey {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl Visitor<'_> for JsonNumberKey {
+    type Value = ();
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("an arbitrary precision number")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v != JSON_NUMBER_TOKEN {
+            return Err(de::Error::invalid_value(de::Unexpected::Str(v), &self));
+        }
+        Ok(())
+    }
+}
+
+/// A stack-allocated buffer that can be used for writing formatted strings.
+///
+/// This allows us to leverage existing `fmt` implementations on integer types
+/// without requiring heap allocations (i.e. writing to a `String` buffer).
+struct FormatBuffer<const N: usize> {
+    offset: usize,
+    buffer: [MaybeUninit<u8>; N],
+}
 
 impl<const N: usize> FormatBuffer<N> {
     /// Creates a new formatting buffer.
@@ -637,6 +1741,14 @@ impl FormatBuffer<67> {
     }
 }
 
+impl FormatBuffer<79> {
+    /// Allocates a formatting buffer large enough to hold any possible 256-bit
+    /// value formatted as a decimal with 18 fractional digits.
+    fn decimal_fraction() -> Self {
+        Self::new()
+    }
+}
+
 impl<const N: usize> Write for FormatBuffer<N> {
     fn write_str(&mut self, s: &str) -> fmt::Result {
         let end = self.offset.checked_add(s.len()).ok_or(fmt::Error)?;
@@ -695,17 +1807,17 @@ mod tests {
 
         assert_eq!(
             ser!(I256::serialize, I256::MIN),
-            "-0x8000000000000000000000000000000000000000000000000000000000000000",
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
         );
-        assert_eq!(ser!(I256::serialize, I256::new(-1)), "-0x1");
-        assert_eq!(ser!(I256::serialize, I256::new(0)), "0x0");
-        assert_eq!(ser!(I256::serialize, I256::new(42)), "0x2a");
+        assert_eq!(ser!(I256::serialize, I256::new(-1)), "-1");
+        assert_eq!(ser!(I256::serialize, I256::new(0)), "0");
+        assert_eq!(ser!(I256::serialize, I256::new(42)), "42");
 
-        assert_eq!(ser!(U256::serialize, U256::new(0)), "0x0");
-        assert_eq!(ser!(U256::serialize, U256::new(4919)), "0x1337");
+        assert_eq!(ser!(U256::serialize, U256::new(0)), "0");
+        assert_eq!(ser!(U256::serialize, U256::new(4919)), "4919");
         assert_eq!(
             ser!(U256::serialize, U256::MAX),
-            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
         );
 
         assert_eq!(
@@ -723,8 +1835,8 @@ mod tests {
             "115792089237316195423570985008687907853269984665640564039457584007913129639935",
         );
 
-        assert_eq!(ser!(prefixed::serialize, I256::new(42)), "0x2a");
-        assert_eq!(ser!(permissive::serialize, I256::new(42)), "0x2a");
+        assert_eq!(ser!(prefixed::serialize, I256::new(42)), "42");
+        assert_eq!(ser!(permissive::serialize, I256::new(-42)), "-42");
 
         assert_eq!(bin_ser!(bytes::le::serialize, U256::ZERO), vec![0x00; 32]);
         assert_eq!(bin_ser!(bytes::le::serialize, U256::MAX), vec![0xff; 32]);
@@ -764,7 +1876,7 @@ mod tests {
 
         assert_eq!(
             bin_ser!(compressed_bytes::le::serialize, U256::ZERO),
-            vec![]
+            Vec::<u8>::new()
         );
         assert_eq!(
             bin_ser!(compressed_bytes::le::serialize, U256::MAX),
@@ -782,7 +1894,7 @@ mod tests {
         });
         assert_eq!(
             bin_ser!(compressed_bytes::le::serialize, I256::ZERO),
-            vec![]
+            Vec::<u8>::new()
         );
         assert_eq!(
             bin_ser!(compressed_bytes::le::serialize, I256::new(-1)),
@@ -799,7 +1911,7 @@ mod tests {
 
         assert_eq!(
             bin_ser!(compressed_bytes::be::serialize, U256::ZERO),
-            vec![]
+            Vec::<u8>::new()
         );
         assert_eq!(
             bin_ser!(compressed_bytes::be::serialize, U256::MAX),
@@ -817,7 +1929,7 @@ mod tests {
         });
         assert_eq!(
             bin_ser!(compressed_bytes::be::serialize, I256::ZERO),
-            vec![]
+            Vec::<u8>::new()
         );
         assert_eq!(
             bin_ser!(compressed_bytes::be::serialize, I256::new(-1)),
@@ -849,25 +1961,26 @@ mod tests {
         assert_eq!(
             de!(
                 I256::deserialize,
-                "-0x8000000000000000000000000000000000000000000000000000000000000000"
+                "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
             ),
             I256::MIN
         );
-        assert_eq!(de!(I256::deserialize, "-0x1337"), I256::new(-4919));
-        assert_eq!(de!(I256::deserialize, "0x0"), I256::new(0));
-        assert_eq!(de!(I256::deserialize, "0x2a"), I256::new(42));
-        assert_eq!(de!(I256::deserialize, "0x2A"), I256::new(42));
+        assert_eq!(de!(I256::deserialize, "-4919"), I256::new(-4919));
+        assert_eq!(de!(I256::deserialize, "0"), I256::new(0));
+        assert_eq!(de!(I256::deserialize, "42"), I256::new(42));
+        assert!(de!(err; I256::deserialize, "0x2a"));
 
-        assert_eq!(de!(U256::deserialize, "0x0"), U256::new(0));
-        assert_eq!(de!(U256::deserialize, "0x2a"), U256::new(42));
-        assert_eq!(de!(U256::deserialize, "0x2A"), U256::new(42));
+        assert_eq!(de!(U256::deserialize, "0"), U256::new(0));
+        assert_eq!(de!(U256::deserialize, "42"), U256::new(42));
         assert_eq!(
             de!(
                 U256::deserialize,
-                "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+                "115792089237316195423570985008687907853269984665640564039457584007913129639935"
             ),
             U256::MAX
         );
+        assert!(de!(err; U256::deserialize, "-1"));
+        assert!(de!(err; U256::deserialize, "0x2a"));
 
         assert_eq!(
             de!(
@@ -968,6 +2081,14 @@ mod tests {
             de!(permissive::deserialize::<U256, _>, 100.0_f64),
             U256::new(100)
         );
+        assert_eq!(
+            de!(permissive::deserialize::<U256, _>, 16777216.0_f32),
+            U256::new(1 << 24)
+        );
+        assert_eq!(
+            de!(permissive::deserialize::<U256, _>, 9007199254740992.0_f32),
+            U256::new(1 << 53)
+        );
         assert_eq!(
             de!(permissive::deserialize::<U256, _>, "1000"),
             U256::new(1000)
@@ -985,9 +2106,8 @@ mod tests {
         );
 
         assert!(de!(err; permissive::deserialize::<I256, _>, 4.2_f32));
-        assert!(de!(err; permissive::deserialize::<I256, _>, 16777216.0_f32));
         assert!(de!(err; permissive::deserialize::<I256, _>, -13.37_f64));
-        assert!(de!(err; permissive::deserialize::<I256, _>, 9007199254740992.0_f32));
+        assert!(de!(err; permissive::deserialize::<I256, _>, f32::INFINITY));
         assert!(
             de!(err; permissive::deserialize::<I256, _>, "0x8000000000000000000000000000000000000000000000000000000000000000")
         );
@@ -997,9 +2117,8 @@ mod tests {
         );
 
         assert!(de!(err; permissive::deserialize::<U256, _>, 4.2_f32));
-        assert!(de!(err; permissive::deserialize::<U256, _>, 16777216.0_f32));
         assert!(de!(err; permissive::deserialize::<U256, _>, 13.37_f64));
-        assert!(de!(err; permissive::deserialize::<U256, _>, 9007199254740992.0_f32));
+        assert!(de!(err; permissive::deserialize::<U256, _>, -1.0_f32));
         assert!(
             de!(err; permissive::deserialize::<U256, _>, "0x10000000000000000000000000000000000000000000000000000000000000000")
         );
@@ -1135,6 +2254,1075 @@ mod tests {
         );
     }
 
+    #[test]
+    fn permissive_floats() {
+        use alloc::string::{String, ToString};
+
+        fn de<T: permissive::Permissive>(v: f64) -> Result<T, String> {
+            let deserializer = IntoDeserializer::<value::Error>::into_deserializer(v);
+            permissive::deserialize(deserializer).map_err(|err| err.to_string())
+        }
+
+        assert_eq!(de::<U256>(1e18), Ok(U256::new(10_u128.pow(18))));
+        assert_eq!(de::<I256>(-1e18), Ok(I256::new(-10_i128.pow(18))));
+        assert_eq!(de::<U256>(42.0), Ok(U256::new(42)));
+        assert_eq!(de::<U256>(-0.0), Ok(U256::ZERO));
+        assert_eq!(de::<I256>(-0.0), Ok(I256::ZERO));
+
+        // Integers larger than 2^53 convert to the exact value of the float.
+        assert_eq!(
+            de::<U256>(1e30),
+            Ok(U256::new(1000000000000000019884624838656))
+        );
+        assert_eq!(de::<U256>(2f64.powi(255)), Ok(U256::ONE << 255u32));
+        assert_eq!(
+            de::<U256>(2f64.powi(256) - 2f64.powi(203)),
+            Ok(U256::MAX << 203u32)
+        );
+        assert_eq!(de::<I256>(-(2f64.powi(255))), Ok(I256::MIN));
+        assert_eq!(
+            de::<I256>(2f64.powi(255) - 2f64.powi(202)),
+            Ok((I256::MAX >> 202u32) << 202u32)
+        );
+
+        assert_eq!(
+            de::<U256>(2.5),
+            Err("invalid conversion from floating point number 2.5 \
+                 with fractional part to 256-bit integer"
+                .into())
+        );
+        assert!(de::<I256>(-f64::from_bits(1)).is_err());
+        assert!(de::<U256>(1.0 + f64::EPSILON).is_err());
+        assert_eq!(
+            de::<U256>(-1.0),
+            Err("invalid conversion from negative floating point number -1 \
+                 to unsigned 256-bit integer"
+                .into())
+        );
+        assert_eq!(
+            de::<U256>(1e80),
+            Err("invalid conversion from floating point number 1e80 \
+                 outside of the range of 256-bit integer"
+                .into())
+        );
+        assert_eq!(
+            de::<U256>(2f64.powi(256)),
+            Err(
+                "invalid conversion from floating point number 1.157920892373162e77 \
+                 outside of the range of 256-bit integer"
+                    .into()
+            )
+        );
+        assert!(de::<I256>(2f64.powi(255)).is_err());
+        assert!(de::<I256>(-(2f64.powi(255)) - 2f64.powi(203)).is_err());
+        assert!(de::<U256>(-1e80).is_err());
+        assert_eq!(
+            de::<I256>(f64::NAN),
+            Err(
+                "invalid conversion from non-finite floating point number NaN \
+                 to 256-bit integer"
+                    .into()
+            )
+        );
+        assert!(de::<I256>(f64::INFINITY).is_err());
+        assert!(de::<U256>(f64::NEG_INFINITY).is_err());
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Transfer {
+            #[serde(with = "permissive")]
+            amount: U256,
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Transfer>(r#"{"amount": 1e18}"#).unwrap(),
+            Transfer {
+                amount: U256::new(10_u128.pow(18))
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<Transfer>(r#"{"amount": 42.0}"#).unwrap(),
+            Transfer {
+                amount: U256::new(42)
+            }
+        );
+        assert!(serde_json::from_str::<Transfer>(r#"{"amount": 2.5}"#).is_err());
+        assert!(serde_json::from_str::<Transfer>(r#"{"amount": 1e80}"#).is_err());
+        assert!(serde_json::from_str::<Transfer>(r#"{"amount": -1.0}"#).is_err());
+    }
+
+    #[test]
+    fn default_binary_round_trips() {
+        use alloc::string::ToString;
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Account {
+            balance: U256,
+            debt: I256,
+        }
+
+        let account = Account {
+            balance: U256::new(42),
+            debt: I256::MIN,
+        };
+
+        let json = serde_json::to_value(&account).unwrap();
+        assert_eq!(json["balance"], "42");
+        assert_eq!(json["debt"], I256::MIN.to_string());
+        assert_eq!(serde_json::from_value::<Account>(json).unwrap(), account);
+
+        let bincode = bincode::serialize(&account).unwrap();
+        let postcard = postcard::to_allocvec(&account).unwrap();
+        if cfg!(feature = "serde-binary") {
+            assert_eq!(bincode.len(), 2 * (8 + 32));
+            assert_eq!(&bincode[8..9], &[42]);
+            assert_eq!(postcard.len(), 2 * (1 + 32));
+            assert_eq!(&postcard[1..2], &[42]);
+            assert_eq!(&postcard[34..], &bytes::le::Bytes::to_bytes(I256::MIN)[..]);
+        } else {
+            assert_eq!(bincode.len(), 2 * 8 + 2 + 78);
+            assert_eq!(postcard.len(), 2 + 2 + 78);
+        }
+        assert_eq!(bincode::deserialize::<Account>(&bincode).unwrap(), account);
+        assert_eq!(postcard::from_bytes::<Account>(&postcard).unwrap(), account);
+    }
+
+    #[test]
+    fn json_with_modules() {
+        use alloc::string::ToString;
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Amounts {
+            default: U256,
+            #[serde(with = "decimal")]
+            decimal: I256,
+            #[serde(with = "prefixed")]
+            prefixed: I256,
+            #[serde(with = "permissive")]
+            permissive: U256,
+            #[serde(with = "permissive")]
+            signed: I256,
+        }
+
+        let amounts: Amounts = serde_json::from_str(
+            r#"{
+                "default": "18446744073709551616",
+                "decimal": "-18446744073709551616",
+                "prefixed": "-0x10000000000000000",
+                "permissive": 18446744073709551615,
+                "signed": -9223372036854775808
+            }"#,
+        )
+        .unwrap();
+        let over_u64 = U256::from(u64::MAX) + 1;
+        assert_eq!(
+            amounts,
+            Amounts {
+                default: over_u64,
+                decimal: -over_u64.as_i256(),
+                prefixed: -over_u64.as_i256(),
+                permissive: U256::from(u64::MAX),
+                signed: I256::from(i64::MIN),
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(&amounts).unwrap(),
+            serde_json::json!({
+                "default": "18446744073709551616",
+                "decimal": "-18446744073709551616",
+                "prefixed": "-18446744073709551616",
+                "permissive": "18446744073709551615",
+                "signed": "-9223372036854775808",
+            })
+        );
+
+        #[derive(Debug, Deserialize)]
+        struct Permissive(#[serde(with = "permissive")] U256);
+        #[derive(Debug, Deserialize)]
+        struct SignedPermissive(#[serde(with = "permissive")] I256);
+
+        let de = |json| serde_json::from_str::<Permissive>(json).map(|p| p.0);
+        assert_eq!(de("\"18446744073709551616\"").unwrap(), over_u64);
+        assert_eq!(de("\"0x10000000000000000\"").unwrap(), over_u64);
+        assert_eq!(de("4096.0").unwrap(), U256::new(4096));
+        // JSON numbers that don't fit in a `u64` are parsed as floats, which
+        // round to the nearest representable integer unless `serde_json` uses
+        // arbitrary precision numbers.
+        assert_eq!(de("18446744073709551616").unwrap(), over_u64);
+        assert_eq!(
+            de("18446744073709551617").unwrap(),
+            if json_arbitrary_precision() {
+                over_u64 + 1
+            } else {
+                over_u64
+            }
+        );
+        assert!(de("-1")
+            .unwrap_err()
+            .to_string()
+            .contains("negative integer -1"));
+        assert!(de("\"-1\"").is_err());
+
+        let de = |json| serde_json::from_str::<SignedPermissive>(json).map(|p| p.0);
+        assert_eq!(de("-1").unwrap(), I256::MINUS_ONE);
+        assert_eq!(de("\"-0x2a\"").unwrap(), I256::new(-42));
+        assert_eq!(de("-4096.0").unwrap(), I256::new(-4096));
+        assert_eq!(
+            de("-18446744073709551617").unwrap(),
+            if json_arbitrary_precision() {
+                -over_u64.as_i256() - 1
+            } else {
+                -over_u64.as_i256()
+            }
+        );
+        assert!(de("0.5").is_err());
+
+        assert_eq!(serde_json::from_str::<U256>("42").unwrap(), U256::new(42));
+        assert!(serde_json::from_str::<U256>("\"0x2a\"").is_err());
+        assert_eq!(
+            serde_json::from_str::<I256>("\"-42\"").unwrap(),
+            I256::new(-42)
+        );
+    }
+
+    #[test]
+    fn deserialize_native_integers() {
+        use alloc::string::ToString;
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Transfer {
+            amount: U256,
+            delta: I256,
+        }
+
+        let transfer = Transfer {
+            amount: U256::new(42),
+            delta: I256::new(-42),
+        };
+        assert_eq!(
+            serde_json::from_str::<Transfer>(r#"{"amount": 42, "delta": -42}"#).unwrap(),
+            transfer
+        );
+        assert_eq!(
+            serde_json::from_str::<Transfer>(r#"{"amount": "42", "delta": "-42"}"#).unwrap(),
+            transfer
+        );
+        assert_eq!(
+            serde_json::from_str::<U256>(&u64::MAX.to_string()).unwrap(),
+            U256::new(u64::MAX.into())
+        );
+        assert_eq!(
+            serde_json::from_str::<I256>(&i64::MIN.to_string()).unwrap(),
+            I256::new(i64::MIN.into())
+        );
+
+        let err = serde_json::from_str::<U256>("-1").unwrap_err();
+        assert!(err.to_string().starts_with(
+            "invalid conversion from negative integer -1 to unsigned 256-bit integer"
+        ));
+        assert!(serde_json::from_str::<U256>("4.2").is_err());
+        assert!(serde_json::from_str::<U256>("true").is_err());
+
+        // MessagePack is not human-readable, so it expects bytes instead of
+        // integers in binary mode.
+        #[cfg(not(feature = "serde-binary"))]
+        {
+            #[derive(Serialize)]
+            struct Native {
+                amount: u64,
+                delta: i8,
+            }
+
+            let msgpack = rmp_serde::to_vec_named(&Native {
+                amount: 42,
+                delta: -42,
+            })
+            .unwrap();
+            assert_eq!(
+                rmp_serde::from_slice::<Transfer>(&msgpack).unwrap(),
+                transfer
+            );
+
+            let msgpack = rmp_serde::to_vec(&u64::MAX).unwrap();
+            assert_eq!(
+                rmp_serde::from_slice::<U256>(&msgpack).unwrap(),
+                U256::new(u64::MAX.into())
+            );
+            let msgpack = rmp_serde::to_vec(&-1i64).unwrap();
+            assert!(rmp_serde::from_slice::<U256>(&msgpack).is_err());
+        }
+
+        let bincode = bincode::serialize(&transfer).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Transfer>(&bincode).unwrap(),
+            transfer
+        );
+    }
+
+    #[test]
+    fn json_arbitrary_precision_numbers() {
+        use alloc::string::ToString;
+
+        let arbitrary_precision = json_arbitrary_precision();
+
+        let max = U256::MAX.to_string();
+        let min = I256::MIN.to_string();
+        assert_eq!(
+            serde_json::from_str::<U256>(&max).ok(),
+            arbitrary_precision.then_some(U256::MAX)
+        );
+        assert_eq!(
+            serde_json::from_str::<I256>(&min).ok(),
+            arbitrary_precision.then_some(I256::MIN)
+        );
+        assert_eq!(serde_json::from_str::<U256>("42").unwrap(), U256::new(42));
+        assert!(serde_json::from_str::<U256>("-42").is_err());
+        assert!(serde_json::from_str::<U256>("4.2").is_err());
+        assert!(serde_json::from_str::<U256>("1e3").is_err());
+
+        #[derive(Debug, Deserialize)]
+        struct Permissive(#[serde(with = "permissive")] I256);
+
+        let de = |json: &str| serde_json::from_str::<Permissive>(json).map(|p| p.0);
+        assert_eq!(de(&min).unwrap(), I256::MIN);
+        assert_eq!(de("-4096.0").unwrap(), I256::new(-4096));
+        assert_eq!(de("1e3").unwrap(), I256::new(1000));
+        assert!(de("0.5").is_err());
+
+        // The map representation used by `arbitrary_precision` numbers.
+        let number = |digits: &str| format!(r#"{{"{JSON_NUMBER_TOKEN}": "{digits}"}}"#);
+        assert_eq!(
+            serde_json::from_str::<U256>(&number(&max)).unwrap(),
+            U256::MAX
+        );
+        assert_eq!(
+            serde_json::from_str::<I256>(&number(&min)).unwrap(),
+            I256::MIN
+        );
+        assert_eq!(de(&number(&min)).unwrap(), I256::MIN);
+        assert!(serde_json::from_str::<U256>(&number("-1")).is_err());
+        assert!(serde_json::from_str::<U256>(&format!("{max}0")).is_err());
+        assert!(serde_json::from_str::<U256>(r#"{"amount": "42"}"#).is_err());
+        assert!(serde_json::from_str::<U256>("{}").is_err());
+    }
+
+    #[test]
+    fn option_modules() {
+        #[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
+        struct Optional {
+            #[serde(default, with = "permissive::option")]
+            permissive: Option<U256>,
+            #[serde(default, with = "decimal::option")]
+            decimal: Option<I256>,
+            #[serde(default, with = "quantity::option")]
+            quantity: Option<U256>,
+            #[serde(default, with = "bytes::be::option")]
+            bytes: Option<U256>,
+            #[serde(default, with = "compressed_bytes::ne::option")]
+            compressed: Option<I256>,
+        }
+
+        let some = Optional {
+            permissive: Some(U256::new(42)),
+            decimal: Some(I256::new(-42)),
+            quantity: Some(U256::ZERO),
+            bytes: Some(U256::ONE),
+            compressed: Some(I256::MINUS_ONE),
+        };
+        let mut be = [0u8; 32];
+        be[31] = 1;
+        let json = serde_json::to_value(&some).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "permissive": "42",
+                "decimal": "-42",
+                "quantity": "0x0",
+                "bytes": be,
+                "compressed": [0xff],
+            })
+        );
+        assert_eq!(serde_json::from_value::<Optional>(json).unwrap(), some);
+        assert_eq!(
+            serde_json::from_str::<Optional>(r#"{"permissive": 42, "quantity": "0x0"}"#).unwrap(),
+            Optional {
+                permissive: Some(U256::new(42)),
+                quantity: Some(U256::ZERO),
+                ..Default::default()
+            }
+        );
+
+        let json = serde_json::to_value(Optional::default()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "permissive": null,
+                "decimal": null,
+                "quantity": null,
+                "bytes": null,
+                "compressed": null,
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Optional>(json).unwrap(),
+            Optional::default()
+        );
+        assert_eq!(
+            serde_json::from_str::<Optional>("{}").unwrap(),
+            Optional::default()
+        );
+        assert!(serde_json::from_str::<Optional>(r#"{"quantity": "0x00"}"#).is_err());
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Binary(#[serde(with = "compressed_bytes::le::option")] Option<I256>);
+
+        for value in [Binary(Some(I256::new(-0x80))), Binary(None)] {
+            let bincode = bincode::serialize(&value).unwrap();
+            assert_eq!(bincode::deserialize::<Binary>(&bincode).unwrap(), value);
+            let postcard = postcard::to_allocvec(&value).unwrap();
+            assert_eq!(postcard::from_bytes::<Binary>(&postcard).unwrap(), value);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_modules() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Snapshot {
+            #[serde(with = "bytes::be::vec")]
+            balances: Vec<U256>,
+            #[serde(with = "compressed_bytes::le::vec")]
+            deltas: Vec<I256>,
+            #[serde(with = "quantity::vec")]
+            nonces: Vec<U256>,
+        }
+
+        let snapshot = Snapshot {
+            balances: (0..1000).map(U256::new).collect(),
+            deltas: vec![I256::ZERO, I256::new(-42), I256::MAX],
+            nonces: vec![U256::ZERO, U256::new(42)],
+        };
+
+        let json = serde_json::to_value(&snapshot).unwrap();
+        assert_eq!(json["balances"].as_array().unwrap().len(), 1000);
+        assert_eq!(
+            json["deltas"],
+            serde_json::json!([[], [0xd6], I256::MAX.to_le_bytes()])
+        );
+        assert_eq!(json["nonces"], serde_json::json!(["0x0", "0x2a"]));
+        assert_eq!(serde_json::from_value::<Snapshot>(json).unwrap(), snapshot);
+
+        // Each element is encoded as its raw bytes with a length prefix, and not
+        // as a string.
+        let bincode = bincode::serialize(&snapshot).unwrap();
+        assert_eq!(
+            bincode.len(),
+            (8 + 1000 * (8 + 32)) + (8 + 3 * 8 + 1 + 32) + (8 + 2 * 8 + 3 + 4),
+        );
+        assert_eq!(
+            bincode::deserialize::<Snapshot>(&bincode).unwrap(),
+            snapshot
+        );
+
+        let postcard = postcard::to_allocvec(&snapshot).unwrap();
+        assert_eq!(
+            postcard.len(),
+            (2 + 1000 * (1 + 32)) + (1 + 3 + 1 + 32) + (1 + 2 + 3 + 4),
+        );
+        assert_eq!(
+            postcard::from_bytes::<Snapshot>(&postcard).unwrap(),
+            snapshot
+        );
+
+        #[derive(Debug, Deserialize)]
+        struct Nonces(#[serde(with = "quantity::vec")] Vec<U256>);
+
+        assert!(serde_json::from_str::<Nonces>(r#"["0x1", "0x01"]"#).is_err());
+        assert!(serde_json::from_str::<Nonces>(r#""0x1""#).is_err());
+        assert!(serde_json::from_str::<Nonces>("[]").unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn hex_proof_fixtures() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Proof {
+            #[serde(with = "hex::padded")]
+            code_hash: U256,
+            #[serde(with = "hex::padded")]
+            storage_hash: U256,
+            #[serde(with = "quantity")]
+            nonce: U256,
+            storage_proof: [StorageProof; 2],
+        }
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct StorageProof {
+            #[serde(with = "hex::padded")]
+            key: U256,
+            #[serde(with = "hex::padded")]
+            value: I256,
+        }
+
+        // Fields of an `eth_getProof` response for an account without code,
+        // with slot values as 32-byte words instead of quantities.
+        let fixture = r#"{
+            "codeHash": "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+            "storageHash": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+            "nonce": "0x1",
+            "storageProof": [
+                {
+                    "key": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                    "value": "0x000000000000000000000000000000000000000000000000000000000000002a"
+                },
+                {
+                    "key": "0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563",
+                    "value": "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd6"
+                }
+            ]
+        }"#;
+        let proof = serde_json::from_str::<Proof>(fixture).unwrap();
+        assert_eq!(
+            proof,
+            Proof {
+                code_hash: U256::from_words(
+                    0xc5d2460186f7233c927e7db2dcc703c0,
+                    0xe500b653ca82273b7bfad8045d85a470,
+                ),
+                storage_hash: U256::from_words(
+                    0x56e81f171bcc55a6ff8345e692c0f86e,
+                    0x5b48e01b996cadc001622fb5e363b421,
+                ),
+                nonce: U256::ONE,
+                storage_proof: [
+                    StorageProof {
+                        key: U256::ZERO,
+                        value: I256::new(42),
+                    },
+                    StorageProof {
+                        key: U256::from_words(
+                            0x290decd9548b62a8d60345a988386fc8,
+                            0x4ba6bc95484008f6362f93160ef3e563,
+                        ),
+                        value: I256::new(-42),
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(&proof).unwrap(),
+            serde_json::from_str::<serde_json::Value>(fixture).unwrap(),
+        );
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Padded(#[serde(with = "hex::padded")] I256);
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Lenient(#[serde(with = "hex::lenient")] I256);
+
+        let min = format!("\"0x8{}\"", "0".repeat(63));
+        assert_eq!(serde_json::to_string(&Padded(I256::MIN)).unwrap(), min);
+        assert_eq!(serde_json::to_string(&Lenient(I256::MIN)).unwrap(), min);
+        assert_eq!(
+            serde_json::from_str::<Padded>(&min).unwrap(),
+            Padded(I256::MIN)
+        );
+        assert_eq!(
+            serde_json::from_str::<Padded>(&format!("\"0x{}\"", "F".repeat(64))).unwrap(),
+            Padded(I256::MINUS_ONE)
+        );
+
+        for invalid in [
+            "\"\"".into(),
+            "\"0x\"".into(),
+            "\"0x2a\"".into(),
+            format!("\"{}\"", "0".repeat(64)),
+            format!("\"0X{}\"", "0".repeat(64)),
+            format!("\"0x{}\"", "0".repeat(65)),
+            format!("\"0x{}g\"", "0".repeat(63)),
+            format!("\"0x+{}\"", "0".repeat(63)),
+            format!("\"-0x{}\"", "0".repeat(64)),
+        ] {
+            assert!(serde_json::from_str::<Padded>(&invalid).is_err());
+        }
+
+        for (lenient, value) in [
+            ("\"0x2a\"", I256::new(42)),
+            ("\"2A\"", I256::new(42)),
+            ("\"0X0\"", I256::ZERO),
+            (&*format!("\"{}\"", "f".repeat(64)), I256::MINUS_ONE),
+            (&*min, I256::MIN),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<Lenient>(lenient).unwrap(),
+                Lenient(value)
+            );
+        }
+        for invalid in [
+            "\"\"".into(),
+            "\"0x\"".into(),
+            "\"-0x1\"".into(),
+            "\"+1\"".into(),
+            format!("\"0x{}\"", "0".repeat(65)),
+        ] {
+            assert!(serde_json::from_str::<Lenient>(&invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn base64_round_trips() {
+        use alloc::string::ToString;
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct RawQuery {
+            #[serde(with = "base64::be")]
+            data: U256,
+        }
+
+        // A raw contract state query response for a balance of 10^24 stored
+        // as 32 big endian bytes.
+        let fixture = r#"{"data":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAANPCG87M7aEAAAA="}"#;
+        let query = serde_json::from_str::<RawQuery>(fixture).unwrap();
+        assert_eq!(query.data, U256::from_words(0, 10u128.pow(24)));
+        assert_eq!(serde_json::to_string(&query).unwrap(), fixture);
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Be(#[serde(with = "base64::be")] I256);
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Le(#[serde(with = "base64::le")] U256);
+
+        for (value, be) in [
+            (
+                I256::ZERO,
+                "\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=\"",
+            ),
+            (
+                I256::new(-42),
+                "\"/////////////////////////////////////////9Y=\"",
+            ),
+            (
+                I256::MINUS_ONE,
+                "\"//////////////////////////////////////////8=\"",
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&Be(value)).unwrap(), be);
+            assert_eq!(serde_json::from_str::<Be>(be).unwrap(), Be(value));
+        }
+
+        let bytes = U256::from_le_bytes(core::array::from_fn(|i| i as _));
+        let le = "\"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=\"";
+        assert_eq!(serde_json::to_string(&Le(bytes)).unwrap(), le);
+        assert_eq!(serde_json::from_str::<Le>(le).unwrap(), Le(bytes));
+        assert_eq!(
+            serde_json::from_str::<Le>("\"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8\"").unwrap(),
+            Le(bytes)
+        );
+
+        let err = serde_json::from_str::<Be>("\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==\"")
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("invalid length 31, expected base64 encoded 32 bytes"));
+        let err = serde_json::from_str::<Be>("\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\"")
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("invalid length 33, expected base64 encoded 32 bytes"));
+
+        for invalid in [
+            "\"\"",
+            "\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==\"",
+            "\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAB=\"",
+            "\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA-_\"",
+            "\"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\\n\"",
+            "\"=AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\"",
+        ] {
+            assert!(serde_json::from_str::<Be>(invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn decimal_fraction_strings() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Price(#[serde(with = "decimal_fraction")] U256);
+
+        let max =
+            "\"115792089237316195423570985008687907853269984665640564039457.584007913129639935\"";
+        for (atomics, json) in [
+            (U256::ZERO, "\"0\""),
+            (U256::ONE, "\"0.000000000000000001\""),
+            (U256::new(1_500_000_000_000_000_000), "\"1.5\""),
+            (U256::new(42_000_000_000_000_000_000), "\"42\""),
+            (U256::new(123_456_789_000_000_000), "\"0.123456789\""),
+            (U256::MAX, max),
+        ] {
+            assert_eq!(serde_json::to_string(&Price(atomics)).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Price>(json).unwrap(), Price(atomics));
+
+            #[cfg(feature = "cosmos")]
+            {
+                let decimal = cosmwasm_std::Decimal256::new(atomics.into());
+                assert_eq!(serde_json::to_string(&decimal).unwrap(), json);
+            }
+        }
+
+        for (json, atomics) in [
+            ("\"0.0\"", U256::ZERO),
+            ("\"00.100\"", U256::new(100_000_000_000_000_000)),
+            (
+                "\"1.000000000000000000\"",
+                U256::new(1_000_000_000_000_000_000),
+            ),
+        ] {
+            assert_eq!(serde_json::from_str::<Price>(json).unwrap(), Price(atomics));
+        }
+
+        for invalid in [
+            "\"\"",
+            "\".5\"",
+            "\"1.\"",
+            "\"1.2.3\"",
+            "\"-1\"",
+            "\"+1\"",
+            "\"1.+5\"",
+            "\"1e18\"",
+            "\"0.0000000000000000001\"",
+            "\"115792089237316195423570985008687907853269984665640564039458\"",
+            "\"115792089237316195423570985008687907853269984665640564039457.584007913129639936\"",
+            "1",
+        ] {
+            assert!(serde_json::from_str::<Price>(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn msgpack_round_trips() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Order {
+            amount: U256,
+            delta: I256,
+            #[serde(with = "bytes::be")]
+            price: U256,
+            #[serde(with = "compressed_bytes::le")]
+            fee: I256,
+            #[serde(with = "base64::le")]
+            id: U256,
+            #[serde(with = "permissive")]
+            nonce: U256,
+        }
+
+        let order = Order {
+            amount: U256::MAX,
+            delta: I256::MIN,
+            price: U256::new(42),
+            fee: I256::new(-42),
+            id: U256::ONE,
+            nonce: U256::ZERO,
+        };
+        let compact = rmp_serde::to_vec(&order).unwrap();
+        let named = rmp_serde::to_vec_named(&order).unwrap();
+        for msgpack in [&compact, &named] {
+            assert_eq!(rmp_serde::from_slice::<Order>(msgpack).unwrap(), order);
+        }
+
+        // MessagePack is not human-readable, so the default implementations
+        // use `bin 8` in binary mode and `str 8` otherwise. The other modules
+        // keep their format regardless of the mode.
+        assert_eq!(compact[0], 0x96);
+        if cfg!(feature = "serde-binary") {
+            assert_eq!(compact[1..3], [0xc4, 32]);
+            assert_eq!(compact.len(), 1 + 3 * (2 + 32) + 3 + (2 + 44) + 2);
+        } else {
+            assert_eq!(compact[1..3], [0xd9, 78]);
+            assert_eq!(
+                compact.len(),
+                1 + 2 * (2 + 78) + (2 + 32) + 3 + (2 + 44) + 2
+            );
+        }
+        assert_eq!(compact[compact.len() - 2..], [0xa1, b'0']);
+
+        #[cfg(feature = "alloc")]
+        {
+            #[derive(Debug, PartialEq, Deserialize, Serialize)]
+            struct Batch {
+                #[serde(with = "bytes::le::vec")]
+                amounts: Vec<U256>,
+                #[serde(with = "prefixed::option")]
+                limit: Option<I256>,
+            }
+
+            let batch = Batch {
+                amounts: vec![U256::ZERO, U256::MAX],
+                limit: Some(I256::new(-1)),
+            };
+            for msgpack in [
+                rmp_serde::to_vec(&batch).unwrap(),
+                rmp_serde::to_vec_named(&batch).unwrap(),
+            ] {
+                assert_eq!(rmp_serde::from_slice::<Batch>(&msgpack).unwrap(), batch);
+            }
+        }
+    }
+
+    #[test]
+    fn bytes_round_trips() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Balances {
+            #[serde(with = "bytes::be")]
+            be: U256,
+            #[serde(with = "bytes::le")]
+            le: U256,
+            #[serde(with = "bytes::be")]
+            signed_be: I256,
+            #[serde(with = "bytes::le")]
+            signed_le: I256,
+        }
+
+        let balances = Balances {
+            be: U256::new(0x2a),
+            le: U256::MAX - 1,
+            signed_be: I256::new(-42),
+            signed_le: I256::MIN,
+        };
+
+        let json = serde_json::to_value(&balances).unwrap();
+        let mut be = [0u8; 32];
+        be[31] = 0x2a;
+        assert_eq!(json["be"], serde_json::json!(be));
+        let mut signed_le = [0u8; 32];
+        signed_le[31] = 0x80;
+        assert_eq!(json["signed_le"], serde_json::json!(signed_le));
+        assert_eq!(serde_json::from_value::<Balances>(json).unwrap(), balances);
+
+        let bincode = bincode::serialize(&balances).unwrap();
+        assert_eq!(bincode.len(), 4 * (8 + 32));
+        assert_eq!(
+            bincode::deserialize::<Balances>(&bincode).unwrap(),
+            balances
+        );
+
+        let postcard = postcard::to_allocvec(&balances).unwrap();
+        assert_eq!(postcard.len(), 4 * (1 + 32));
+        assert_eq!(
+            postcard::from_bytes::<Balances>(&postcard).unwrap(),
+            balances
+        );
+
+        #[derive(Debug, Deserialize)]
+        struct Be(#[serde(with = "bytes::be")] U256);
+
+        let de = |json: &str| serde_json::from_str::<Be>(json).map(|b| b.0);
+        assert_eq!(de(&format!("{be:?}")).unwrap(), U256::new(0x2a));
+        assert!(de(&format!("{:?}", [0u8; 31])).is_err());
+        assert!(de(&format!("{:?}", [0u8; 33])).is_err());
+        assert!(de("[256]").is_err());
+        assert!(bincode::deserialize::<Be>(&bincode[..39]).is_err());
+    }
+
+    #[test]
+    fn bson_round_trips() {
+        use ::bson::{from_document, spec::BinarySubtype, to_document, Binary, Bson, Document};
+        use alloc::string::ToString;
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Account {
+            balance: U256,
+            delta: I256,
+            #[serde(with = "bson_binary")]
+            stored: U256,
+            #[serde(with = "bson_binary")]
+            debt: I256,
+        }
+
+        for account in [
+            Account {
+                balance: U256::MAX,
+                delta: I256::MIN,
+                stored: U256::new(0x2a),
+                debt: I256::new(-42),
+            },
+            Account {
+                balance: U256::ZERO,
+                delta: I256::MINUS_ONE,
+                stored: U256::MAX,
+                debt: I256::MIN,
+            },
+        ] {
+            let document = to_document(&account).unwrap();
+            assert_eq!(
+                document.get("balance"),
+                Some(&Bson::String(account.balance.to_string()))
+            );
+            assert_eq!(
+                document.get("debt"),
+                Some(&Bson::Binary(Binary {
+                    subtype: BinarySubtype::Generic,
+                    bytes: account.debt.to_be_bytes().to_vec(),
+                }))
+            );
+            assert_eq!(from_document::<Account>(document).unwrap(), account);
+        }
+
+        // The default implementations read bytes as a decimal string, so
+        // binary values must be read with `bson_binary` instead.
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Stored {
+            value: I256,
+        }
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct StoredBinary {
+            #[serde(with = "bson_binary")]
+            value: I256,
+        }
+
+        let document = |bytes: &[u8]| {
+            let mut document = Document::new();
+            document.insert(
+                "value",
+                Bson::Binary(Binary {
+                    subtype: BinarySubtype::Generic,
+                    bytes: bytes.to_vec(),
+                }),
+            );
+            document
+        };
+
+        let mut bytes = [0xff; 32];
+        bytes[31] = 0xd6;
+        assert!(from_document::<Stored>(document(&bytes)).is_err());
+        assert_eq!(
+            from_document::<StoredBinary>(document(&bytes)).unwrap(),
+            StoredBinary {
+                value: I256::new(-42)
+            }
+        );
+        assert!(from_document::<StoredBinary>(document(&bytes[1..])).is_err());
+
+        let digits = [b'1'; 32];
+        assert_eq!(
+            from_document::<Stored>(document(&digits)).unwrap(),
+            Stored {
+                value: I256::from_str_radix(str::from_utf8(&digits).unwrap(), 10).unwrap()
+            }
+        );
+        assert_eq!(
+            from_document::<StoredBinary>(document(&digits)).unwrap(),
+            StoredBinary {
+                value: I256::from_be_bytes(digits)
+            }
+        );
+    }
+
+    #[test]
+    fn words_match_previous_derived_format() {
+        // The implementations derived by previous versions.
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct U256([u128; 2]);
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct I256([i128; 2]);
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Legacy {
+            a: U256,
+            b: I256,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Words {
+            #[serde(with = "words")]
+            a: super::U256,
+            #[serde(with = "words")]
+            b: super::I256,
+        }
+
+        for (a, b) in [
+            (super::U256::new(42), super::I256::new(-42)),
+            (super::U256::MAX, super::I256::MIN),
+            (
+                super::U256::from_words(1, 2),
+                super::I256::from_words(-1, 2),
+            ),
+        ] {
+            let legacy = Legacy {
+                a: U256(a.0),
+                b: I256(b.0),
+            };
+            let words = Words { a, b };
+
+            let json = serde_json::to_string(&legacy).unwrap();
+            assert_eq!(serde_json::to_string(&words).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Words>(&json).unwrap(), words);
+
+            let bincode = bincode::serialize(&legacy).unwrap();
+            assert_eq!(bincode::serialize(&words).unwrap(), bincode);
+            assert_eq!(bincode::deserialize::<Words>(&bincode).unwrap(), words);
+
+            let msgpack = rmp_serde::to_vec(&legacy).unwrap();
+            assert_eq!(rmp_serde::to_vec(&words).unwrap(), msgpack);
+            assert_eq!(rmp_serde::from_slice::<Words>(&msgpack).unwrap(), words);
+        }
+
+        #[cfg(target_endian = "little")]
+        assert_eq!(
+            serde_json::to_string(&Words {
+                a: super::U256::new(42),
+                b: super::I256::MINUS_ONE,
+            })
+            .unwrap(),
+            r#"{"a":[42,0],"b":[-1,-1]}"#
+        );
+    }
+
+    #[test]
+    fn compressed_bytes_round_trips() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Be(#[serde(with = "compressed_bytes::be")] I256);
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Le(#[serde(with = "compressed_bytes::le")] I256);
+
+        for (value, be) in [
+            (I256::ZERO, &[][..]),
+            (I256::new(42), &[0x2a]),
+            (I256::new(-42), &[0xd6]),
+            (I256::new(0x7f), &[0x7f]),
+            (I256::new(0x80), &[0x00, 0x80]),
+            (I256::new(-0x80), &[0x80]),
+            (I256::new(-0x81), &[0xff, 0x7f]),
+            (I256::new(0x8000), &[0x00, 0x80, 0x00]),
+            (I256::new(-0x8000), &[0x80, 0x00]),
+            (I256::MINUS_ONE, &[0xff]),
+        ] {
+            let json = serde_json::to_value(Be(value)).unwrap();
+            assert_eq!(json, serde_json::json!(be));
+            assert_eq!(serde_json::from_value::<Be>(json).unwrap(), Be(value));
+
+            let le = be.iter().rev().copied().collect::<Vec<_>>();
+            let json = serde_json::to_value(Le(value)).unwrap();
+            assert_eq!(json, serde_json::json!(le));
+            assert_eq!(serde_json::from_value::<Le>(json).unwrap(), Le(value));
+
+            let bincode = bincode::serialize(&Be(value)).unwrap();
+            assert_eq!(bincode.len(), 8 + be.len());
+            assert_eq!(bincode::deserialize::<Be>(&bincode).unwrap(), Be(value));
+
+            let postcard = postcard::to_allocvec(&Le(value)).unwrap();
+            assert_eq!(postcard.len(), 1 + be.len());
+            assert_eq!(postcard::from_bytes::<Le>(&postcard).unwrap(), Le(value));
+        }
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Unsigned(#[serde(with = "compressed_bytes::be")] U256);
+
+        let json = serde_json::to_value(Unsigned(U256::new(0x80))).unwrap();
+        assert_eq!(json, serde_json::json!([0x80]));
+        assert_eq!(
+            serde_json::from_str::<Unsigned>("[0, 0, 128]").unwrap(),
+            Unsigned(U256::new(0x80))
+        );
+        assert_eq!(serde_json::from_str::<Be>("[0]").unwrap(), Be(I256::ZERO));
+
+        assert!(serde_json::from_str::<Be>(&format!("{:?}", [0u8; 33])).is_err());
+        assert!(serde_json::from_str::<Unsigned>(&format!("{:?}", [0u8; 33])).is_err());
+        assert!(bincode::deserialize::<Be>(&bincode::serialize(&[0u8; 33][..]).unwrap()).is_err());
+    }
+
     #[test]
     fn formatting_buffer() {
         for value in [
@@ -1148,6 +3336,9 @@ mod tests {
             assert_eq!(f.as_str(), format!("{value}"));
         }
 
+        let mut f = FormatBuffer::decimal();
+        assert!(write!(f, "{}{}", U256::MAX, 0).is_err());
+
         for value in [
             Box::new(I256::MIN) as Box<dyn LowerHex>,
             Box::new(I256::MAX),
@@ -1161,6 +3352,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn quantity_rpc_fixtures() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Block {
+            #[serde(with = "quantity")]
+            number: U256,
+            #[serde(with = "quantity")]
+            difficulty: U256,
+            #[serde(with = "quantity")]
+            total_difficulty: U256,
+            #[serde(with = "quantity")]
+            gas_limit: U256,
+            #[serde(with = "quantity")]
+            gas_used: U256,
+            #[serde(with = "quantity")]
+            base_fee_per_gas: U256,
+            #[serde(with = "quantity")]
+            timestamp: U256,
+        }
+
+        // Quantity fields of a mainnet `eth_getBlockByNumber` response.
+        let fixture = r#"{
+            "number": "0x103e176",
+            "difficulty": "0x0",
+            "totalDifficulty": "0xc70d815d562d3cfa955",
+            "gasLimit": "0x1c9c380",
+            "gasUsed": "0xe5b4ee",
+            "baseFeePerGas": "0x7d9c2a0ba",
+            "timestamp": "0x6437fb2f"
+        }"#;
+        let block = serde_json::from_str::<Block>(fixture).unwrap();
+        assert_eq!(
+            block,
+            Block {
+                number: U256::new(17031542),
+                difficulty: U256::ZERO,
+                total_difficulty: U256::new(58750003716598352816469),
+                gas_limit: U256::new(30_000_000),
+                gas_used: U256::new(0xe5b4ee),
+                base_fee_per_gas: U256::new(0x7d9c2a0ba),
+                timestamp: U256::new(0x6437fb2f),
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(&block).unwrap(),
+            serde_json::from_str::<serde_json::Value>(fixture).unwrap(),
+        );
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Quantity<T>(#[serde(with = "quantity")] T)
+        where
+            T: quantity::Quantity;
+
+        // Captured `eth_getBalance` and `eth_blockNumber` results.
+        for fixture in [
+            "\"0x0\"",
+            "\"0x1\"",
+            "\"0x103e176\"",
+            "\"0x2d5a2a80d8f4bcc5b9\"",
+            "\"0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\"",
+        ] {
+            let value = serde_json::from_str::<Quantity<U256>>(fixture).unwrap();
+            assert_eq!(serde_json::to_string(&value).unwrap(), fixture);
+        }
+
+        for fixture in ["\"-0x1\"", "\"0x0\"", "\"-0x2a\""] {
+            let value = serde_json::from_str::<Quantity<I256>>(fixture).unwrap();
+            assert_eq!(serde_json::to_string(&value).unwrap(), fixture);
+        }
+        assert_eq!(
+            serde_json::to_string(&Quantity(I256::MIN)).unwrap(),
+            format!("\"-0x8{}\"", "0".repeat(63)),
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Quantity<U256>>("\"0xABCDEF\"").unwrap(),
+            Quantity(U256::new(0xabcdef)),
+        );
+        for invalid in [
+            "\"\"",
+            "\"0x\"",
+            "\"0x0123\"",
+            "\"0x00\"",
+            "\"123\"",
+            "\"0X1\"",
+            "\"+0x1\"",
+            "\"0x-1\"",
+            "\"0xg\"",
+            "\" 0x1\"",
+            "\"-0x1\"",
+            "\"0x1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\"",
+            "42",
+        ] {
+            assert!(serde_json::from_str::<Quantity<U256>>(invalid).is_err());
+        }
+        for invalid in ["\"-0x0\"", "\"-0x\"", "\"-0x01\"", "\"--0x1\""] {
+            assert!(serde_json::from_str::<Quantity<I256>>(invalid).is_err());
+        }
+    }
+
+    /// Returns whether `serde_json` was built with the `arbitrary_precision`
+    /// feature, in which case it preserves integers beyond 64 bits.
+    fn json_arbitrary_precision() -> bool {
+        let value = serde_json::from_str::<serde_json::Value>("18446744073709551616").unwrap();
+        serde_json::to_string(&value).unwrap() == "18446744073709551616"
+    }
+
     /// A string serializer used for testing.
     struct StringSerializer;
 
@@ -1219,9 +3518,9 @@ mod tests {
         fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
             unimplemented!()
         }
-        fn serialize_some<T: ?Sized>(self, _: &T) -> Result<Self::Ok, Self::Error>
+        fn serialize_some<T>(self, _: &T) -> Result<Self::Ok, Self::Error>
         where
-            T: Serialize,
+            T: ?Sized + Serialize,
         {
             unimplemented!()
         }
@@ -1239,17 +3538,17 @@ mod tests {
         ) -> Result<Self::Ok, Self::Error> {
             unimplemented!()
         }
-        fn serialize_newtype_struct<T: ?Sized>(
+        fn serialize_newtype_struct<T>(
             self,
             _: &'static str,
             _: &T,
         ) -> Result<Self::Ok, Self::Error>
         where
-            T: Serialize,
+            T: ?Sized + Serialize,
         {
             unimplemented!()
         }
-        fn serialize_newtype_variant<T: ?Sized>(
+        fn serialize_newtype_variant<T>(
             self,
             _: &'static str,
             _: u32,
@@ -1257,7 +3556,7 @@ mod tests {
             _: &T,
         ) -> Result<Self::Ok, Self::Error>
         where
-            T: Serialize,
+            T: ?Sized + Serialize,
         {
             unimplemented!()
         }
@@ -1368,9 +3667,9 @@ mod tests {
         fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
             unimplemented!()
         }
-        fn serialize_some<T: ?Sized>(self, _: &T) -> Result<Self::Ok, Self::Error>
+        fn serialize_some<T>(self, _: &T) -> Result<Self::Ok, Self::Error>
         where
-            T: Serialize,
+            T: ?Sized + Serialize,
         {
             unimplemented!()
         }
@@ -1388,17 +3687,17 @@ mod tests {
         ) -> Result<Self::Ok, Self::Error> {
             unimplemented!()
         }
-        fn serialize_newtype_struct<T: ?Sized>(
+        fn serialize_newtype_struct<T>(
             self,
             _: &'static str,
             _: &T,
         ) -> Result<Self::Ok, Self::Error>
         where
-            T: Serialize,
+            T: ?Sized + Serialize,
         {
             unimplemented!()
         }
-        fn serialize_newtype_variant<T: ?Sized>(
+        fn serialize_newtype_variant<T>(
             self,
             _: &'static str,
             _: u32,
@@ -1406,7 +3705,7 @@ mod tests {
             _: &T,
         ) -> Result<Self::Ok, Self::Error>
         where
-            T: Serialize,
+            T: ?Sized + Serialize,
         {
             unimplemented!()
         }
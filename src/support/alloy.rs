@@ -0,0 +1,90 @@
+//! Module that implements conversions to and from the
+//! [`alloy-primitives`](https://crates.io/crates/alloy-primitives) crate's
+//! types.
+//!
+//! `alloy_primitives::U256` is an alias for `ruint`'s 256-bit unsigned integer
+//! type, so its conversions are provided by the `ruint` feature (which is
+//! enabled by this feature). Hashes (`B256`) are interpreted as big endian
+//! numbers.
+//!
+//! Note that alloy's integer types have inherent `from` methods that shadow
+//! `From::from`, so conversions into them are best written with `Into::into`.
+
+use crate::{I256, U256};
+use alloy_primitives::{Signed, B256};
+
+impl From<Signed<256, 4>> for I256 {
+    fn from(value: Signed<256, 4>) -> Self {
+        U256::from(value.into_raw()).as_i256()
+    }
+}
+
+impl From<I256> for Signed<256, 4> {
+    fn from(value: I256) -> Self {
+        Signed::from_raw(value.as_u256().into())
+    }
+}
+
+impl From<B256> for U256 {
+    fn from(value: B256) -> Self {
+        U256::from_be_bytes(value.0)
+    }
+}
+
+impl From<U256> for B256 {
+    fn from(value: U256) -> Self {
+        B256::new(value.to_be_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    fn to_alloy(x: U256) -> alloy_primitives::U256 {
+        x.into()
+    }
+
+    fn to_alloy_signed(x: I256) -> alloy_primitives::I256 {
+        x.into()
+    }
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(to_alloy(U256::MAX), alloy_primitives::U256::MAX);
+        assert_eq!(U256::from(alloy_primitives::U256::ZERO), U256::ZERO);
+
+        for (x, s) in [
+            (I256::MIN, alloy_primitives::I256::MIN),
+            (I256::MAX, alloy_primitives::I256::MAX),
+            (I256::MINUS_ONE, alloy_primitives::I256::MINUS_ONE),
+            (I256::ZERO, alloy_primitives::I256::ZERO),
+        ] {
+            assert_eq!(to_alloy_signed(x), s);
+            assert_eq!(I256::from(s), x);
+        }
+        assert!(to_alloy_signed(I256::MIN).is_negative());
+
+        let x = U256::from_words(0x0102030405060708090a0b0c0d0e0f10, 42);
+        assert_eq!(B256::from(x)[0], 0x01);
+        assert_eq!(B256::from(x)[31], 42);
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(bytes in any::<[u8; 32]>()) {
+            let x = U256::from_be_bytes(bytes);
+            let y = x.as_i256();
+
+            prop_assert_eq!(U256::from(to_alloy(x)), x);
+            prop_assert_eq!(I256::from(to_alloy_signed(y)), y);
+            prop_assert_eq!(U256::from(B256::from(x)), x);
+            prop_assert_eq!(B256::from(x), B256::new(bytes));
+
+            prop_assert_eq!(format!("{}", to_alloy(x)), format!("{x}"));
+            prop_assert_eq!(format!("{}", to_alloy_signed(y)), format!("{y}"));
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Module that implements support for the
+//! [`alloy-rlp`](https://crates.io/crates/alloy-rlp) crate.
+//!
+//! `U256` values are encoded as canonical RLP integers: big endian byte
+//! strings with no leading zeros, where zero is encoded as the empty string.
+//! Decoding rejects encodings with leading zeros as well as strings that are
+//! longer than 32 bytes.
+
+use crate::U256;
+use alloy_rlp::{
+    BufMut, Decodable, Encodable, Error, Header, MaxEncodedLen, MaxEncodedLenAssoc, Result,
+    EMPTY_STRING_CODE,
+};
+
+impl Encodable for U256 {
+    #[inline]
+    fn length(&self) -> usize {
+        if *self < EMPTY_STRING_CODE as u128 {
+            1
+        } else {
+            1 + 32 - self.leading_zeros() as usize / 8
+        }
+    }
+
+    #[inline]
+    fn encode(&self, out: &mut dyn BufMut) {
+        if *self == 0 {
+            out.put_u8(EMPTY_STRING_CODE);
+        } else if *self < EMPTY_STRING_CODE as u128 {
+            out.put_u8(self.as_u8());
+        } else {
+            let bytes = self.to_be_bytes();
+            let bytes = &bytes[self.leading_zeros() as usize / 8..];
+            out.put_u8(EMPTY_STRING_CODE + bytes.len() as u8);
+            out.put_slice(bytes);
+        }
+    }
+}
+
+// SAFETY: the encoding is at most a single byte header followed by 32 bytes.
+unsafe impl MaxEncodedLen<33> for U256 {}
+
+// SAFETY: see above.
+unsafe impl MaxEncodedLenAssoc for U256 {
+    const LEN: usize = 33;
+}
+
+impl Decodable for U256 {
+    #[inline]
+    fn decode(buf: &mut &[u8]) -> Result<Self> {
+        let bytes = Header::decode_bytes(buf, false)?;
+        if bytes.len() > 32 {
+            return Err(Error::Overflow);
+        }
+        if bytes.first() == Some(&0) {
+            return Err(Error::LeadingZero);
+        }
+        let mut buffer = [0; 32];
+        buffer[32 - bytes.len()..].copy_from_slice(bytes);
+        Ok(U256::from_be_bytes(buffer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn ethereum_test_vectors() {
+        // Integer vectors from `RLPTests/rlptest.json` of the Ethereum tests.
+        for (value, encoded) in [
+            (U256::ZERO, &b"\x80"[..]),
+            (U256::new(1), b"\x01"),
+            (U256::new(16), b"\x10"),
+            (U256::new(79), b"\x4f"),
+            (U256::new(127), b"\x7f"),
+            (U256::new(128), b"\x81\x80"),
+            (U256::new(1000), b"\x82\x03\xe8"),
+            (U256::new(100000), b"\x83\x01\x86\xa0"),
+            (
+                U256::new(83729609699884896815286331701780722),
+                b"\x8f\x10\x20\x30\x40\x50\x60\x70\x80\x90\xa0\xb0\xc0\xd0\xe0\xf2",
+            ),
+            (
+                "105315505618206987246253880190783558935785933862974822347068935681"
+                    .parse()
+                    .unwrap(),
+                b"\x9c\x01\x00\x02\x00\x03\x00\x04\x00\x05\x00\x06\x00\x07\
+                  \x00\x08\x00\x09\x00\x0a\x00\x0b\x00\x0c\x00\x0d\x00\x0e\x01",
+            ),
+            (U256::MAX, &[&b"\xa0"[..], &[0xff; 32]].concat()),
+        ] {
+            assert_eq!(alloy_rlp::encode(value), encoded);
+            assert_eq!(value.length(), encoded.len());
+            assert_eq!(alloy_rlp::decode_exact::<U256>(encoded).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn rejects_non_canonical() {
+        for (encoded, err) in [
+            (&b"\x00"[..], Error::LeadingZero),
+            (b"\x81\x00", Error::NonCanonicalSingleByte),
+            (b"\x81\x05", Error::NonCanonicalSingleByte),
+            (b"\x82\x00\x80", Error::LeadingZero),
+            (&[&b"\xa1\x01"[..], &[0; 32]].concat(), Error::Overflow),
+            (b"\xc0", Error::UnexpectedList),
+            (b"\x82\x01", Error::InputTooShort),
+        ] {
+            assert_eq!(alloy_rlp::decode_exact::<U256>(encoded).unwrap_err(), err);
+        }
+    }
+
+    #[test]
+    fn lists() {
+        let values = [U256::ZERO, U256::new(0x400), U256::MAX];
+        let mut encoded = Vec::new();
+        alloy_rlp::encode_list::<_, U256>(&values, &mut encoded);
+        assert_eq!(encoded.len(), 1 + 1 + 3 + 33);
+        assert_eq!(
+            alloy_rlp::decode_exact::<Vec<U256>>(&encoded).unwrap(),
+            values
+        );
+    }
+}
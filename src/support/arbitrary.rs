@@ -0,0 +1,150 @@
+//! Module that implements support for the [`arbitrary`](https://crates.io/crates/arbitrary)
+//! crate.
+//!
+//! [`Arbitrary::arbitrary`] always consumes exactly 32 bytes of unstructured
+//! data, which are interpreted as a little endian integer (two's complement
+//! for `I256`). If there is not enough data left, the missing bytes are zero.
+//!
+//! Uniformly sampled 256-bit integers almost never hit edge cases like zero or
+//! the maximum value, so [`Arbitrary::arbitrary_take_rest`], which is used by
+//! `cargo fuzz` for the top level fuzz input, uses the biased
+//! `arbitrary_biased` generator instead. It can also be called directly from
+//! `Arbitrary` implementations of types that contain 256-bit integers.
+
+use crate::{I256, U256};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// The number of choices for the selector byte of biased generators. The
+/// choices that don't select an edge case or a special shape produce
+/// uniformly sampled values.
+const CHOICES: u8 = 16;
+
+macro_rules! impl_arbitrary {
+    ($($t:ident => [$($edge:ident),*];)*) => {$(
+        impl<'a> Arbitrary<'a> for $t {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let mut bytes = [0; 32];
+                u.fill_buffer(&mut bytes)?;
+                Ok($t::from_le_bytes(bytes))
+            }
+
+            fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+                $t::arbitrary_biased(&mut u)
+            }
+
+            #[inline]
+            fn size_hint(_: usize) -> (usize, Option<usize>) {
+                (32, Some(32))
+            }
+        }
+
+        impl $t {
+            #[doc = concat!("Generates an arbitrary `", stringify!($t), "` that is biased towards edge cases.")]
+            ///
+            /// This consumes one selector byte, which picks one of the edge
+            #[doc = concat!("cases ", $("`", stringify!($edge), "`, ",)* "a value with only")]
+            /// the high or low word set, a power of two, or a power of two
+            /// minus one, each with a probability of 1/16. Otherwise, and for
+            /// the special shapes, it then consumes 32 more bytes in the same
+            /// way as [`Arbitrary::arbitrary`]. Overall, roughly half of the
+            /// generated values are uniformly sampled.
+            pub fn arbitrary_biased(u: &mut Unstructured<'_>) -> Result<Self> {
+                const EDGES: &[$t] = &[$($t::$edge),*];
+
+                let choice = (u8::arbitrary(u)? % CHOICES) as usize;
+                if let Some(edge) = EDGES.get(choice) {
+                    return Ok(*edge);
+                }
+
+                let value = $t::arbitrary(u)?;
+                let shift = value.as_u32() % 256;
+                Ok(match choice - EDGES.len() {
+                    0 => $t::from_words(*value.high(), 0),
+                    1 => $t::from_words(0, *value.low()),
+                    2 => $t::ONE << shift,
+                    3 => ($t::ONE << shift).wrapping_sub($t::ONE),
+                    _ => value,
+                })
+            }
+        }
+    )*};
+}
+
+impl_arbitrary! {
+    U256 => [ZERO, ONE, MAX];
+    I256 => [ZERO, ONE, MINUS_ONE, MIN, MAX];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn consumes_exactly_32_bytes() {
+        let data = (0..40).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&data);
+        let value = U256::arbitrary(&mut u).unwrap();
+        assert_eq!(value.to_le_bytes(), data[..32]);
+        assert_eq!(u.len(), 8);
+
+        let value = I256::arbitrary(&mut u).unwrap();
+        assert_eq!(value.to_le_bytes()[..8], data[32..]);
+        assert_eq!(value.to_le_bytes()[8..], [0; 24]);
+        assert!(u.is_empty());
+
+        assert_eq!(U256::arbitrary(&mut u).unwrap(), U256::ZERO);
+        assert_eq!(U256::size_hint(0), (32, Some(32)));
+        assert_eq!(I256::size_hint(0), (32, Some(32)));
+    }
+
+    #[test]
+    fn biased_edge_cases() {
+        let biased = |data: &[u8]| U256::arbitrary_biased(&mut Unstructured::new(data)).unwrap();
+        assert_eq!(biased(&[0]), U256::ZERO);
+        assert_eq!(biased(&[1]), U256::ONE);
+        assert_eq!(biased(&[2]), U256::MAX);
+        assert_eq!(biased(&[3, 0xff]), U256::from_words(0, 0));
+        assert_eq!(
+            biased(&[3, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17]),
+            U256::from_words(17, 0)
+        );
+        assert_eq!(biased(&[4, 42]), U256::new(42));
+        assert_eq!(biased(&[5, 255]), U256::ONE << 255u32);
+        assert_eq!(biased(&[6, 128]), U256::new(u128::MAX));
+        assert_eq!(biased(&[6, 0]), U256::ZERO);
+        assert_eq!(biased(&[7, 42]), U256::new(42));
+        assert_eq!(biased(&[16 + 2]), U256::MAX);
+
+        let biased = |data: &[u8]| I256::arbitrary_biased(&mut Unstructured::new(data)).unwrap();
+        assert_eq!(biased(&[2]), I256::MINUS_ONE);
+        assert_eq!(biased(&[3]), I256::MIN);
+        assert_eq!(biased(&[4]), I256::MAX);
+        assert_eq!(biased(&[7, 255]), I256::MIN);
+        assert_eq!(biased(&[8, 255]), I256::MAX);
+
+        // Without any data, the generators produce zero.
+        assert_eq!(
+            U256::arbitrary_take_rest(Unstructured::new(&[])).unwrap(),
+            U256::ZERO
+        );
+        assert_eq!(
+            I256::arbitrary_biased(&mut Unstructured::new(&[])).unwrap(),
+            I256::ZERO
+        );
+    }
+
+    #[test]
+    fn biased_distribution() {
+        let data = [100; 33];
+        let hits = (0..=255)
+            .map(|choice| {
+                let mut data = data;
+                data[0] = choice;
+                U256::arbitrary_take_rest(Unstructured::new(&data)).unwrap()
+            })
+            .filter(|value| [U256::ZERO, U256::ONE, U256::MAX].contains(value))
+            .count();
+        assert_eq!(hits, 3 * 256 / CHOICES as usize);
+    }
+}
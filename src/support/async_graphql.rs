@@ -0,0 +1,182 @@
+//! Module that implements support for the
+//! [`async-graphql`](https://crates.io/crates/async-graphql) crate.
+//!
+//! The 256-bit integer types are GraphQL scalars named `U256` and `I256`. Like
+//! the `serde` implementation, values are output as decimal strings, since
+//! GraphQL integers are limited to 32 bits and JSON numbers lose precision in
+//! most clients. Inputs can either be decimal strings or integer literals.
+
+use crate::{AsI256 as _, I256, U256};
+use async_graphql::{
+    InputType, InputValueError, InputValueResult, Number, Scalar, ScalarType, Value,
+};
+use std::string::ToString as _;
+
+/// Converts an integer GraphQL number to a 256-bit integer.
+fn from_number<T>(number: &Number) -> InputValueResult<T>
+where
+    T: InputType + TryFrom<I256>,
+    T::Error: core::fmt::Display,
+{
+    let value = match (number.as_i64(), number.as_u64()) {
+        (Some(value), _) => value.as_i256(),
+        (None, Some(value)) => value.as_i256(),
+        _ => {
+            return Err(InputValueError::custom(format_args!(
+                "expected an integer, found the floating point number {number}"
+            )))
+        }
+    };
+    T::try_from(value).map_err(InputValueError::custom)
+}
+
+macro_rules! impl_async_graphql {
+    ($($t:ident),*) => {$(
+        #[Scalar]
+        impl ScalarType for $t {
+            fn parse(value: Value) -> InputValueResult<Self> {
+                match &value {
+                    Value::String(s) => $t::from_str_radix(s, 10).map_err(InputValueError::custom),
+                    Value::Number(number) => from_number(number),
+                    _ => Err(InputValueError::expected_type(value)),
+                }
+            }
+
+            fn is_valid(value: &Value) -> bool {
+                matches!(value, Value::String(_) | Value::Number(_))
+            }
+
+            fn to_value(&self) -> Value {
+                Value::String(self.to_string())
+            }
+        }
+    )*};
+}
+
+impl_async_graphql!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{EmptySubscription, InputObject, Object, Schema, SimpleObject};
+    use futures_executor::block_on;
+    use std::{format, string::String, vec::Vec};
+
+    #[derive(Clone, InputObject, SimpleObject)]
+    #[graphql(input_name = "TransferInput")]
+    struct Transfer {
+        amount: U256,
+        delta: I256,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn max(&self) -> U256 {
+            U256::MAX
+        }
+
+        async fn negate(&self, value: I256) -> I256 {
+            -value
+        }
+    }
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn transfer(&self, transfer: Transfer) -> Transfer {
+            Transfer {
+                amount: transfer.amount * 2,
+                delta: transfer.delta,
+            }
+        }
+    }
+
+    fn execute(query: &str) -> Result<serde_json::Value, Vec<String>> {
+        let schema = Schema::new(Query, Mutation, EmptySubscription);
+        let response = block_on(schema.execute(query));
+        if response.errors.is_empty() {
+            Ok(response.data.into_json().unwrap())
+        } else {
+            Err(response.errors.into_iter().map(|e| e.message).collect())
+        }
+    }
+
+    #[test]
+    fn query() {
+        assert_eq!(
+            execute("{ max }").unwrap(),
+            serde_json::json!({ "max": U256::MAX.to_string() })
+        );
+        assert_eq!(
+            execute(r#"{ a: negate(value: "-18446744073709551616") b: negate(value: -42) }"#)
+                .unwrap(),
+            serde_json::json!({ "a": "18446744073709551616", "b": "42" })
+        );
+    }
+
+    #[test]
+    fn mutation() {
+        let amount = U256::new(u64::MAX as u128) * 1000;
+        let response = execute(&format!(
+            r#"mutation {{
+                transfer(transfer: {{ amount: "{amount}", delta: 18446744073709551615 }}) {{
+                    amount
+                    delta
+                }}
+            }}"#
+        ))
+        .unwrap();
+        assert_eq!(
+            response,
+            serde_json::json!({
+                "transfer": {
+                    "amount": (amount * 2).to_string(),
+                    "delta": "18446744073709551615",
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            <U256 as ScalarType>::parse(Value::String(U256::MAX.to_string())).unwrap(),
+            U256::MAX
+        );
+        assert_eq!(
+            <I256 as ScalarType>::parse(Value::Number((-1).into())).unwrap(),
+            I256::MINUS_ONE
+        );
+        assert_eq!(
+            <U256 as ScalarType>::parse(Value::Number(u64::MAX.into())).unwrap(),
+            U256::new(u64::MAX as u128)
+        );
+
+        for value in [
+            Value::Number((-1).into()),
+            Value::String("-1".into()),
+            Value::String("0x10".into()),
+            Value::Number(Number::from_f64(1.5).unwrap()),
+            Value::Boolean(true),
+        ] {
+            assert!(<U256 as ScalarType>::parse(value).is_err());
+        }
+
+        let err =
+            <I256 as ScalarType>::parse(Value::Number(Number::from_f64(1.0).unwrap())).unwrap_err();
+        assert_eq!(
+            err.into_server_error(Default::default()).message,
+            r#"Failed to parse "I256": expected an integer, found the floating point number 1.0"#
+        );
+    }
+
+    #[test]
+    fn rejects_float_literals() {
+        let errors = execute("{ negate(value: 1.5) }").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("expected an integer"), "{errors:?}");
+    }
+}
@@ -0,0 +1,116 @@
+//! Module that implements support for the `Encode` and `Decode` traits of
+//! version 2 of the [`bincode`](https://crates.io/crates/bincode) crate.
+//!
+//! The 256-bit integer types are always encoded as exactly 32 bytes in little
+//! endian order (two's complement for `I256`), regardless of the integer
+//! encoding and endianness of the configuration. Variable-length integer
+//! encoding is not used, as values tend to be large and would end up taking
+//! more space than the fixed-width encoding.
+
+use crate::{I256, U256};
+use bincode_v2::{
+    de::{read::Reader as _, BorrowDecoder, Decoder},
+    enc::{write::Writer as _, Encoder},
+    error::{DecodeError, EncodeError},
+    BorrowDecode, Decode, Encode,
+};
+
+macro_rules! impl_bincode {
+    ($($t:ident),*) => {$(
+        impl Encode for $t {
+            #[inline]
+            fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+                encoder.writer().write(&self.to_le_bytes())
+            }
+        }
+
+        impl<Context> Decode<Context> for $t {
+            #[inline]
+            fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+                decoder.claim_bytes_read(32)?;
+                let mut bytes = [0; 32];
+                decoder.reader().read(&mut bytes)?;
+                Ok($t::from_le_bytes(bytes))
+            }
+        }
+
+        impl<'de, Context> BorrowDecode<'de, Context> for $t {
+            #[inline]
+            fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+                decoder: &mut D,
+            ) -> Result<Self, DecodeError> {
+                Decode::decode(decoder)
+            }
+        }
+    )*};
+}
+
+impl_bincode!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bincode_v2::{
+        borrow_decode_from_slice, config, decode_from_slice, encode_into_slice, error::DecodeError,
+    };
+
+    #[test]
+    fn round_trips() {
+        let mut buffer = [0; 64];
+        for value in [U256::ZERO, U256::new(42), U256::ONE << 200u32, U256::MAX] {
+            let len = encode_into_slice(value, &mut buffer, config::standard()).unwrap();
+            assert_eq!(len, 32);
+            assert_eq!(buffer[..32], value.to_le_bytes());
+            assert_eq!(
+                decode_from_slice::<U256, _>(&buffer, config::standard()).unwrap(),
+                (value, 32)
+            );
+            assert_eq!(
+                borrow_decode_from_slice::<U256, _>(&buffer, config::standard()).unwrap(),
+                (value, 32)
+            );
+        }
+
+        for value in [I256::MIN, I256::new(-42), I256::MAX] {
+            let config = config::standard().with_big_endian();
+            let len = encode_into_slice(value, &mut buffer, config).unwrap();
+            assert_eq!(len, 32);
+            assert_eq!(buffer[..32], value.to_le_bytes());
+            assert_eq!(
+                decode_from_slice::<I256, _>(&buffer, config).unwrap(),
+                (value, 32)
+            );
+
+            let len = encode_into_slice(value, &mut buffer, config::legacy()).unwrap();
+            assert_eq!(len, 32);
+            assert_eq!(
+                decode_from_slice::<I256, _>(&buffer, config::legacy()).unwrap(),
+                (value, 32)
+            );
+        }
+    }
+
+    #[test]
+    fn encoded_size() {
+        let mut buffer = [0; 128];
+        let values = (U256::new(1), I256::new(-1), [U256::MAX; 2]);
+        let len = encode_into_slice(values, &mut buffer, config::standard()).unwrap();
+        assert_eq!(len, 4 * 32);
+        assert_eq!(
+            decode_from_slice::<(U256, I256, [U256; 2]), _>(&buffer, config::standard()).unwrap(),
+            (values, 4 * 32)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            decode_from_slice::<U256, _>(&[0; 31], config::standard()),
+            Err(DecodeError::UnexpectedEnd { .. })
+        ));
+        assert!(
+            decode_from_slice::<U256, _>(&[0; 32], config::standard().with_limit::<16>()).is_err()
+        );
+        assert!(encode_into_slice(U256::MAX, &mut [0; 31], config::standard()).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+//! Module that implements conversions to and from the
+//! [`bnum`](https://crates.io/crates/bnum) crate's fixed width integer types.
+//!
+//! All conversions go through little endian `u64` digits.
+
+use crate::{error, I256, U256};
+use bnum::types;
+use core::num::TryFromIntError;
+
+impl From<types::U256> for U256 {
+    fn from(value: types::U256) -> Self {
+        U256::from_limbs(*value.digits())
+    }
+}
+
+impl From<U256> for types::U256 {
+    fn from(value: U256) -> Self {
+        types::U256::from_digits(value.into_limbs())
+    }
+}
+
+impl From<types::I256> for I256 {
+    fn from(value: types::I256) -> Self {
+        U256::from(value.to_bits()).as_i256()
+    }
+}
+
+impl From<I256> for types::I256 {
+    fn from(value: I256) -> Self {
+        types::I256::from_bits(value.as_u256().into())
+    }
+}
+
+impl TryFrom<types::U512> for U256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: types::U512) -> Result<Self, Self::Error> {
+        let digits = value.digits();
+        if digits[4..] != [0; 4] {
+            return Err(error::tfie());
+        }
+        Ok(U256::from_limbs([
+            digits[0], digits[1], digits[2], digits[3],
+        ]))
+    }
+}
+
+impl From<U256> for types::U512 {
+    fn from(value: U256) -> Self {
+        let [a, b, c, d] = value.into_limbs();
+        types::U512::from_digits([a, b, c, d, 0, 0, 0, 0])
+    }
+}
+
+impl TryFrom<types::I512> for I256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: types::I512) -> Result<Self, Self::Error> {
+        let bits = value.to_bits();
+        let digits = bits.digits();
+        let x = U256::from_limbs([digits[0], digits[1], digits[2], digits[3]]).as_i256();
+        let ext = if x.is_negative() { u64::MAX } else { 0 };
+        if digits[4..] != [ext; 4] {
+            return Err(error::tfie());
+        }
+        Ok(x)
+    }
+}
+
+impl From<I256> for types::I512 {
+    fn from(value: I256) -> Self {
+        let [a, b, c, d] = value.as_u256().into_limbs();
+        let ext = if value.is_negative() { u64::MAX } else { 0 };
+        types::I512::from_bits(types::U512::from_digits([a, b, c, d, ext, ext, ext, ext]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(types::U256::from(U256::MAX), types::U256::MAX);
+        assert_eq!(U256::from(types::U256::ZERO), U256::ZERO);
+        assert_eq!(types::I256::from(I256::MIN), types::I256::MIN);
+        assert_eq!(types::I256::from(I256::MAX), types::I256::MAX);
+        assert_eq!(types::I256::from(I256::MINUS_ONE), types::I256::NEG_ONE);
+
+        let max = types::U512::from(U256::MAX);
+        assert_eq!(U256::try_from(max).unwrap(), U256::MAX);
+        assert!(U256::try_from(max + types::U512::ONE).is_err());
+        assert!(U256::try_from(types::U512::MAX).is_err());
+
+        let (min, max) = (types::I512::from(I256::MIN), types::I512::from(I256::MAX));
+        assert_eq!(min, -(types::I512::ONE << 255u32));
+        assert_eq!(I256::try_from(min).unwrap(), I256::MIN);
+        assert_eq!(I256::try_from(max).unwrap(), I256::MAX);
+        assert!(I256::try_from(min - types::I512::ONE).is_err());
+        assert!(I256::try_from(max + types::I512::ONE).is_err());
+        assert!(I256::try_from(types::I512::MIN).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(limbs in any::<[u64; 4]>()) {
+            let x = U256::from_limbs(limbs);
+            let y = x.as_i256();
+
+            prop_assert_eq!(U256::from(types::U256::from(x)), x);
+            prop_assert_eq!(I256::from(types::I256::from(y)), y);
+            prop_assert_eq!(U256::try_from(types::U512::from(x)).unwrap(), x);
+            prop_assert_eq!(I256::try_from(types::I512::from(y)).unwrap(), y);
+
+            prop_assert_eq!(format!("{}", types::U256::from(x)), format!("{x}"));
+            prop_assert_eq!(format!("{}", types::I256::from(y)), format!("{y}"));
+            prop_assert_eq!(format!("{}", types::I512::from(y)), format!("{y}"));
+        }
+    }
+}
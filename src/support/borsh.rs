@@ -0,0 +1,167 @@
+//! Module that implements support for version 0.10 of the
+//! [`borsh`](https://crates.io/crates/borsh) crate, enabled with the `borsh`
+//! or `borsh-v0_10` features. See the `borsh-v1` feature for version 1.
+//!
+//! Both 256-bit integer types are encoded as exactly 32 bytes in little endian
+//! order (two's complement for `I256`), which extends the way `borsh` encodes
+//! `u128` and `i128`. This encoding is the same on all platforms.
+//!
+//! Previous versions derived the `borsh` traits, which encoded the internal
+//! `[u128; 2]` words in memory order. On little endian targets (including
+//! `wasm32`) this is byte-for-byte identical to the current encoding, so
+//! existing state does not need to be migrated. Only data written by big
+//! endian hosts used the previous word order, and needs to be re-encoded with
+//! its two 16-byte halves swapped.
+//!
+//! The `BorshSchema` of both types declares them as `"U256"` and `"I256"`
+//! respectively, defined as fixed arrays of 32 bytes.
+
+use crate::{I256, U256};
+use borsh::{
+    maybestd::{
+        collections::HashMap,
+        io::{Read, Result, Write},
+    },
+    schema::{Declaration, Definition},
+    BorshDeserialize, BorshSchema, BorshSerialize,
+};
+
+macro_rules! impl_borsh {
+    ($($t:ident),*) => {$(
+        impl BorshSerialize for $t {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+        }
+
+        impl BorshDeserialize for $t {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                let mut bytes = [0; 32];
+                reader.read_exact(&mut bytes)?;
+                Ok(<$t>::from_le_bytes(bytes))
+            }
+        }
+
+        impl BorshSchema for $t {
+            fn add_definitions_recursively(definitions: &mut HashMap<Declaration, Definition>) {
+                let definition = Definition::Array {
+                    length: 32,
+                    elements: u8::declaration(),
+                };
+                Self::add_definition(Self::declaration(), definition, definitions);
+            }
+
+            fn declaration() -> Declaration {
+                stringify!($t).into()
+            }
+        }
+    )*};
+}
+
+impl_borsh!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn le(words: [u128; 2]) -> Vec<u8> {
+        let [lo, hi] = words;
+        [lo.to_le_bytes(), hi.to_le_bytes()].concat()
+    }
+
+    #[test]
+    fn fixtures() {
+        let mut one = [0; 32];
+        one[0] = 1;
+        let mut two_pow_128 = [0; 32];
+        two_pow_128[16] = 1;
+        let mut min = [0; 32];
+        min[31] = 0x80;
+        let mut max = [0xff; 32];
+        max[31] = 0x7f;
+
+        for (value, bytes) in [
+            (U256::ZERO, [0; 32]),
+            (U256::ONE, one),
+            (U256::ONE << 128u32, two_pow_128),
+            (U256::MAX, [0xff; 32]),
+        ] {
+            assert_eq!(value.try_to_vec().unwrap(), bytes);
+            assert_eq!(U256::try_from_slice(&bytes).unwrap(), value);
+        }
+
+        for (value, bytes) in [
+            (I256::ZERO, [0; 32]),
+            (I256::ONE, one),
+            (I256::MINUS_ONE, [0xff; 32]),
+            (I256::MIN, min),
+            (I256::MAX, max),
+        ] {
+            assert_eq!(value.try_to_vec().unwrap(), bytes);
+            assert_eq!(I256::try_from_slice(&bytes).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn matches_u128_encoding() {
+        let x = U256::from_words(0x0123456789abcdef_fedcba9876543210, 42);
+        assert_eq!(
+            x.try_to_vec().unwrap(),
+            le([42, 0x0123456789abcdef_fedcba9876543210])
+        );
+        assert_eq!(
+            U256::new(42).try_to_vec().unwrap()[..16],
+            42u128.try_to_vec().unwrap()
+        );
+        assert_eq!(
+            I256::new(-42).try_to_vec().unwrap()[..16],
+            (-42i128).try_to_vec().unwrap()
+        );
+    }
+
+    #[test]
+    fn schema() {
+        use alloc::{string::ToString, vec};
+        use borsh::schema::Fields;
+
+        #[allow(dead_code)]
+        #[derive(BorshSchema)]
+        struct Account {
+            balance: U256,
+            debt: I256,
+        }
+
+        let container = Account::schema_container();
+        assert_eq!(container.declaration, "Account");
+
+        let bytes = Definition::Array {
+            length: 32,
+            elements: "u8".to_string(),
+        };
+        assert_eq!(
+            container.definitions,
+            HashMap::from([
+                (
+                    "Account".to_string(),
+                    Definition::Struct {
+                        fields: Fields::NamedFields(vec![
+                            ("balance".to_string(), "U256".to_string()),
+                            ("debt".to_string(), "I256".to_string()),
+                        ]),
+                    },
+                ),
+                ("U256".to_string(), bytes.clone()),
+                ("I256".to_string(), bytes),
+            ])
+        );
+    }
+
+    #[test]
+    fn rejects_short_input() {
+        assert!(U256::try_from_slice(&[0; 31]).is_err());
+        assert!(I256::try_from_slice(&[0; 33]).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+//! Module that implements support for version 1 of the
+//! [`borsh`](https://crates.io/crates/borsh) crate, enabled with the
+//! `borsh-v1` feature.
+//!
+//! The encoding is the same as for `borsh` version 0.10 (enabled with the
+//! `borsh` or `borsh-v0_10` features): exactly 32 bytes in little endian
+//! order, with `I256` in two's complement. Both features can be enabled at the
+//! same time, and produce identical bytes.
+
+use crate::{I256, U256};
+use borsh_v1::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+macro_rules! impl_borsh {
+    ($($t:ident),*) => {$(
+        impl BorshSerialize for $t {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+        }
+
+        impl BorshDeserialize for $t {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                let mut bytes = [0; 32];
+                reader.read_exact(&mut bytes)?;
+                Ok($t::from_le_bytes(bytes))
+            }
+        }
+    )*};
+}
+
+impl_borsh!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh_v1::{from_slice, to_vec};
+
+    #[test]
+    fn fixtures() {
+        let mut two_pow_128 = [0; 32];
+        two_pow_128[16] = 1;
+        let mut min = [0; 32];
+        min[31] = 0x80;
+
+        for (value, bytes) in [
+            (U256::ZERO, [0; 32]),
+            (U256::ONE << 128u32, two_pow_128),
+            (U256::MAX, [0xff; 32]),
+        ] {
+            assert_eq!(to_vec(&value).unwrap(), bytes);
+            assert_eq!(from_slice::<U256>(&bytes).unwrap(), value);
+        }
+
+        for (value, bytes) in [(I256::MINUS_ONE, [0xff; 32]), (I256::MIN, min)] {
+            assert_eq!(to_vec(&value).unwrap(), bytes);
+            assert_eq!(from_slice::<I256>(&bytes).unwrap(), value);
+        }
+
+        assert!(from_slice::<U256>(&[0; 31]).is_err());
+        assert!(from_slice::<I256>(&[0; 33]).is_err());
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn matches_v0_10() {
+        for value in [
+            U256::ZERO,
+            U256::new(42),
+            U256::from_words(42, 0x0123456789abcdef_fedcba9876543210),
+            U256::MAX,
+        ] {
+            let bytes = to_vec(&value).unwrap();
+            assert_eq!(bytes, borsh::BorshSerialize::try_to_vec(&value).unwrap());
+            assert_eq!(
+                <U256 as borsh::BorshDeserialize>::try_from_slice(&bytes).unwrap(),
+                value
+            );
+        }
+
+        for value in [I256::MIN, I256::new(-42), I256::MAX] {
+            let bytes = to_vec(&value).unwrap();
+            assert_eq!(bytes, borsh::BorshSerialize::try_to_vec(&value).unwrap());
+            assert_eq!(
+                <I256 as borsh::BorshDeserialize>::try_from_slice(&bytes).unwrap(),
+                value
+            );
+        }
+    }
+}
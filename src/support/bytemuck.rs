@@ -0,0 +1,42 @@
+//! Module that implements support for the [`bytemuck`](https://crates.io/crates/bytemuck) crate.
+//!
+//! Note that the in-memory representation of the 256-bit integer types is
+//! native endian, so casting them to bytes does **not** produce a portable
+//! layout. Use `to_le_bytes` or `to_be_bytes` for that instead.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{I256, U256};
+
+// SAFETY: Both types are `#[repr(transparent)]` wrappers around `[u128; 2]`
+// and `[i128; 2]` respectively, which have no padding and for which any bit
+// pattern is valid (including all zeros).
+unsafe impl Zeroable for U256 {}
+unsafe impl Pod for U256 {}
+unsafe impl Zeroable for I256 {}
+unsafe impl Pod for I256 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cast_slice_round_trip() {
+        let values = [U256::ZERO, U256::ONE, U256::MAX, U256::new(42) << 200];
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(bytes.len(), 128);
+        assert_eq!(bytes[32..64], U256::ONE.to_ne_bytes());
+        assert_eq!(bytemuck::cast_slice::<u8, U256>(bytes), values);
+
+        let values = [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX];
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(bytes[32..64], [0xff; 32]);
+        assert_eq!(bytemuck::cast_slice::<u8, I256>(bytes), values);
+    }
+
+    #[test]
+    fn zeroed() {
+        assert_eq!(U256::zeroed(), U256::ZERO);
+        assert_eq!(I256::zeroed(), I256::ZERO);
+    }
+}
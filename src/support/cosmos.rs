@@ -1,9 +1,22 @@
-//! Module with conversion traits for converting between `U256` and vanilla cosmwasm-std types.
+//! Module with conversion traits for converting between `U256` and vanilla
+//! cosmwasm-std types.
+//!
+//! Note that the signed integer types (`Int64`, `Int128` and `Int256`) were
+//! added in upstream `cosmwasm-std` 1.5 and are not available in the
+//! `secret-cosmwasm-std` 1.1 release this module targets. Conversions for
+//! them, and for `SignedDecimal256`, are provided by the `cosmwasm-1_5`
+//! feature instead. [`I256`] can also be converted to and from the unsigned
+//! `Uint256` type with range checks.
 
 #![allow(clippy::from_over_into)]
-use cosmwasm_std::{Decimal256, Uint128, Uint256, Uint64, Decimal, Uint512};
+use alloc::string::ToString;
+use core::fmt;
+use cosmwasm_std::{
+    ConversionOverflowError, Decimal, Decimal256, DivideByZeroError, Fraction, Isqrt,
+    OverflowError, OverflowOperation, StdError, Uint128, Uint256, Uint512, Uint64,
+};
 
-use crate::U256;
+use crate::{cast::sealed::Sealed, CastTarget, I256, U256};
 
 impl From<Uint128> for U256 {
     fn from(u: Uint128) -> Self {
@@ -11,9 +24,17 @@ impl From<Uint128> for U256 {
     }
 }
 
-impl Into<Uint128> for U256 {
-    fn into(self) -> Uint128 {
-        Uint128::new(self.as_u128())
+/// Converts a `U256` to a `Uint128`, returning an error instead of truncating
+/// values that don't fit in 128 bits.
+///
+/// Use [`U256::truncate_to`] for the lossy conversion.
+impl TryFrom<U256> for Uint128 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        u128::try_from(value)
+            .map(Uint128::new)
+            .map_err(|_| ConversionOverflowError::new("U256", "Uint128", value.to_string()))
     }
 }
 
@@ -23,9 +44,131 @@ impl From<Uint64> for U256 {
     }
 }
 
-impl Into<Uint64> for U256 {
-    fn into(self) -> Uint64 {
-        Uint64::new(self.as_u64())
+/// Converts a `U256` to a `Uint64`, returning an error instead of truncating
+/// values that don't fit in 64 bits.
+impl TryFrom<U256> for Uint64 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        u64::try_from(value)
+            .map(Uint64::new)
+            .map_err(|_| ConversionOverflowError::new("U256", "Uint64", value.to_string()))
+    }
+}
+
+impl U256 {
+    /// Converts to a `Uint128`, returning an error if the value doesn't fit
+    /// in 128 bits.
+    ///
+    /// This is the same as the `TryFrom<U256>` implementation for `Uint128`.
+    /// Use [`U256::truncate_to`] if the truncating behaviour is desired.
+    pub fn to_uint128_checked(self) -> Result<Uint128, ConversionOverflowError> {
+        self.try_into()
+    }
+
+    /// Converts a `Uint512` to a `U256`, keeping only the lower 256 bits.
+    ///
+    /// Use the `TryFrom<Uint512>` implementation to detect values that don't
+    /// fit in 256 bits instead.
+    pub fn from_uint512_truncating(u: Uint512) -> Self {
+        let bytes = u.to_be_bytes();
+        U256::from_be_slice(&bytes[32..]).unwrap()
+    }
+
+    /// Returns the raw atomics of a `Decimal256`, i.e. the decimal value
+    /// multiplied by 10^18.
+    pub fn from_decimal256_atomics(d: Decimal256) -> Self {
+        U256::from_be_bytes(d.atomics().to_be_bytes())
+    }
+
+    /// Creates a `Decimal256` with the value as its raw atomics, i.e. the
+    /// resulting decimal is the value divided by 10^18.
+    pub fn to_decimal256_atomics(self) -> Decimal256 {
+        Decimal256::new(Uint256::from_be_bytes(self.to_be_bytes()))
+    }
+
+    /// Returns the integer part of a `Decimal256`, rounding down.
+    ///
+    /// This matches `Decimal256::to_uint_floor` from newer `cosmwasm-std`
+    /// releases.
+    pub fn from_decimal256_floor(d: Decimal256) -> Self {
+        U256::from_decimal256_atomics(d) / DECIMAL_FRACTIONAL
+    }
+
+    /// Returns the integer part of a `Decimal256`, rounding up.
+    ///
+    /// This matches `Decimal256::to_uint_ceil` from newer `cosmwasm-std`
+    /// releases.
+    pub fn from_decimal256_ceil(d: Decimal256) -> Self {
+        ceil_atomics(U256::from_decimal256_atomics(d))
+    }
+
+    /// Converts the integer value to a `Decimal256`, returning an error if
+    /// it exceeds the largest integer a `Decimal256` can represent.
+    pub fn to_decimal256_checked(self) -> Result<Decimal256, ConversionOverflowError> {
+        self.checked_mul(DECIMAL_FRACTIONAL)
+            .map(U256::to_decimal256_atomics)
+            .ok_or_else(|| ConversionOverflowError::new("U256", "Decimal256", self.to_string()))
+    }
+
+    /// Returns the raw atomics of a `Decimal`, i.e. the decimal value
+    /// multiplied by 10^18.
+    pub fn from_decimal_atomics(d: Decimal) -> Self {
+        U256::new(d.atomics().u128())
+    }
+
+    /// Creates a `Decimal` with the value as its raw atomics, returning an
+    /// error if the value doesn't fit in 128 bits.
+    ///
+    /// This is the same as the `TryFrom<U256>` implementation for `Decimal`.
+    pub fn to_decimal_atomics_checked(self) -> Result<Decimal, ConversionOverflowError> {
+        self.try_into()
+    }
+
+    /// Creates a `Decimal` with the lower 128 bits of the value as its raw
+    /// atomics.
+    ///
+    /// Use [`U256::to_decimal_atomics_checked`] to detect values that don't
+    /// fit instead.
+    pub fn to_decimal_atomics_truncating(self) -> Decimal {
+        Decimal::new(Uint128::new(self.as_u128()))
+    }
+
+    /// Returns the integer part of a `Decimal`, rounding down.
+    ///
+    /// This matches `Decimal::to_uint_floor` from newer `cosmwasm-std`
+    /// releases.
+    pub fn from_decimal_floor(d: Decimal) -> Self {
+        U256::from_decimal_atomics(d) / DECIMAL_FRACTIONAL
+    }
+
+    /// Returns the integer part of a `Decimal`, rounding up.
+    ///
+    /// This matches `Decimal::to_uint_ceil` from newer `cosmwasm-std`
+    /// releases.
+    pub fn from_decimal_ceil(d: Decimal) -> Self {
+        ceil_atomics(U256::from_decimal_atomics(d))
+    }
+
+    /// Converts the integer value to a `Decimal`, returning an error if it
+    /// exceeds the largest integer a `Decimal` can represent.
+    pub fn to_decimal_checked(self) -> Result<Decimal, ConversionOverflowError> {
+        self.checked_mul(DECIMAL_FRACTIONAL)
+            .and_then(|atomics| atomics.to_decimal_atomics_checked().ok())
+            .ok_or_else(|| ConversionOverflowError::new("U256", "Decimal", self.to_string()))
+    }
+}
+
+/// The number of atomics in one unit of a `Decimal` or `Decimal256`.
+const DECIMAL_FRACTIONAL: U256 = U256::new(1_000_000_000_000_000_000);
+
+/// Divides decimal atomics by [`DECIMAL_FRACTIONAL`], rounding up.
+fn ceil_atomics(atomics: U256) -> U256 {
+    let (q, r) = (atomics / DECIMAL_FRACTIONAL, atomics % DECIMAL_FRACTIONAL);
+    if r == 0 {
+        q
+    } else {
+        q + 1
     }
 }
 
@@ -41,27 +184,77 @@ impl From<Uint256> for U256 {
     }
 }
 
+/// Converts an `I256` to a `Uint256`, returning an error for negative values.
+impl TryFrom<I256> for Uint256 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: I256) -> Result<Self, Self::Error> {
+        value
+            .try_into_unsigned()
+            .map(|u| Uint256::from_be_bytes(u.to_be_bytes()))
+            .map_err(|_| ConversionOverflowError::new("I256", "Uint256", value.to_string()))
+    }
+}
+
+/// Converts a `Uint256` to an `I256`, returning an error for values larger
+/// than [`I256::MAX`].
+impl TryFrom<Uint256> for I256 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(u: Uint256) -> Result<Self, Self::Error> {
+        U256::from(u)
+            .try_into_signed()
+            .map_err(|_| ConversionOverflowError::new("Uint256", "I256", u.to_string()))
+    }
+}
+
+/// Converts a `U256` to a `Decimal256` by treating it as the raw atomics
+/// (i.e. the decimal value multiplied by 10^18).
+///
+/// This is the same as [`U256::to_decimal256_atomics`]; use
+/// [`U256::to_decimal256_checked`] to convert the integer value instead.
 impl Into<Decimal256> for U256 {
     fn into(self) -> Decimal256 {
         Decimal256::new(Uint256::from_be_bytes(self.to_be_bytes()))
     }
 }
 
+/// Converts a `Decimal256` to a `U256` by taking its raw atomics (i.e. the
+/// decimal value multiplied by 10^18).
+///
+/// This is the same as [`U256::from_decimal256_atomics`]; use
+/// [`U256::from_decimal256_floor`] or [`U256::from_decimal256_ceil`] to get
+/// the integer part instead.
 impl From<Decimal256> for U256 {
     fn from(u: Decimal256) -> Self {
         U256::from_be_bytes(u.atomics().to_be_bytes())
     }
 }
 
-impl Into<Decimal> for U256 {
-    fn into(self) -> Decimal {
-        Decimal::new(Uint128::new(self.as_u128()))
+/// Converts a `U256` to a `Decimal` by treating it as the raw atomics (i.e.
+/// the decimal value multiplied by 10^18), returning an error instead of
+/// truncating values that don't fit in 128 bits.
+///
+/// Use [`U256::to_decimal_checked`] to convert the integer value instead.
+impl TryFrom<U256> for Decimal {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        u128::try_from(value)
+            .map(|atomics| Decimal::new(Uint128::new(atomics)))
+            .map_err(|_| ConversionOverflowError::new("U256", "Decimal", value.to_string()))
     }
 }
 
+/// Converts a `Decimal` to a `U256` by taking its raw atomics (i.e. the
+/// decimal value multiplied by 10^18).
+///
+/// This is the same as [`U256::from_decimal_atomics`]; use
+/// [`U256::from_decimal_floor`] or [`U256::from_decimal_ceil`] to get the
+/// integer part instead.
 impl From<Decimal> for U256 {
     fn from(u: Decimal) -> Self {
-        U256::from(u.atomics())
+        U256::from_decimal_atomics(u)
     }
 }
 
@@ -71,9 +264,994 @@ impl Into<Uint512> for U256 {
     }
 }
 
-impl From<Uint512> for U256 {
-    fn from(u: Uint512) -> Self {
-        let u: Uint256 = u.try_into().unwrap();
-        U256::from(u)
+/// Converts a `Uint512` to a `U256`, returning an error for values that
+/// don't fit in 256 bits.
+///
+/// Use [`U256::from_uint512_truncating`] for the lossy conversion.
+impl TryFrom<Uint512> for U256 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(u: Uint512) -> Result<Self, Self::Error> {
+        let bytes = u.to_be_bytes();
+        let (hi, lo) = bytes.split_at(32);
+        if hi.iter().any(|&b| b != 0) {
+            return Err(ConversionOverflowError::new(
+                "Uint512",
+                "U256",
+                u.to_string(),
+            ));
+        }
+        Ok(U256::from_be_slice(lo).unwrap())
+    }
+}
+
+/// A ratio of two [`U256`] values that implements `cosmwasm_std::Fraction`.
+///
+/// This allows ethnum-based prices and rates to be passed to cosmwasm
+/// functions that accept a `Fraction<U256>`. The denominator is expected to
+/// be non-zero.
+///
+/// # Examples
+///
+/// ```
+/// # use cosmwasm_std::{Fraction, Uint256};
+/// # use ethnum::{Ratio256, U256};
+/// let price = Ratio256::new(U256::new(3), U256::new(8));
+/// let amount = Uint256::from(1000u32);
+/// assert_eq!(
+///     amount.multiply_ratio(price.numerator(), price.denominator()),
+///     Uint256::from(375u32),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Ratio256 {
+    /// The numerator of the ratio.
+    pub numerator: U256,
+    /// The denominator of the ratio.
+    pub denominator: U256,
+}
+
+impl Ratio256 {
+    /// Creates a new ratio from a numerator and a denominator.
+    pub const fn new(numerator: U256, denominator: U256) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Multiplies two ratios, returning `None` if either the numerator or the
+    /// denominator of the product overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.numerator.checked_mul(rhs.numerator)?,
+            self.denominator.checked_mul(rhs.denominator)?,
+        ))
+    }
+
+    /// Adds two ratios by cross-multiplication, returning `None` if any of
+    /// the intermediate products or the sum overflows.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let lhs_numerator = self.numerator.checked_mul(rhs.denominator)?;
+        let rhs_numerator = rhs.numerator.checked_mul(self.denominator)?;
+        Some(Self::new(
+            lhs_numerator.checked_add(rhs_numerator)?,
+            self.denominator.checked_mul(rhs.denominator)?,
+        ))
+    }
+}
+
+impl Fraction<U256> for Ratio256 {
+    fn numerator(&self) -> U256 {
+        self.numerator
+    }
+
+    fn denominator(&self) -> U256 {
+        self.denominator
+    }
+
+    fn inv(&self) -> Option<Self> {
+        if self.numerator == 0 {
+            None
+        } else {
+            Some(Self::new(self.denominator, self.numerator))
+        }
+    }
+}
+
+/// Converts a `Decimal256` to the equivalent ratio of its atomics over 10^18.
+impl From<Decimal256> for Ratio256 {
+    fn from(d: Decimal256) -> Self {
+        Self::new(U256::from_decimal256_atomics(d), DECIMAL_FRACTIONAL)
+    }
+}
+
+/// Allows generic code written against `cosmwasm_std::Isqrt` to accept
+/// `U256` values. This delegates to [`U256::isqrt`].
+impl Isqrt for U256 {
+    fn isqrt(self) -> Self {
+        U256::isqrt(self)
+    }
+}
+
+/// Generates checked arithmetic methods returning `cosmwasm_std` errors.
+macro_rules! impl_cw_checked_ops {
+    ($int:ident, $div_err:ty, $div:expr) => {
+        impl $int {
+            impl_cw_checked_ops! {
+                @overflow $int;
+                /// Checked integer addition, returning a `cosmwasm_std::OverflowError`
+                /// if an overflow occurred.
+                checked_add_cw(rhs: Self) => checked_add, Add;
+                /// Checked integer subtraction, returning a
+                /// `cosmwasm_std::OverflowError` if an overflow occurred.
+                checked_sub_cw(rhs: Self) => checked_sub, Sub;
+                /// Checked integer multiplication, returning a
+                /// `cosmwasm_std::OverflowError` if an overflow occurred.
+                checked_mul_cw(rhs: Self) => checked_mul, Mul;
+                /// Checked exponentiation, returning a `cosmwasm_std::OverflowError`
+                /// if an overflow occurred.
+                checked_pow_cw(exp: u32) => checked_pow, Pow;
+                /// Checked shift left, returning a `cosmwasm_std::OverflowError` if
+                /// `rhs` is larger than or equal to the number of bits.
+                checked_shl_cw(rhs: u32) => checked_shl, Shl;
+                /// Checked shift right, returning a `cosmwasm_std::OverflowError` if
+                /// `rhs` is larger than or equal to the number of bits.
+                checked_shr_cw(rhs: u32) => checked_shr, Shr;
+            }
+
+            /// Checked integer division, returning an error if `rhs` is zero or
+            /// the division overflowed.
+            pub fn checked_div_cw(self, rhs: Self) -> Result<Self, $div_err> {
+                self.checked_div(rhs).ok_or_else(|| $div(self, rhs))
+            }
+
+            /// Checked integer remainder, returning an error if `rhs` is zero or
+            /// the division overflowed.
+            pub fn checked_rem_cw(self, rhs: Self) -> Result<Self, $div_err> {
+                self.checked_rem(rhs).ok_or_else(|| $div(self, rhs))
+            }
+        }
+    };
+
+    (@overflow $int:ident; $(
+        $(#[$attr:meta])*
+        $name:ident($rhs:ident: $rhs_ty:ty) => $checked:ident, $op:ident;
+    )*) => {$(
+        $(#[$attr])*
+        pub fn $name(self, $rhs: $rhs_ty) -> Result<Self, OverflowError> {
+            self.$checked($rhs)
+                .ok_or_else(|| OverflowError::new(OverflowOperation::$op, self, $rhs))
+        }
+    )*};
+}
+
+impl_cw_checked_ops!(U256, DivideByZeroError, |lhs, _| {
+    DivideByZeroError::new(lhs)
+});
+impl_cw_checked_ops!(I256, DivisionError, |_, rhs| {
+    if rhs == 0 {
+        DivisionError::DivideByZero
+    } else {
+        DivisionError::Overflow
     }
-}
\ No newline at end of file
+});
+
+/// The error returned by the checked [`I256`] division methods returning
+/// `cosmwasm_std` errors, such as [`I256::checked_div_cw`].
+///
+/// This mirrors the `DivisionError` type from newer `cosmwasm-std` releases,
+/// which is not available in `secret-cosmwasm-std`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DivisionError {
+    /// The divisor was zero.
+    DivideByZero,
+    /// The division overflowed, i.e. [`I256::MIN`] was divided by `-1`.
+    Overflow,
+}
+
+impl fmt::Display for DivisionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DivisionError::DivideByZero => f.write_str("Divide by zero"),
+            DivisionError::Overflow => f.write_str("Overflow in division"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DivisionError {}
+
+impl From<DivisionError> for StdError {
+    fn from(err: DivisionError) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
+
+/// Generates multiplication and division methods by fractions that round in
+/// an explicit direction, matching the ones on `Uint256` from newer
+/// `cosmwasm-std` releases.
+macro_rules! impl_mul_fraction {
+    ($(
+        $(#[$doc:meta])*
+        $name:ident => $checked:ident;
+    )*) => {$(
+        $(#[$doc])*
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!(
+            "This panics on division by zero or overflow. Use [`U256::",
+            stringify!($checked),
+            "`] for a non-panicking version.",
+        )]
+        pub fn $name<F: Fraction<T>, T: Into<U256>>(self, rhs: F) -> Self {
+            self.$checked(rhs).unwrap()
+        }
+    )*};
+}
+
+impl U256 {
+    impl_mul_fraction! {
+        /// Multiplies the value by a fraction, such as a `Decimal256` or a
+        /// [`Ratio256`], rounding down.
+        mul_floor => checked_mul_floor;
+        /// Multiplies the value by a fraction, such as a `Decimal256` or a
+        /// [`Ratio256`], rounding up.
+        mul_ceil => checked_mul_ceil;
+        /// Divides the value by a fraction, such as a `Decimal256` or a
+        /// [`Ratio256`], rounding down.
+        ///
+        /// This is the equivalent of `Uint256::div_floor` from newer
+        /// `cosmwasm-std` releases. It is named differently so that it doesn't
+        /// shadow `num_integer::Integer::div_floor` when the `num-integer`
+        /// feature is enabled.
+        #[doc(alias = "div_floor")]
+        div_fraction_floor => checked_div_fraction_floor;
+        /// Divides the value by a fraction, such as a `Decimal256` or a
+        /// [`Ratio256`], rounding up.
+        ///
+        /// This is the equivalent of `Uint256::div_ceil` from newer
+        /// `cosmwasm-std` releases. It is named differently so that it doesn't
+        /// shadow `num_integer::Integer::div_ceil` when the `num-integer`
+        /// feature is enabled.
+        #[doc(alias = "div_ceil")]
+        div_fraction_ceil => checked_div_fraction_ceil;
+    }
+
+    /// Multiplies the value by a fraction, rounding down, and returning an
+    /// error on division by zero or overflow.
+    ///
+    /// The product is computed with a 512-bit intermediate value, so this only
+    /// overflows if the final result doesn't fit in 256 bits.
+    pub fn checked_mul_floor<F: Fraction<T>, T: Into<U256>>(
+        self,
+        rhs: F,
+    ) -> Result<Self, CheckedMultiplyFractionError> {
+        let divisor = Uint512::from_uint256(rhs.denominator().into().into());
+        let res = full_mul(self, rhs.numerator().into()).checked_div(divisor)?;
+        Ok(res.try_into()?)
+    }
+
+    /// Multiplies the value by a fraction, rounding up, and returning an
+    /// error on division by zero or overflow.
+    ///
+    /// The product is computed with a 512-bit intermediate value, so this only
+    /// overflows if the final result doesn't fit in 256 bits.
+    pub fn checked_mul_ceil<F: Fraction<T>, T: Into<U256>>(
+        self,
+        rhs: F,
+    ) -> Result<Self, CheckedMultiplyFractionError> {
+        let dividend = full_mul(self, rhs.numerator().into());
+        let divisor = Uint512::from_uint256(rhs.denominator().into().into());
+        let floor_result = U256::try_from(dividend.checked_div(divisor)?)?;
+        let remainder = dividend.checked_rem(divisor)?;
+        if !remainder.is_zero() {
+            Ok(U256::ONE.checked_add_cw(floor_result)?)
+        } else {
+            Ok(floor_result)
+        }
+    }
+
+    /// Divides the value by a fraction, rounding down, and returning an
+    /// error on division by zero or overflow.
+    ///
+    /// The value is multiplied by the denominator of the fraction with a
+    /// 512-bit intermediate value before dividing by its numerator, so the
+    /// result is exact, as with `Uint256::checked_div_floor` from newer
+    /// `cosmwasm-std` releases. It is named differently for consistency with
+    /// [`U256::div_fraction_floor`].
+    #[doc(alias = "checked_div_floor")]
+    pub fn checked_div_fraction_floor<F: Fraction<T>, T: Into<U256>>(
+        self,
+        rhs: F,
+    ) -> Result<Self, CheckedMultiplyFractionError> {
+        let divisor = rhs.numerator().into();
+        if divisor == U256::ZERO {
+            return Err(DivideByZeroError::new(self).into());
+        }
+        let divisor = Uint512::from_uint256(divisor.into());
+        let res = full_mul(self, rhs.denominator().into()).checked_div(divisor)?;
+        Ok(res.try_into()?)
+    }
+
+    /// Divides the value by a fraction, rounding up, and returning an error
+    /// on division by zero or overflow.
+    ///
+    /// The value is multiplied by the denominator of the fraction with a
+    /// 512-bit intermediate value before dividing by its numerator, so the
+    /// result is exact, as with `Uint256::checked_div_ceil` from newer
+    /// `cosmwasm-std` releases. It is named differently for consistency with
+    /// [`U256::div_fraction_ceil`].
+    #[doc(alias = "checked_div_ceil")]
+    pub fn checked_div_fraction_ceil<F: Fraction<T>, T: Into<U256>>(
+        self,
+        rhs: F,
+    ) -> Result<Self, CheckedMultiplyFractionError> {
+        let divisor = rhs.numerator().into();
+        if divisor == U256::ZERO {
+            return Err(DivideByZeroError::new(self).into());
+        }
+        let dividend = full_mul(self, rhs.denominator().into());
+        let divisor = Uint512::from_uint256(divisor.into());
+        let floor_result = U256::try_from(dividend.checked_div(divisor)?)?;
+        let remainder = dividend.checked_rem(divisor)?;
+        if !remainder.is_zero() {
+            Ok(U256::ONE.checked_add_cw(floor_result)?)
+        } else {
+            Ok(floor_result)
+        }
+    }
+}
+
+/// Computes the full 512-bit product of two values.
+fn full_mul(lhs: U256, rhs: U256) -> Uint512 {
+    Uint256::from_be_bytes(lhs.to_be_bytes()).full_mul(rhs)
+}
+
+/// The error returned by the checked fraction multiplication and division
+/// methods, such as [`U256::checked_mul_floor`].
+///
+/// This mirrors the `CheckedMultiplyFractionError` type from newer
+/// `cosmwasm-std` releases, which is not available in `secret-cosmwasm-std`.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CheckedMultiplyFractionError {
+    /// The denominator of the fraction (or the numerator when dividing) was
+    /// zero.
+    DivideByZero(DivideByZeroError),
+    /// The result didn't fit in 256 bits.
+    ConversionOverflow(ConversionOverflowError),
+    /// Rounding the result up overflowed.
+    Overflow(OverflowError),
+}
+
+impl fmt::Display for CheckedMultiplyFractionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckedMultiplyFractionError::DivideByZero(err) => fmt::Display::fmt(err, f),
+            CheckedMultiplyFractionError::ConversionOverflow(err) => fmt::Display::fmt(err, f),
+            CheckedMultiplyFractionError::Overflow(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CheckedMultiplyFractionError {}
+
+impl From<DivideByZeroError> for CheckedMultiplyFractionError {
+    fn from(err: DivideByZeroError) -> Self {
+        CheckedMultiplyFractionError::DivideByZero(err)
+    }
+}
+
+impl From<ConversionOverflowError> for CheckedMultiplyFractionError {
+    fn from(err: ConversionOverflowError) -> Self {
+        CheckedMultiplyFractionError::ConversionOverflow(err)
+    }
+}
+
+impl From<OverflowError> for CheckedMultiplyFractionError {
+    fn from(err: OverflowError) -> Self {
+        CheckedMultiplyFractionError::Overflow(err)
+    }
+}
+
+impl From<CheckedMultiplyFractionError> for StdError {
+    fn from(err: CheckedMultiplyFractionError) -> Self {
+        match err {
+            CheckedMultiplyFractionError::DivideByZero(err) => StdError::divide_by_zero(err),
+            CheckedMultiplyFractionError::ConversionOverflow(err) => {
+                StdError::generic_err(err.to_string())
+            }
+            CheckedMultiplyFractionError::Overflow(err) => StdError::overflow(err),
+        }
+    }
+}
+
+/// Generates arithmetic operators for `U256` with `cosmwasm_std` integer
+/// right-hand sides. The right-hand side is converted losslessly, so these
+/// behave exactly like the corresponding `U256` operators.
+macro_rules! impl_cw_ops {
+    ($($cw:ident),*) => {$(
+        impl_cw_ops! {
+            @ops $cw:
+            Add add +, AddAssign add_assign +=;
+            Sub sub -, SubAssign sub_assign -=;
+            Mul mul *, MulAssign mul_assign *=;
+            Div div /, DivAssign div_assign /=;
+            Rem rem %, RemAssign rem_assign %=;
+        }
+    )*};
+
+    (@ops $cw:ident: $($op:ident $method:ident $x:tt, $opa:ident $methoda:ident $xa:tt;)*) => {$(
+        __impl_ops_binop_ref! {
+            impl $op for U256 {
+                $method(a: &'_ U256, b:     $cw) { a $x U256::from(b) };
+                $method(a: &'_ U256, b: &'_ $cw) { a $x U256::from(*b) };
+                $method(a:     U256, b:     $cw) { a $x U256::from(b) };
+                $method(a:     U256, b: &'_ $cw) { a $x U256::from(*b) };
+            }
+        }
+
+        __impl_ops_binop_assign_ref! {
+            impl $opa for U256 {
+                $methoda(a, b:     $cw) { *a $xa U256::from(b) };
+                $methoda(a, b: &'_ $cw) { *a $xa U256::from(*b) };
+            }
+        }
+    )*};
+}
+
+impl_cw_ops!(Uint64, Uint128, Uint256);
+
+impl Sealed for Uint128 {}
+
+impl CastTarget for Uint128 {
+    fn saturating_from_u256(value: U256) -> Self {
+        Uint128::new(value.saturating_to())
+    }
+
+    fn saturating_from_i256(value: I256) -> Self {
+        Uint128::new(value.saturating_to())
+    }
+
+    fn truncating_from_u256(value: U256) -> Self {
+        Uint128::new(value.truncate_to())
+    }
+
+    fn truncating_from_i256(value: I256) -> Self {
+        Uint128::new(value.truncate_to())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn signed_uint256() {
+        let max = Uint256::from_be_bytes(I256::MAX.to_be_bytes());
+        assert_eq!(I256::try_from(max), Ok(I256::MAX));
+        assert_eq!(Uint256::try_from(I256::MAX), Ok(max));
+        assert_eq!(Uint256::try_from(I256::ZERO), Ok(Uint256::zero()));
+        assert!(I256::try_from(max + Uint256::one()).is_err());
+        assert!(I256::try_from(Uint256::MAX).is_err());
+        assert_eq!(
+            Uint256::try_from(I256::MINUS_ONE),
+            Err(ConversionOverflowError::new("I256", "Uint256", "-1"))
+        );
+        assert!(Uint256::try_from(I256::MIN).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn signed_uint256_round_trip(bytes in any::<[u8; 32]>()) {
+            let x = I256::from_be_bytes(bytes);
+            match Uint256::try_from(x) {
+                Ok(u) => {
+                    prop_assert!(!x.is_negative());
+                    prop_assert_eq!(u.to_be_bytes(), bytes);
+                    prop_assert_eq!(I256::try_from(u), Ok(x));
+                }
+                Err(_) => prop_assert!(x.is_negative()),
+            }
+
+            let u = Uint256::from_be_bytes(bytes);
+            prop_assert_eq!(I256::try_from(u).is_ok(), bytes[0] < 0x80);
+        }
+    }
+
+    #[test]
+    fn from_uint512() {
+        let max = Uint512::from(Uint256::MAX);
+        assert_eq!(U256::try_from(max), Ok(U256::MAX));
+        assert_eq!(U256::try_from(Uint512::zero()), Ok(U256::ZERO));
+        assert_eq!(
+            U256::try_from(Uint512::MAX),
+            Err(ConversionOverflowError::new(
+                "Uint512",
+                "U256",
+                Uint512::MAX.to_string()
+            ))
+        );
+        assert!(U256::try_from(max + Uint512::one()).is_err());
+
+        assert_eq!(U256::from_uint512_truncating(Uint512::MAX), U256::MAX);
+        assert_eq!(
+            U256::from_uint512_truncating(max + Uint512::from(2u8)),
+            U256::ONE
+        );
+
+        let x = U256::from_words(42, 1337);
+        let y: Uint512 = x.into();
+        assert_eq!(U256::try_from(y), Ok(x));
+    }
+
+    #[test]
+    fn checked_narrowing() {
+        let max = U256::new(u128::MAX);
+        assert_eq!(Uint128::try_from(max), Ok(Uint128::MAX));
+        assert_eq!(max.to_uint128_checked(), Ok(Uint128::MAX));
+
+        let err = (max + 1).to_uint128_checked().unwrap_err();
+        assert_eq!(
+            err,
+            ConversionOverflowError::new("U256", "Uint128", (max + 1).to_string())
+        );
+        assert!(Uint128::try_from(U256::MAX).is_err());
+
+        assert_eq!(Uint64::try_from(U256::from(u64::MAX)), Ok(Uint64::MAX));
+        assert!(Uint64::try_from(U256::from(u64::MAX) + 1).is_err());
+        assert!(Uint64::try_from(U256::MAX).is_err());
+    }
+
+    #[test]
+    fn decimal256_rounding() {
+        let d = Decimal256::from_atomics(U256::new(25), 1).unwrap();
+        assert_eq!(d.to_string(), "2.5");
+        assert_eq!(U256::from_decimal256_floor(d), U256::new(2));
+        assert_eq!(U256::from_decimal256_ceil(d), U256::new(3));
+        assert_eq!(
+            U256::from_decimal256_atomics(d),
+            U256::new(2_500_000_000_000_000_000)
+        );
+
+        let whole = Decimal256::from_atomics(U256::new(7), 0).unwrap();
+        assert_eq!(U256::from_decimal256_floor(whole), U256::new(7));
+        assert_eq!(U256::from_decimal256_ceil(whole), U256::new(7));
+
+        assert_eq!(
+            U256::from_decimal256_floor(Decimal256::MAX),
+            U256::MAX / DECIMAL_FRACTIONAL
+        );
+        assert_eq!(
+            U256::from_decimal256_ceil(Decimal256::MAX),
+            U256::MAX / DECIMAL_FRACTIONAL + 1
+        );
+    }
+
+    #[test]
+    fn decimal256_checked() {
+        assert_eq!(
+            U256::new(7).to_decimal256_checked(),
+            Ok(Decimal256::percent(700))
+        );
+        assert_eq!(
+            U256::new(7).to_decimal256_atomics().to_string(),
+            "0.000000000000000007"
+        );
+
+        let max = U256::MAX / DECIMAL_FRACTIONAL;
+        assert_eq!(
+            U256::from_decimal256_floor(max.to_decimal256_checked().unwrap()),
+            max
+        );
+        assert_eq!(
+            (max + 1).to_decimal256_checked(),
+            Err(ConversionOverflowError::new(
+                "U256",
+                "Decimal256",
+                (max + 1).to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn ratio_fraction() {
+        fn mul_fraction(x: Uint128, f: &impl Fraction<U256>) -> Uint256 {
+            Uint256::from(x).multiply_ratio(f.numerator(), f.denominator())
+        }
+
+        let half = Ratio256::new(U256::new(1), U256::new(2));
+        let three_quarters = Ratio256::new(U256::new(3), U256::new(4));
+        let price = half.checked_mul(three_quarters).unwrap();
+        assert_eq!(price, Ratio256::new(U256::new(3), U256::new(8)));
+
+        let amount = Uint128::new(1_000_000_007);
+        let decimal = Decimal256::from_ratio(3u8, 8u8);
+        assert_eq!(
+            mul_fraction(amount, &price),
+            Uint256::from(amount) * decimal
+        );
+        assert_eq!(
+            mul_fraction(amount, &Ratio256::from(decimal)),
+            Uint256::from(amount) * decimal
+        );
+
+        let sum = Ratio256::new(U256::new(1), U256::new(4))
+            .checked_add(Ratio256::new(U256::new(1), U256::new(8)))
+            .unwrap();
+        assert_eq!(sum, Ratio256::new(U256::new(12), U256::new(32)));
+        assert_eq!(mul_fraction(amount, &sum), mul_fraction(amount, &price));
+
+        assert_eq!(price.inv(), Some(Ratio256::new(U256::new(8), U256::new(3))));
+        assert_eq!(Ratio256::new(U256::ZERO, U256::ONE).inv(), None);
+
+        let big = Ratio256::new(U256::MAX, U256::ONE);
+        assert_eq!(
+            big.checked_mul(Ratio256::new(U256::new(2), U256::ONE)),
+            None
+        );
+        assert_eq!(
+            big.checked_mul(Ratio256::new(U256::ONE, U256::MAX)),
+            Some(Ratio256::new(U256::MAX, U256::MAX))
+        );
+        assert_eq!(big.checked_add(half), None);
+        assert_eq!(half.checked_add(Ratio256::new(U256::ONE, U256::MAX)), None);
+    }
+
+    #[test]
+    fn isqrt() {
+        fn lp_shares<T: Isqrt + core::ops::Mul<Output = T>>(a: T, b: T) -> T {
+            (a * b).isqrt()
+        }
+
+        assert_eq!(lp_shares(U256::new(4), U256::new(9)), U256::new(6));
+        assert_eq!(lp_shares(U256::new(1000), U256::new(10)), U256::new(100));
+        assert_eq!(lp_shares(U256::new(3), U256::new(5)), U256::new(3));
+        assert_eq!(
+            lp_shares(U256::new(u128::MAX), U256::new(u128::MAX)),
+            U256::new(u128::MAX)
+        );
+
+        let (a, b) = (Uint128::new(123_456_789), Uint128::new(987_654_321));
+        assert_eq!(
+            lp_shares(U256::from(a), U256::from(b)),
+            U256::from(lp_shares(Uint256::from(a), Uint256::from(b)))
+        );
+    }
+
+    #[test]
+    fn checked_ops_cw() {
+        use alloc::format;
+
+        assert_eq!(U256::ONE.checked_add_cw(U256::ONE), Ok(U256::new(2)));
+        assert_eq!(
+            U256::MAX.checked_add_cw(U256::ONE).unwrap_err().to_string(),
+            format!("Cannot Add with {} and 1", U256::MAX)
+        );
+        assert_eq!(
+            U256::ZERO.checked_sub_cw(U256::ONE),
+            Err(OverflowError::new(OverflowOperation::Sub, 0, 1))
+        );
+        assert_eq!(
+            U256::MAX.checked_mul_cw(U256::new(2)),
+            Err(OverflowError::new(OverflowOperation::Mul, U256::MAX, 2))
+        );
+        assert_eq!(
+            U256::new(2).checked_pow_cw(256).unwrap_err().to_string(),
+            "Cannot Pow with 2 and 256"
+        );
+        assert_eq!(U256::ONE.checked_shl_cw(255), Ok(U256::ONE << 255));
+        assert_eq!(
+            U256::ONE.checked_shr_cw(256).unwrap_err().to_string(),
+            "Cannot Shr with 1 and 256"
+        );
+        assert_eq!(U256::new(7).checked_div_cw(U256::new(2)), Ok(U256::new(3)));
+        assert_eq!(U256::new(7).checked_rem_cw(U256::new(2)), Ok(U256::ONE));
+        assert_eq!(
+            U256::new(7)
+                .checked_div_cw(U256::ZERO)
+                .unwrap_err()
+                .to_string(),
+            "Cannot devide 7 by zero"
+        );
+
+        // The error messages match the ones for the native `cosmwasm_std`
+        // types.
+        let native = Uint256::MAX.checked_add(Uint256::one()).unwrap_err();
+        let ethnum = U256::MAX.checked_add_cw(U256::ONE).unwrap_err();
+        assert_eq!(ethnum, native);
+        let native = Uint256::one().checked_rem(Uint256::zero()).unwrap_err();
+        let ethnum = U256::ONE.checked_rem_cw(U256::ZERO).unwrap_err();
+        assert_eq!(ethnum, native);
+
+        assert_eq!(
+            I256::MIN.checked_sub_cw(I256::ONE).unwrap_err().to_string(),
+            format!("Cannot Sub with {} and 1", I256::MIN)
+        );
+        assert_eq!(
+            I256::new(-7).checked_div_cw(I256::new(2)),
+            Ok(I256::new(-3))
+        );
+        assert_eq!(
+            I256::new(-7).checked_rem_cw(I256::ZERO),
+            Err(DivisionError::DivideByZero)
+        );
+        let minus_one = I256::MINUS_ONE;
+        assert_eq!(
+            I256::MIN.checked_div_cw(minus_one),
+            Err(DivisionError::Overflow)
+        );
+        assert_eq!(
+            StdError::from(DivisionError::Overflow),
+            StdError::generic_err("Overflow in division")
+        );
+
+        fn sum(values: &[U256]) -> Result<U256, StdError> {
+            let mut total = U256::ZERO;
+            for value in values {
+                total = total.checked_add_cw(*value)?;
+            }
+            Ok(total)
+        }
+        assert_eq!(sum(&[U256::ONE, U256::new(2)]), Ok(U256::new(3)));
+        assert_eq!(
+            sum(&[U256::MAX, U256::ONE]),
+            Err(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+                U256::MAX,
+                1
+            )))
+        );
+    }
+
+    #[test]
+    fn decimal() {
+        let max = U256::new(u128::MAX);
+        assert_eq!(Decimal::try_from(max), Ok(Decimal::MAX));
+        assert_eq!(max.to_decimal_atomics_checked(), Ok(Decimal::MAX));
+        assert_eq!(U256::from(Decimal::MAX), max);
+        assert_eq!(
+            Decimal::try_from(max + 1),
+            Err(ConversionOverflowError::new(
+                "U256",
+                "Decimal",
+                (max + 1).to_string()
+            ))
+        );
+        assert_eq!(
+            (max + 2).to_decimal_atomics_truncating(),
+            Decimal::new(Uint128::new(1))
+        );
+
+        let d = Decimal::from_atomics(25u8, 1).unwrap();
+        assert_eq!(U256::from_decimal_floor(d), U256::new(2));
+        assert_eq!(U256::from_decimal_ceil(d), U256::new(3));
+        assert_eq!(
+            U256::from_decimal_floor(Decimal::MAX),
+            max / DECIMAL_FRACTIONAL
+        );
+        assert_eq!(
+            U256::from_decimal_ceil(Decimal::MAX),
+            max / DECIMAL_FRACTIONAL + 1
+        );
+
+        let max_int = max / DECIMAL_FRACTIONAL;
+        assert_eq!(
+            U256::from_decimal_floor(max_int.to_decimal_checked().unwrap()),
+            max_int
+        );
+        assert_eq!(U256::new(7).to_decimal_checked(), Ok(Decimal::percent(700)));
+        assert_eq!(
+            (max_int + 1).to_decimal_checked(),
+            Err(ConversionOverflowError::new(
+                "U256",
+                "Decimal",
+                (max_int + 1).to_string()
+            ))
+        );
+        assert!(U256::MAX.to_decimal_checked().is_err());
+    }
+
+    #[test]
+    fn mul_div_fraction() {
+        assert_eq!(U256::MAX.mul_floor(Decimal256::one()), U256::MAX);
+        assert_eq!(U256::MAX.mul_ceil(Decimal256::one()), U256::MAX);
+        assert_eq!(U256::MAX.div_fraction_floor(Decimal256::one()), U256::MAX);
+        assert_eq!(U256::MAX.div_fraction_ceil(Decimal256::one()), U256::MAX);
+
+        let fee = Decimal256::percent(3);
+        assert_eq!(U256::new(1001).mul_floor(fee), U256::new(30));
+        assert_eq!(U256::new(1001).mul_ceil(fee), U256::new(31));
+        assert_eq!(U256::new(1000).mul_ceil(fee), U256::new(30));
+        assert_eq!(U256::new(100).div_fraction_floor(fee), U256::new(3333));
+        assert_eq!(U256::new(100).div_fraction_ceil(fee), U256::new(3334));
+
+        let third = Ratio256::new(U256::ONE, U256::new(3));
+        assert_eq!(U256::new(10).mul_floor(third), U256::new(3));
+        assert_eq!(U256::new(10).mul_ceil(third), U256::new(4));
+        assert_eq!(U256::new(10).div_fraction_floor(third), U256::new(30));
+        assert_eq!(U256::MAX.mul_floor(third), U256::MAX / 3);
+
+        assert_eq!(
+            U256::MAX.checked_mul_floor(Decimal256::percent(101)),
+            Err(CheckedMultiplyFractionError::ConversionOverflow(
+                ConversionOverflowError::new(
+                    "Uint512",
+                    "U256",
+                    (full_mul(U256::MAX, U256::new(101)) / Uint512::from(100u8)).to_string()
+                )
+            ))
+        );
+        assert_eq!(
+            U256::ONE.checked_div_fraction_floor(Decimal256::zero()),
+            Err(CheckedMultiplyFractionError::DivideByZero(
+                DivideByZeroError::new(1)
+            ))
+        );
+        assert!(U256::ONE
+            .checked_mul_ceil(Ratio256::new(U256::ONE, U256::ZERO))
+            .is_err());
+        assert_eq!(
+            U256::MAX.checked_mul_ceil(Ratio256::new(U256::MAX, U256::MAX - 1)),
+            Err(CheckedMultiplyFractionError::ConversionOverflow(
+                ConversionOverflowError::new(
+                    "Uint512",
+                    "U256",
+                    full_mul(U256::MAX, U256::MAX)
+                        .checked_div(Uint512::from(Uint256::MAX - Uint256::one()))
+                        .unwrap()
+                        .to_string()
+                )
+            ))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn mul_div_fraction_matches_uint256(
+            value in any::<[u8; 32]>(),
+            numerator in any::<[u8; 32]>(),
+            denominator in any::<[u8; 32]>(),
+        ) {
+            let (x, n, d) = (
+                Uint256::from_be_bytes(value),
+                Uint256::from_be_bytes(numerator),
+                Uint256::from_be_bytes(denominator),
+            );
+            let ratio = Ratio256::new(n.into(), d.into());
+
+            let floor = x.checked_multiply_ratio(n, d).ok().map(U256::from);
+            prop_assert_eq!(U256::from(x).checked_mul_floor(ratio).ok(), floor);
+
+            let ceil = (!d.is_zero())
+                .then(|| {
+                    let product = x.full_mul(n);
+                    let d = Uint512::from(d);
+                    let ceil = product / d + Uint512::from((!(product % d).is_zero()) as u8);
+                    Uint256::try_from(ceil).ok()
+                })
+                .flatten()
+                .map(U256::from);
+            prop_assert_eq!(U256::from(x).checked_mul_ceil(ratio).ok(), ceil);
+
+            let decimal = Decimal256::new(n);
+            let floor = x.checked_multiply_ratio(n, Uint256::from(10u8).pow(18)).ok().map(U256::from);
+            prop_assert_eq!(U256::from(x).checked_mul_floor(decimal).ok(), floor);
+            prop_assert_eq!(
+                U256::from(x).checked_div_fraction_floor(ratio).ok(),
+                x.checked_multiply_ratio(d, n).ok().map(U256::from)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn div_fraction_matches_upstream_cosmwasm(
+            value in any::<[u8; 32]>(),
+            atomics in any::<[u8; 32]>(),
+            shift in 0..256u32,
+        ) {
+            // Shift the decimals to also cover small values and exact
+            // divisions, which the rounding of `inv` would get wrong.
+            let atomics = U256::from_be_bytes(atomics) >> shift;
+            let (x, decimal) = (
+                U256::from_be_bytes(value),
+                Decimal256::new(atomics.into()),
+            );
+            let upstream = (
+                cw_cosmwasm_std::Uint256::from_be_bytes(x.to_be_bytes()),
+                cw_cosmwasm_std::Decimal256::new(
+                    cw_cosmwasm_std::Uint256::from_be_bytes(atomics.to_be_bytes()),
+                ),
+            );
+
+            let floor = upstream.0
+                .checked_div_floor(upstream.1)
+                .ok()
+                .map(|v| U256::from_be_bytes(v.to_be_bytes()));
+            prop_assert_eq!(x.checked_div_fraction_floor(decimal).ok(), floor);
+
+            let ceil = upstream.0
+                .checked_div_ceil(upstream.1)
+                .ok()
+                .map(|v| U256::from_be_bytes(v.to_be_bytes()));
+            prop_assert_eq!(x.checked_div_fraction_ceil(decimal).ok(), ceil);
+        }
+    }
+
+    #[test]
+    fn div_fraction_is_exact() {
+        let three = "3.0".parse::<Decimal256>().unwrap();
+        assert_eq!(U256::new(3).div_fraction_floor(three), U256::ONE);
+        assert_eq!(U256::new(3).div_fraction_ceil(three), U256::ONE);
+
+        let e18 = U256::new(10_u128.pow(18));
+        assert_eq!(e18.div_fraction_floor(three), U256::new(333333333333333333));
+        assert_eq!(e18.div_fraction_ceil(three), U256::new(333333333333333334));
+        assert_eq!(
+            U256::MAX.checked_div_fraction_ceil(Ratio256::new(U256::ZERO, U256::ONE)),
+            Err(CheckedMultiplyFractionError::DivideByZero(
+                DivideByZeroError::new(U256::MAX)
+            ))
+        );
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn mixed_ops() {
+        let reserve = U256::new(u128::MAX);
+        let deposit = Uint128::MAX;
+        assert_eq!(reserve + deposit, reserve * 2);
+        assert_eq!(&reserve + deposit, reserve * 2);
+        assert_eq!(reserve + &deposit, reserve * 2);
+        assert_eq!(&reserve - &deposit, U256::ZERO);
+        assert_eq!(reserve * Uint64::new(2), reserve * 2);
+        assert_eq!(reserve / Uint64::new(2), reserve / 2);
+        assert_eq!(reserve % Uint128::new(10), reserve % 10);
+
+        let large = Uint256::MAX - Uint256::one();
+        assert_eq!(U256::MAX - large, U256::ONE);
+        assert_eq!(U256::MAX / large, U256::ONE);
+        assert_eq!(U256::MAX % large, U256::ONE);
+
+        let mut x = U256::ZERO;
+        x += Uint128::new(40);
+        x += &Uint64::new(2);
+        x *= Uint256::from(3u8);
+        x -= Uint128::new(6);
+        x /= &Uint256::from(4u8);
+        x %= Uint64::new(7);
+        assert_eq!(x, U256::new(2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mixed_ops_overflow() {
+        let _ = U256::MAX + Uint128::one();
+    }
+
+    #[test]
+    #[should_panic]
+    fn mixed_ops_underflow() {
+        let _ = U256::ZERO - Uint256::one();
+    }
+
+    #[test]
+    #[should_panic]
+    fn mixed_ops_division_by_zero() {
+        let _ = U256::ONE / Uint64::zero();
+    }
+
+    #[test]
+    fn cast_to_uint128() {
+        let max = U256::new(u128::MAX);
+        assert_eq!(max.saturating_to::<Uint128>(), Uint128::MAX);
+        assert_eq!((max + 1).saturating_to::<Uint128>(), Uint128::MAX);
+        assert_eq!((max + 2).truncate_to::<Uint128>(), Uint128::new(1));
+        assert_eq!(I256::MINUS_ONE.saturating_to::<Uint128>(), Uint128::zero());
+        assert_eq!(I256::MINUS_ONE.truncate_to::<Uint128>(), Uint128::MAX);
+    }
+}
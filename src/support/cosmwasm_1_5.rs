@@ -0,0 +1,184 @@
+//! Module with conversions between `I256` and the signed integer and decimal
+//! types added in upstream
+//! [`cosmwasm-std`](https://crates.io/crates/cosmwasm-std) 1.5.
+//!
+//! These types are not available in the `secret-cosmwasm-std` release used by
+//! the `cosmos` feature, so they are implemented against the upstream crate.
+
+use alloc::string::ToString;
+use cw_cosmwasm_std::{ConversionOverflowError, Int128, Int256, Int64, SignedDecimal256};
+
+use crate::I256;
+
+impl From<Int64> for I256 {
+    fn from(i: Int64) -> Self {
+        I256::from(i.i64())
+    }
+}
+
+impl From<Int128> for I256 {
+    fn from(i: Int128) -> Self {
+        I256::new(i.i128())
+    }
+}
+
+impl From<Int256> for I256 {
+    fn from(i: Int256) -> Self {
+        I256::from_be_bytes(i.to_be_bytes())
+    }
+}
+
+impl From<I256> for Int256 {
+    fn from(value: I256) -> Self {
+        Int256::from_be_bytes(value.to_be_bytes())
+    }
+}
+
+/// Converts an `I256` to an `Int64`, returning an error instead of truncating
+/// values outside of the `Int64` range.
+impl TryFrom<I256> for Int64 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: I256) -> Result<Self, Self::Error> {
+        i64::try_from(value)
+            .map(Int64::new)
+            .map_err(|_| ConversionOverflowError::new("I256", "Int64", value.to_string()))
+    }
+}
+
+/// Converts an `I256` to an `Int128`, returning an error instead of truncating
+/// values outside of the `Int128` range.
+impl TryFrom<I256> for Int128 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: I256) -> Result<Self, Self::Error> {
+        i128::try_from(value)
+            .map(Int128::new)
+            .map_err(|_| ConversionOverflowError::new("I256", "Int128", value.to_string()))
+    }
+}
+
+impl I256 {
+    /// Returns the raw atomics of a `SignedDecimal256`, i.e. the decimal value
+    /// multiplied by 10^18.
+    pub fn from_signed_decimal_atomics(d: SignedDecimal256) -> Self {
+        I256::from(d.atomics())
+    }
+
+    /// Creates a `SignedDecimal256` with the value as its raw atomics, i.e.
+    /// the resulting decimal is the value divided by 10^18.
+    pub fn to_signed_decimal(self) -> SignedDecimal256 {
+        SignedDecimal256::new(self.into())
+    }
+}
+
+/// Converts a `SignedDecimal256` to an `I256` by taking its raw atomics (i.e.
+/// the decimal value multiplied by 10^18).
+///
+/// This is the same as [`I256::from_signed_decimal_atomics`].
+impl From<SignedDecimal256> for I256 {
+    fn from(d: SignedDecimal256) -> Self {
+        I256::from_signed_decimal_atomics(d)
+    }
+}
+
+/// Converts an `I256` to a `SignedDecimal256` by treating it as the raw
+/// atomics (i.e. the decimal value multiplied by 10^18).
+///
+/// This is the same as [`I256::to_signed_decimal`].
+impl From<I256> for SignedDecimal256 {
+    fn from(value: I256) -> Self {
+        value.to_signed_decimal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn signed_integers() {
+        assert_eq!(I256::from(Int64::MIN), I256::from(i64::MIN));
+        assert_eq!(I256::from(Int64::MAX), I256::from(i64::MAX));
+        assert_eq!(I256::from(Int128::MIN), I256::new(i128::MIN));
+        assert_eq!(I256::from(Int128::new(-42)), -42);
+        assert_eq!(I256::from(Int256::MIN), I256::MIN);
+        assert_eq!(I256::from(Int256::MAX), I256::MAX);
+
+        for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            assert_eq!(I256::from(Int256::from(value)), value);
+        }
+
+        assert_eq!(Int64::try_from(I256::from(i64::MIN)), Ok(Int64::MIN));
+        assert_eq!(Int64::try_from(I256::new(-42)), Ok(Int64::new(-42)));
+        assert_eq!(Int128::try_from(I256::new(i128::MAX)), Ok(Int128::MAX));
+
+        let too_small = I256::from(i64::MIN) - 1;
+        assert_eq!(
+            Int64::try_from(too_small),
+            Err(ConversionOverflowError::new(
+                "I256",
+                "Int64",
+                "-9223372036854775809"
+            ))
+        );
+        assert_eq!(
+            Int128::try_from(I256::MAX),
+            Err(ConversionOverflowError::new(
+                "I256",
+                "Int128",
+                I256::MAX.to_string()
+            ))
+        );
+        assert_eq!(
+            Int64::try_from(too_small).unwrap_err().to_string(),
+            "Error converting I256 to Int64 for -9223372036854775809"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn signed_integer_round_trip(bytes in any::<[u8; 32]>(), small in any::<i64>()) {
+            // Random bytes rarely produce small values, so check values
+            // around zero separately to cover both sides of the sign.
+            for x in [I256::from_be_bytes(bytes), I256::from(small)] {
+                let i = Int256::from(x);
+                prop_assert_eq!(i.to_be_bytes(), x.to_be_bytes());
+                prop_assert_eq!(i.to_string(), x.to_string());
+                prop_assert_eq!(I256::from(i), x);
+
+                let narrow = Int128::try_from(x).ok().map(I256::from);
+                prop_assert_eq!(narrow, i128::try_from(x).ok().map(I256::new));
+                let narrow = Int64::try_from(x).ok().map(I256::from);
+                prop_assert_eq!(narrow, i64::try_from(x).ok().map(I256::from));
+            }
+        }
+    }
+
+    #[test]
+    fn signed_decimals() {
+        let d: SignedDecimal256 = "-1.5".parse().unwrap();
+        assert_eq!(I256::from(d), I256::new(-1_500_000_000_000_000_000));
+        assert_eq!(I256::from_signed_decimal_atomics(d), I256::from(d));
+        assert_eq!(I256::new(-1_500_000_000_000_000_000).to_signed_decimal(), d);
+        assert_eq!(I256::from(SignedDecimal256::MIN), I256::MIN);
+        assert_eq!(I256::from(SignedDecimal256::MAX), I256::MAX);
+
+        for value in [
+            I256::MIN,
+            I256::new(-1),
+            I256::ZERO,
+            I256::new(7),
+            I256::MAX,
+        ] {
+            let d: SignedDecimal256 = value.into();
+            assert_eq!(I256::from(d), value);
+        }
+        assert_eq!(SignedDecimal256::from(I256::MIN), SignedDecimal256::MIN);
+        assert_eq!(
+            I256::ONE.to_signed_decimal(),
+            SignedDecimal256::new(Int256::one())
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Module that implements support for the [`defmt`](https://crates.io/crates/defmt)
+//! crate.
+//!
+//! Values are logged as `0x` prefixed hexadecimal numbers without leading
+//! zeros, the same as the `{:#x}` format. Negative `I256` values are logged as
+//! a `-` sign followed by the hexadecimal magnitude. The 128-bit words are sent
+//! with defmt's own integer encoding, so the formatting happens on the host and
+//! no buffers are needed on the device.
+
+use crate::{I256, U256};
+use defmt::{Format, Formatter};
+
+/// The parts that a 256-bit integer is logged as.
+#[derive(Debug, Eq, PartialEq)]
+struct HexParts {
+    negative: bool,
+    high: Option<u128>,
+    low: u128,
+}
+
+impl HexParts {
+    /// Splits a sign and magnitude into the words to log. The high word is
+    /// omitted when it is zero so that small values are logged compactly.
+    fn new(negative: bool, magnitude: U256) -> Self {
+        let (high, low) = magnitude.into_words();
+        Self {
+            negative,
+            high: (high != 0).then_some(high),
+            low,
+        }
+    }
+
+    fn format(&self, f: Formatter<'_>) {
+        match (self.negative, self.high) {
+            (false, None) => defmt::write!(f, "{=u128:#x}", self.low),
+            (false, Some(high)) => defmt::write!(f, "{=u128:#x}{=u128:032x}", high, self.low),
+            (true, None) => defmt::write!(f, "-{=u128:#x}", self.low),
+            (true, Some(high)) => defmt::write!(f, "-{=u128:#x}{=u128:032x}", high, self.low),
+        }
+    }
+}
+
+impl Format for U256 {
+    fn format(&self, f: Formatter<'_>) {
+        HexParts::new(false, *self).format(f);
+    }
+}
+
+impl Format for I256 {
+    fn format(&self, f: Formatter<'_>) {
+        HexParts::new(self.is_negative(), self.unsigned_abs()).format(f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::String};
+
+    /// Renders the parts the same way the host side decoder does for the
+    /// format strings above.
+    fn render(parts: HexParts) -> String {
+        let sign = if parts.negative { "-" } else { "" };
+        match parts.high {
+            None => format!("{sign}{:#x}", parts.low),
+            Some(high) => format!("{sign}{high:#x}{:032x}", parts.low),
+        }
+    }
+
+    #[test]
+    fn hex_parts() {
+        for value in [
+            U256::ZERO,
+            U256::new(42),
+            U256::new(u128::MAX),
+            U256::from_words(1, 0),
+            U256::from_words(0xff, 1),
+            U256::MAX,
+        ] {
+            assert_eq!(render(HexParts::new(false, value)), format!("{value:#x}"));
+        }
+
+        assert_eq!(
+            HexParts::new(false, U256::new(42)),
+            HexParts {
+                negative: false,
+                high: None,
+                low: 42,
+            }
+        );
+        assert_eq!(
+            render(HexParts::new(false, U256::from_words(1, 2))),
+            "0x100000000000000000000000000000002"
+        );
+    }
+
+    #[test]
+    fn signed_hex_parts() {
+        let parts = |value: I256| HexParts::new(value.is_negative(), value.unsigned_abs());
+        assert_eq!(render(parts(I256::new(-42))), "-0x2a");
+        assert_eq!(render(parts(I256::ZERO)), "0x0");
+        assert_eq!(render(parts(I256::MAX)), format!("{:#x}", I256::MAX));
+        assert_eq!(
+            render(parts(I256::MIN)),
+            format!("-{:#x}", I256::MIN.unsigned_abs())
+        );
+    }
+}
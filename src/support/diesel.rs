@@ -0,0 +1,223 @@
+//! Module that implements support for the [`diesel`](https://crates.io/crates/diesel)
+//! crate with the PostgreSQL backend.
+//!
+//! The 256-bit integer types derive `AsExpression` and `FromSqlRow`, so they
+//! can be used directly in Diesel schemas and models for the following SQL
+//! types:
+//!
+//! - `Numeric`: values are encoded with the binary `NUMERIC` wire format.
+//!   Values with fractional digits, `NaN` and out of range values produce a
+//!   deserialization error instead of being truncated.
+//! - `Binary`: values are encoded as exactly 32 big endian bytes (two's
+//!   complement for `I256`), so that `BYTEA` columns of unsigned integers sort
+//!   numerically.
+
+use super::pg_numeric;
+use crate::{I256, U256};
+use diesel::{
+    deserialize::{self, FromSql},
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::{Binary, Numeric},
+};
+use std::io::Write as _;
+
+macro_rules! impl_diesel {
+    ($($t:ident),*) => {$(
+        impl ToSql<Numeric, Pg> for $t {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+                out.write_all(&pg_numeric::encode(self))?;
+                Ok(IsNull::No)
+            }
+        }
+
+        impl FromSql<Numeric, Pg> for $t {
+            fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+                Ok(pg_numeric::decode(value.as_bytes())?)
+            }
+        }
+
+        impl ToSql<Binary, Pg> for $t {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+                out.write_all(&self.to_be_bytes())?;
+                Ok(IsNull::No)
+            }
+        }
+
+        impl FromSql<Binary, Pg> for $t {
+            fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+                let bytes = value
+                    .as_bytes()
+                    .try_into()
+                    .map_err(|_| "invalid length for a 256-bit integer, expected 32 bytes")?;
+                Ok($t::from_be_bytes(bytes))
+            }
+        }
+    )*};
+}
+
+impl_diesel!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::{
+        pg::{data_types::PgNumeric, PgMetadataLookup, PgTypeMetadata},
+        query_builder::bind_collector::RawBytesBindCollector,
+        sql_types::HasSqlType,
+    };
+    use std::{format, num::NonZeroU32, string::ToString, vec, vec::Vec};
+
+    diesel::table! {
+        accounts (id) {
+            id -> Int4,
+            balance -> Numeric,
+            hash -> Bytea,
+        }
+    }
+
+    struct NoLookup;
+
+    impl PgMetadataLookup for NoLookup {
+        fn lookup_type(&mut self, _: &str, _: Option<&str>) -> PgTypeMetadata {
+            unreachable!("built-in types don't need to be looked up")
+        }
+    }
+
+    fn to_sql<ST, T>(value: &T) -> Vec<u8>
+    where
+        Pg: HasSqlType<ST>,
+        T: ToSql<ST, Pg>,
+    {
+        let mut collector = RawBytesBindCollector::<Pg>::new();
+        diesel::query_builder::BindCollector::push_bound_value::<ST, T>(
+            &mut collector,
+            value,
+            &mut NoLookup,
+        )
+        .unwrap();
+        collector.binds.pop().unwrap().unwrap()
+    }
+
+    fn from_sql<ST, T>(raw: &[u8]) -> deserialize::Result<T>
+    where
+        T: FromSql<ST, Pg>,
+    {
+        let oid = || NonZeroU32::new(1700).unwrap();
+        T::from_sql(PgValue::new(raw, &oid))
+    }
+
+    #[test]
+    fn numeric_round_trips() {
+        for value in [U256::ZERO, U256::new(12345678), U256::MAX] {
+            let raw = to_sql::<Numeric, _>(&value);
+            assert_eq!(from_sql::<Numeric, U256>(&raw).unwrap(), value);
+        }
+        for value in [I256::MIN, I256::MINUS_ONE, I256::MAX] {
+            let raw = to_sql::<Numeric, _>(&value);
+            assert_eq!(from_sql::<Numeric, I256>(&raw).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn matches_pg_numeric() {
+        for (value, numeric) in [
+            (
+                I256::ZERO,
+                PgNumeric::Positive {
+                    weight: 0,
+                    scale: 0,
+                    digits: vec![],
+                },
+            ),
+            (
+                I256::new(-12345678),
+                PgNumeric::Negative {
+                    weight: 1,
+                    scale: 0,
+                    digits: vec![1234, 5678],
+                },
+            ),
+            (
+                I256::new(10_0000_0000),
+                PgNumeric::Positive {
+                    weight: 2,
+                    scale: 0,
+                    digits: vec![10],
+                },
+            ),
+        ] {
+            assert_eq!(to_sql::<Numeric, _>(&value), to_sql::<Numeric, _>(&numeric));
+            assert_eq!(
+                from_sql::<Numeric, PgNumeric>(&to_sql::<Numeric, _>(&value)).unwrap(),
+                numeric
+            );
+        }
+
+        // `1.5` is rejected instead of being truncated.
+        let fractional = PgNumeric::Positive {
+            weight: 0,
+            scale: 1,
+            digits: vec![1, 5000],
+        };
+        let raw = to_sql::<Numeric, _>(&fractional);
+        assert_eq!(
+            from_sql::<Numeric, U256>(&raw).unwrap_err().to_string(),
+            "cannot convert NUMERIC with a fractional part to an integer"
+        );
+
+        let raw = to_sql::<Numeric, _>(&PgNumeric::NaN);
+        assert!(from_sql::<Numeric, I256>(&raw).is_err());
+
+        let raw = to_sql::<Numeric, _>(&(I256::MAX.as_u256() + 1));
+        assert_eq!(
+            from_sql::<Numeric, I256>(&raw).unwrap_err().to_string(),
+            "NUMERIC value out of range for a 256-bit integer"
+        );
+    }
+
+    #[test]
+    fn schema() {
+        use diesel::prelude::*;
+
+        let query = diesel::insert_into(accounts::table).values((
+            accounts::balance.eq(U256::MAX),
+            accounts::hash.eq(I256::MINUS_ONE),
+        ));
+        assert_eq!(
+            diesel::debug_query::<Pg, _>(&query).to_string(),
+            format!(
+                "INSERT INTO \"accounts\" (\"balance\", \"hash\") VALUES ($1, $2) \
+                 -- binds: [{}, {}]",
+                U256::MAX,
+                I256::MINUS_ONE,
+            )
+        );
+
+        let query = accounts::table
+            .filter(accounts::balance.gt(U256::ONE))
+            .select(accounts::id);
+        assert_eq!(
+            diesel::debug_query::<Pg, _>(&query).to_string(),
+            "SELECT \"accounts\".\"id\" FROM \"accounts\" \
+             WHERE (\"accounts\".\"balance\" > $1) -- binds: [1]"
+        );
+    }
+
+    #[test]
+    fn binary() {
+        let value = U256::from_words(1, 2);
+        let raw = to_sql::<Binary, _>(&value);
+        assert_eq!(raw, value.to_be_bytes());
+        assert_eq!(from_sql::<Binary, U256>(&raw).unwrap(), value);
+
+        assert_eq!(to_sql::<Binary, _>(&I256::MINUS_ONE), [0xff; 32]);
+        assert_eq!(
+            from_sql::<Binary, I256>(&[0xff; 32]).unwrap(),
+            I256::MINUS_ONE
+        );
+
+        assert!(from_sql::<Binary, U256>(&[0; 31]).is_err());
+        assert!(from_sql::<Binary, U256>(&[0; 33]).is_err());
+    }
+}
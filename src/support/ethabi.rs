@@ -0,0 +1,118 @@
+//! Module that implements conversions to and from the
+//! [`ethabi`](https://crates.io/crates/ethabi) crate's Solidity ABI tokens.
+//!
+//! `U256` values convert to `uint256` tokens and `I256` values to `int256`
+//! tokens. ABI `int` tokens hold the 32-byte word in two's complement, so the
+//! signed conversions reinterpret the word's bits without any range checks.
+
+use crate::{I256, U256};
+use alloc::borrow::Cow;
+use ethabi::{Error, Token};
+
+/// Converts a 256-bit integer to the limbs of `ethabi`'s word type.
+fn to_word(value: U256) -> ethabi::Uint {
+    let (hi, lo) = value.into_words();
+    ethabi::ethereum_types::U256([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+}
+
+/// Converts the limbs of `ethabi`'s word type to a 256-bit integer.
+fn from_word(word: ethabi::Uint) -> U256 {
+    let [a, b, c, d] = word.0.map(u128::from);
+    U256::from_words(c | (d << 64), a | (b << 64))
+}
+
+impl From<U256> for Token {
+    fn from(value: U256) -> Self {
+        Token::Uint(to_word(value))
+    }
+}
+
+impl From<I256> for Token {
+    fn from(value: I256) -> Self {
+        Token::Int(to_word(value.as_u256()))
+    }
+}
+
+impl TryFrom<Token> for U256 {
+    type Error = Error;
+
+    /// Converts a `uint` token, failing for all other token types.
+    fn try_from(token: Token) -> Result<Self, Self::Error> {
+        match token {
+            Token::Uint(word) => Ok(from_word(word)),
+            _ => Err(Error::Other(Cow::Borrowed("expected a `uint` token"))),
+        }
+    }
+}
+
+impl TryFrom<Token> for I256 {
+    type Error = Error;
+
+    /// Converts an `int` token, failing for all other token types.
+    fn try_from(token: Token) -> Result<Self, Self::Error> {
+        match token {
+            Token::Int(word) => Ok(from_word(word).as_i256()),
+            _ => Err(Error::Other(Cow::Borrowed("expected an `int` token"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use core::slice;
+    use ethabi::ParamType;
+
+    #[test]
+    fn unsigned_tokens() {
+        for value in [
+            U256::ZERO,
+            U256::ONE,
+            U256::from_words(0x0102, 0x0304),
+            U256::MAX,
+        ] {
+            let token = Token::from(value);
+            let encoded = ethabi::encode(slice::from_ref(&token));
+            assert_eq!(encoded, value.to_be_bytes());
+            let decoded = ethabi::decode(&[ParamType::Uint(256)], &encoded).unwrap();
+            assert_eq!(decoded, vec![token.clone()]);
+            assert_eq!(U256::try_from(token).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn signed_tokens() {
+        for (value, word) in [
+            (I256::MINUS_ONE, [0xff; 32]),
+            (I256::MIN, {
+                let mut word = [0; 32];
+                word[0] = 0x80;
+                word
+            }),
+            (I256::MAX, {
+                let mut word = [0xff; 32];
+                word[0] = 0x7f;
+                word
+            }),
+            (I256::ZERO, [0; 32]),
+        ] {
+            let token = Token::from(value);
+            let encoded = ethabi::encode(slice::from_ref(&token));
+            assert_eq!(encoded, word);
+            let decoded = ethabi::decode(&[ParamType::Int(256)], &encoded).unwrap();
+            assert_eq!(I256::try_from(decoded[0].clone()).unwrap(), value);
+            assert_eq!(I256::try_from(token).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn wrong_token_types() {
+        let err = U256::try_from(Token::from(I256::MINUS_ONE)).unwrap_err();
+        assert!(matches!(err, Error::Other(message) if message.contains("`uint`")));
+        let err = I256::try_from(Token::from(U256::ONE)).unwrap_err();
+        assert!(matches!(err, Error::Other(message) if message.contains("`int`")));
+        assert!(U256::try_from(Token::Bool(true)).is_err());
+        assert!(I256::try_from(Token::Bytes(vec![0; 32])).is_err());
+    }
+}
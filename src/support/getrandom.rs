@@ -0,0 +1,52 @@
+//! Module that implements support for the [`getrandom`](https://crates.io/crates/getrandom)
+//! crate.
+//!
+//! This provides constructors for random 256-bit integers from the operating
+//! system's random number generator, without having to set up a `rand` RNG.
+//!
+//! These values are not reproducible, so they are not suitable for
+//! deterministic contexts such as smart contracts. In particular, `getrandom`
+//! fails to compile for `wasm32-unknown-unknown` unless a backend is
+//! explicitly configured, and the `custom` backend can report an error; it
+//! never silently produces zeros.
+
+use crate::{I256, U256};
+use getrandom::Error;
+
+macro_rules! impl_getrandom {
+    ($($t:ident),*) => {$(
+        impl $t {
+            #[doc = concat!("Generates a random `", stringify!($t), "` from the operating system's random")]
+            /// number generator.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the random number generator fails, see
+            #[doc = concat!("[`", stringify!($t), "::try_random`] for a fallible version.")]
+            pub fn random() -> Self {
+                Self::try_random().expect("failed to get random bytes from the OS")
+            }
+
+            #[doc = concat!("Generates a random `", stringify!($t), "` from the operating system's random")]
+            /// number generator, returning an error if it is unavailable.
+            pub fn try_random() -> Result<Self, Error> {
+                let mut bytes = [0; 32];
+                getrandom::fill(&mut bytes)?;
+                Ok(Self::from_le_bytes(bytes))
+            }
+        }
+    )*};
+}
+
+impl_getrandom!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_values_differ() {
+        assert_ne!(U256::random(), U256::random());
+        assert_ne!(I256::try_random().unwrap(), I256::try_random().unwrap());
+    }
+}
@@ -0,0 +1,228 @@
+//! Module that implements support for the [`minicbor`](https://crates.io/crates/minicbor)
+//! crate.
+//!
+//! Values are encoded with CBOR's preferred serialization for integers: plain
+//! integers when they fit in the 64-bit major types, and bignums, tag 2 for
+//! positive and tag 3 for negative values with a big endian byte string
+//! payload, otherwise. Bignum payloads are encoded without leading zeros.
+//!
+//! Decoding accepts both forms, including bignums with leading zeros in their
+//! payload and bignums for values that would fit a plain integer.
+
+use crate::{I256, U256};
+use minicbor::{
+    data::{IanaTag, Int, Type},
+    decode::{self, Decoder},
+    encode::{self, Encoder, Write},
+    Decode, Encode,
+};
+
+/// Encodes a CBOR integer from its sign and magnitude. Following CBOR, the
+/// value for a negative integer is `-1 - magnitude`.
+fn encode_int<W: Write>(
+    e: &mut Encoder<W>,
+    negative: bool,
+    magnitude: U256,
+) -> Result<(), encode::Error<W::Error>> {
+    if let Ok(magnitude) = u64::try_from(magnitude) {
+        let int = if negative {
+            Int::try_from(-1 - magnitude as i128).expect("value in CBOR integer range")
+        } else {
+            Int::from(magnitude)
+        };
+        e.int(int)?;
+    } else {
+        let bytes = magnitude.to_be_bytes();
+        let start = magnitude.leading_zeros() as usize / 8;
+        e.tag(if negative {
+            IanaTag::NegBignum
+        } else {
+            IanaTag::PosBignum
+        })?
+        .bytes(&bytes[start..])?;
+    }
+    Ok(())
+}
+
+/// Decodes a CBOR integer or bignum into its sign and magnitude.
+fn decode_int(d: &mut Decoder<'_>) -> Result<(bool, U256), decode::Error> {
+    let position = d.position();
+    match d.datatype()? {
+        Type::Tag => {
+            let tag = d.tag()?;
+            let negative = if tag == IanaTag::PosBignum {
+                false
+            } else if tag == IanaTag::NegBignum {
+                true
+            } else {
+                return Err(decode::Error::tag_mismatch(tag).at(position));
+            };
+            let bytes = d.bytes()?;
+            let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+            let bytes = &bytes[start..];
+            if bytes.len() > 32 {
+                return Err(decode::Error::message("bignum out of range").at(position));
+            }
+            let mut buffer = [0; 32];
+            buffer[32 - bytes.len()..].copy_from_slice(bytes);
+            Ok((negative, U256::from_be_bytes(buffer)))
+        }
+        _ => {
+            let value = i128::from(d.int()?);
+            if value < 0 {
+                Ok((true, U256::new((-1 - value) as u128)))
+            } else {
+                Ok((false, U256::new(value as u128)))
+            }
+        }
+    }
+}
+
+impl<C> Encode<C> for U256 {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        encode_int(e, false, *self)
+    }
+}
+
+impl<C> Decode<'_, C> for U256 {
+    fn decode(d: &mut Decoder<'_>, _: &mut C) -> Result<Self, decode::Error> {
+        let position = d.position();
+        match decode_int(d)? {
+            (false, value) => Ok(value),
+            (true, _) => Err(decode::Error::message("negative value for U256").at(position)),
+        }
+    }
+}
+
+impl<C> Encode<C> for I256 {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        // NOTE: `-1 - x` is the bitwise complement of `x`.
+        if self.is_negative() {
+            encode_int(e, true, (!*self).as_u256())
+        } else {
+            encode_int(e, false, self.as_u256())
+        }
+    }
+}
+
+impl<C> Decode<'_, C> for I256 {
+    fn decode(d: &mut Decoder<'_>, _: &mut C) -> Result<Self, decode::Error> {
+        let position = d.position();
+        let (negative, magnitude) = decode_int(d)?;
+        if magnitude > I256::MAX.as_u256() {
+            return Err(decode::Error::message("integer out of range for I256").at(position));
+        }
+        let value = magnitude.as_i256();
+        Ok(if negative { !value } else { value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rfc8949_examples() {
+        for (value, encoded) in [
+            ("0", "00"),
+            ("1000000", "1a000f4240"),
+            ("18446744073709551615", "1bffffffffffffffff"),
+            ("18446744073709551616", "c249010000000000000000"),
+        ] {
+            let value = U256::from_str_radix(value, 10).unwrap();
+            assert_eq!(minicbor::to_vec(value).unwrap(), hex(encoded), "{value}");
+            assert_eq!(minicbor::decode::<U256>(&hex(encoded)).unwrap(), value);
+        }
+
+        for (value, encoded) in [
+            ("-1", "20"),
+            ("-1000", "3903e7"),
+            ("18446744073709551616", "c249010000000000000000"),
+            ("-18446744073709551616", "3bffffffffffffffff"),
+            ("-18446744073709551617", "c349010000000000000000"),
+        ] {
+            let value = I256::from_str_radix(value, 10).unwrap();
+            assert_eq!(minicbor::to_vec(value).unwrap(), hex(encoded), "{value}");
+            assert_eq!(minicbor::decode::<I256>(&hex(encoded)).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for value in [U256::ZERO, U256::new(u64::MAX as u128 + 1), U256::MAX] {
+            let encoded = minicbor::to_vec(value).unwrap();
+            assert_eq!(minicbor::decode::<U256>(&encoded).unwrap(), value);
+        }
+        for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            let encoded = minicbor::to_vec(value).unwrap();
+            assert_eq!(minicbor::decode::<I256>(&encoded).unwrap(), value);
+        }
+
+        let mut max = hex("c25820");
+        max.extend_from_slice(&[0xff; 32]);
+        assert_eq!(minicbor::to_vec(U256::MAX).unwrap(), max);
+        let mut min = hex("c35820");
+        min.extend_from_slice(&I256::MAX.to_be_bytes());
+        assert_eq!(minicbor::to_vec(I256::MIN).unwrap(), min);
+    }
+
+    #[test]
+    fn lenient_decoding() {
+        // Non-minimal bignums and small values as bignums.
+        assert_eq!(
+            minicbor::decode::<U256>(&hex("c243000001")).unwrap(),
+            U256::ONE
+        );
+        assert_eq!(
+            minicbor::decode::<I256>(&hex("c340")).unwrap(),
+            I256::MINUS_ONE
+        );
+        let mut padded = hex("c25821");
+        padded.push(0);
+        padded.extend_from_slice(&[0xff; 32]);
+        assert_eq!(minicbor::decode::<U256>(&padded).unwrap(), U256::MAX);
+        assert_eq!(
+            minicbor::decode::<I256>(&hex("1864")).unwrap(),
+            I256::new(100)
+        );
+    }
+
+    #[test]
+    fn decoding_errors() {
+        // Negative values for `U256`.
+        assert!(minicbor::decode::<U256>(&hex("20")).is_err());
+        assert!(minicbor::decode::<U256>(&hex("c349010000000000000000")).is_err());
+
+        // Out of range.
+        let mut too_big = hex("c25821");
+        too_big.extend_from_slice(&[1; 33]);
+        assert!(minicbor::decode::<U256>(&too_big).is_err());
+        let mut too_big = hex("c25820");
+        too_big.push(0x80);
+        too_big.extend_from_slice(&[0; 31]);
+        assert!(minicbor::decode::<I256>(&too_big).is_err());
+        too_big[0] = 0xc3;
+        assert!(minicbor::decode::<I256>(&too_big).is_err());
+
+        // Wrong tags and types.
+        assert!(minicbor::decode::<U256>(&hex("c14101")).is_err());
+        assert!(minicbor::decode::<U256>(&hex("6131")).is_err());
+        assert!(minicbor::decode::<I256>(&hex("f93c00")).is_err());
+    }
+}
@@ -1,6 +1,88 @@
+#[cfg(feature = "alloy")]
+mod alloy;
+#[cfg(feature = "alloy-rlp")]
+mod alloy_rlp;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "async-graphql")]
+mod async_graphql;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "bnum")]
+mod bnum;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "borsh-v1")]
+mod borsh_v1;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 #[cfg(feature = "cosmos")]
 mod cosmos;
-#[cfg(feature = "std")]
-mod std;
+#[cfg(feature = "cosmwasm-1_5")]
+mod cosmwasm_1_5;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "ethabi")]
+mod ethabi;
+#[cfg(feature = "getrandom")]
+mod getrandom;
+#[cfg(feature = "minicbor")]
+mod minicbor;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "num-integer")]
+mod num_integer;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "parity-uint")]
+#[macro_use]
+mod parity_uint;
+#[cfg(any(feature = "diesel", feature = "postgres", feature = "sqlx-postgres"))]
+mod pg_numeric;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "primitive-types")]
+mod primitive_types;
 #[cfg(feature = "proptest")]
-mod proptest;
\ No newline at end of file
+mod proptest;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "rlp")]
+mod rlp;
+#[cfg(feature = "ruint")]
+mod ruint;
+#[cfg(feature = "scale")]
+mod scale;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "speedy")]
+mod speedy;
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+mod sqlx;
+#[cfg(feature = "storage-plus")]
+mod storage_plus;
+#[cfg(feature = "subtle")]
+mod subtle;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm_bindgen;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "cosmos")]
+pub use self::cosmos::{CheckedMultiplyFractionError, DivisionError, Ratio256};
+#[cfg(feature = "rand")]
+pub use self::rand::{UniformI256, UniformU256};
+#[cfg(feature = "rkyv")]
+pub use self::rkyv::{ArchivedI256, ArchivedU256};
+#[cfg(feature = "scale")]
+pub use self::scale::CompactU256;
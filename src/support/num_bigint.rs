@@ -0,0 +1,123 @@
+//! Module that implements conversions to and from the
+//! [`num-bigint`](https://crates.io/crates/num-bigint) crate's arbitrary
+//! precision integer types.
+
+use crate::{error, I256, U256};
+use core::num::TryFromIntError;
+use num_bigint::{BigInt, BigUint};
+
+impl From<U256> for BigUint {
+    fn from(value: U256) -> Self {
+        BigUint::from_bytes_le(&value.to_le_bytes())
+    }
+}
+
+impl TryFrom<&BigUint> for U256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: &BigUint) -> Result<Self, Self::Error> {
+        U256::from_le_slice(&value.to_bytes_le()).map_err(|_| error::tfie())
+    }
+}
+
+impl From<I256> for BigInt {
+    fn from(value: I256) -> Self {
+        BigInt::from_signed_bytes_le(&value.to_le_bytes())
+    }
+}
+
+impl TryFrom<&BigInt> for I256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        I256::from_le_slice(&value.to_signed_bytes_le()).map_err(|_| error::tfie())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn u256() -> impl Strategy<Value = U256> {
+        any::<[u64; 4]>().prop_map(U256::from_limbs)
+    }
+
+    fn i256() -> impl Strategy<Value = I256> {
+        u256().prop_map(|x| x.as_i256())
+    }
+
+    fn modulus() -> BigUint {
+        BigUint::from(1u8) << 256u32
+    }
+
+    fn wrap(x: BigUint) -> U256 {
+        U256::try_from(&(x % modulus())).unwrap()
+    }
+
+    fn wrap_signed(x: BigInt) -> I256 {
+        let (_, bytes) = x.to_bytes_le();
+        let mut buf = [0; 32];
+        let n = bytes.len().min(32);
+        buf[..n].copy_from_slice(&bytes[..n]);
+        let abs = U256::from_le_bytes(buf);
+        if x.sign() == num_bigint::Sign::Minus {
+            abs.wrapping_neg().as_i256()
+        } else {
+            abs.as_i256()
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        for x in [U256::ZERO, U256::ONE, U256::MAX] {
+            assert_eq!(U256::try_from(&BigUint::from(x)).unwrap(), x);
+        }
+        assert_eq!(BigUint::from(U256::MAX) + 1u8, modulus());
+        assert!(U256::try_from(&modulus()).is_err());
+
+        for x in [I256::ZERO, I256::ONE, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            assert_eq!(I256::try_from(&BigInt::from(x)).unwrap(), x);
+        }
+        assert_eq!(BigInt::from(I256::MIN), -(BigInt::from(1u8) << 255u32));
+        assert_eq!(BigInt::from(I256::MAX), (BigInt::from(1u8) << 255u32) - 1);
+        assert!(I256::try_from(&(BigInt::from(I256::MAX) + 1)).is_err());
+        assert!(I256::try_from(&(BigInt::from(I256::MIN) - 1)).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(a in u256(), b in i256()) {
+            prop_assert_eq!(U256::try_from(&BigUint::from(a)).unwrap(), a);
+            prop_assert_eq!(I256::try_from(&BigInt::from(b)).unwrap(), b);
+        }
+
+        #[test]
+        fn unsigned_arithmetic(a in u256(), b in u256(), s in 0..256u32) {
+            let (ba, bb) = (BigUint::from(a), BigUint::from(b));
+            prop_assert_eq!(a.wrapping_add(b), wrap(&ba + &bb));
+            prop_assert_eq!(a.wrapping_sub(b), wrap(&ba + modulus() - &bb));
+            prop_assert_eq!(a.wrapping_mul(b), wrap(&ba * &bb));
+            prop_assert_eq!(a << s, wrap(&ba << s));
+            prop_assert_eq!(a >> s, wrap(&ba >> s));
+            if b != 0 {
+                prop_assert_eq!(a / b, wrap(&ba / &bb));
+                prop_assert_eq!(a % b, wrap(&ba % &bb));
+            }
+        }
+
+        #[test]
+        fn signed_arithmetic(a in i256(), b in i256()) {
+            let (ba, bb) = (BigInt::from(a), BigInt::from(b));
+            prop_assert_eq!(a.wrapping_add(b), wrap_signed(&ba + &bb));
+            prop_assert_eq!(a.wrapping_sub(b), wrap_signed(&ba - &bb));
+            prop_assert_eq!(a.wrapping_mul(b), wrap_signed(&ba * &bb));
+            if b != 0 {
+                // NOTE: `BigInt` division truncates towards zero just like
+                // primitive integer division.
+                prop_assert_eq!(a.wrapping_div(b), wrap_signed(&ba / &bb));
+                prop_assert_eq!(a.wrapping_rem(b), wrap_signed(&ba % &bb));
+            }
+        }
+    }
+}
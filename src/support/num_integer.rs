@@ -0,0 +1,383 @@
+//! Module that implements support for the
+//! [`num-integer`](https://crates.io/crates/num-integer) crate.
+
+use crate::{I256, U256};
+use num_integer::{Integer, Roots};
+
+impl Integer for U256 {
+    #[inline]
+    fn div_floor(&self, other: &Self) -> Self {
+        *self / *other
+    }
+
+    #[inline]
+    fn mod_floor(&self, other: &Self) -> Self {
+        *self % *other
+    }
+
+    #[inline]
+    fn div_ceil(&self, other: &Self) -> Self {
+        let (d, r) = self.div_rem(other);
+        if r != 0 {
+            d + 1
+        } else {
+            d
+        }
+    }
+
+    /// Calculates the greatest common divisor with Stein's algorithm.
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut m, mut n) = (*self, *other);
+        if m == 0 || n == 0 {
+            return m | n;
+        }
+
+        let shift = (m | n).trailing_zeros();
+        m >>= m.trailing_zeros();
+        n >>= n.trailing_zeros();
+        while m != n {
+            if m > n {
+                m -= n;
+                m >>= m.trailing_zeros();
+            } else {
+                n -= m;
+                n >>= n.trailing_zeros();
+            }
+        }
+        m << shift
+    }
+
+    #[inline]
+    fn lcm(&self, other: &Self) -> Self {
+        self.gcd_lcm(other).1
+    }
+
+    #[inline]
+    fn gcd_lcm(&self, other: &Self) -> (Self, Self) {
+        if *self == 0 && *other == 0 {
+            return (U256::ZERO, U256::ZERO);
+        }
+        let gcd = self.gcd(other);
+        (gcd, *self * (*other / gcd))
+    }
+
+    #[inline]
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        if *other == 0 {
+            return *self == 0;
+        }
+        *self % *other == 0
+    }
+
+    #[inline]
+    fn is_even(&self) -> bool {
+        *self.low() & 1 == 0
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    #[inline]
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        (*self / *other, *self % *other)
+    }
+}
+
+impl Integer for I256 {
+    /// Floored integer division, rounding towards negative infinity.
+    #[inline]
+    fn div_floor(&self, other: &Self) -> Self {
+        self.div_mod_floor(other).0
+    }
+
+    /// Floored integer modulo, with the sign of `other`.
+    #[inline]
+    fn mod_floor(&self, other: &Self) -> Self {
+        let r = *self % *other;
+        if (r > 0 && *other < 0) || (r < 0 && *other > 0) {
+            r + *other
+        } else {
+            r
+        }
+    }
+
+    #[inline]
+    fn div_mod_floor(&self, other: &Self) -> (Self, Self) {
+        let (d, r) = self.div_rem(other);
+        if (r > 0 && *other < 0) || (r < 0 && *other > 0) {
+            (d - 1, r + *other)
+        } else {
+            (d, r)
+        }
+    }
+
+    #[inline]
+    fn div_ceil(&self, other: &Self) -> Self {
+        let (d, r) = self.div_rem(other);
+        if (r > 0 && *other > 0) || (r < 0 && *other < 0) {
+            d + 1
+        } else {
+            d
+        }
+    }
+
+    /// Calculates the greatest common divisor, which is always non-negative.
+    ///
+    /// # Panics
+    ///
+    /// Like [`I256::abs`], this overflows if the result is `2^255`, which is
+    /// only the case when both values are multiples of [`I256::MIN`].
+    #[inline]
+    fn gcd(&self, other: &Self) -> Self {
+        self.unsigned_abs()
+            .gcd(&other.unsigned_abs())
+            .as_i256()
+            .abs()
+    }
+
+    #[inline]
+    fn lcm(&self, other: &Self) -> Self {
+        self.gcd_lcm(other).1
+    }
+
+    #[inline]
+    fn gcd_lcm(&self, other: &Self) -> (Self, Self) {
+        if *self == 0 && *other == 0 {
+            return (I256::ZERO, I256::ZERO);
+        }
+        let gcd = self.gcd(other);
+        (gcd, (*self * (*other / gcd)).abs())
+    }
+
+    #[inline]
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        match *other {
+            I256::ZERO => *self == 0,
+            // NOTE: Avoid the overflow of `I256::MIN % -1`.
+            I256::MINUS_ONE => true,
+            _ => *self % *other == 0,
+        }
+    }
+
+    #[inline]
+    fn is_even(&self) -> bool {
+        *self.low() & 1 == 0
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    #[inline]
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        (*self / *other, *self % *other)
+    }
+}
+
+impl Roots for U256 {
+    /// Returns the truncated principal `n`th root of the integer, computed
+    /// with Newton's method starting from a power of two that is greater or
+    /// equal to the root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn nth_root(&self, n: u32) -> Self {
+        match n {
+            0 => panic!("can't find a root of degree 0!"),
+            1 => return *self,
+            2 => return self.isqrt(),
+            _ => {}
+        }
+
+        let bits = 256 - self.leading_zeros();
+        if *self < 2 || n >= bits {
+            return U256::from(*self != 0);
+        }
+
+        let n1 = U256::from(n - 1);
+        let mut x = U256::ONE << bits.div_ceil(n);
+        loop {
+            let pow = x.checked_pow(n - 1).map_or(U256::ZERO, |pow| *self / pow);
+            let y = (n1 * x + pow) / U256::from(n);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Self {
+        self.isqrt()
+    }
+}
+
+impl Roots for I256 {
+    /// Returns the truncated principal `n`th root of the integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the integer is negative.
+    fn nth_root(&self, n: u32) -> Self {
+        if *self >= 0 {
+            self.as_u256().nth_root(n).as_i256()
+        } else {
+            assert!(n.is_odd(), "even roots of a negative are imaginary");
+            -self.unsigned_abs().nth_root(n).as_i256()
+        }
+    }
+
+    /// Returns the truncated principal square root of the integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the integer is negative.
+    #[inline]
+    fn sqrt(&self) -> Self {
+        assert!(*self >= 0, "the square root of a negative is imaginary");
+        self.as_u256().isqrt().as_i256()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Ratio;
+
+    #[test]
+    fn unsigned_integer() {
+        let (a, b) = (U256::new(84), U256::new(36));
+        assert_eq!(a.gcd(&b), 12);
+        assert_eq!(a.lcm(&b), 252);
+        assert_eq!(a.gcd(&U256::ZERO), a);
+        assert_eq!(U256::ZERO.lcm(&U256::ZERO), 0);
+        assert_eq!(Integer::div_floor(&a, &b), 2);
+        assert_eq!(a.mod_floor(&b), 12);
+        assert_eq!(Integer::div_ceil(&a, &b), 3);
+        assert_eq!(a.div_rem(&b), (U256::new(2), U256::new(12)));
+        assert!(a.is_even() && !a.is_odd());
+        assert!(U256::MAX.is_odd());
+        assert!(a.is_multiple_of(&U256::new(7)));
+        assert!(!a.is_multiple_of(&U256::ZERO));
+        assert!(U256::ZERO.is_multiple_of(&U256::ZERO));
+
+        let big = U256::ONE << 200u32;
+        assert_eq!((big * 3).gcd(&(big * 5)), big);
+        assert_eq!((big * 3).lcm(&U256::new(10)), big * 15);
+    }
+
+    #[test]
+    fn signed_floor_division() {
+        for (a, b, div, rem) in [
+            (7, 2, 3, 1),
+            (-7, 2, -4, 1),
+            (7, -2, -4, -1),
+            (-7, -2, 3, -1),
+            (-8, 2, -4, 0),
+            (8, -2, -4, 0),
+        ] {
+            let (a, b) = (I256::new(a), I256::new(b));
+            assert_eq!(Integer::div_floor(&a, &b), div, "{a} div_floor {b}");
+            assert_eq!(a.mod_floor(&b), rem, "{a} mod_floor {b}");
+            assert_eq!(a.div_mod_floor(&b), (I256::new(div), I256::new(rem)));
+        }
+        assert_eq!(
+            I256::new(-7).div_rem(&I256::new(2)),
+            (I256::new(-3), I256::new(-1))
+        );
+        assert_eq!(Integer::div_ceil(&I256::new(-7), &I256::new(2)), -3);
+        assert_eq!(Integer::div_ceil(&I256::new(7), &I256::new(2)), 4);
+        assert_eq!(Integer::div_floor(&I256::MIN, &I256::new(2)), I256::MIN / 2);
+        assert_eq!((I256::MIN + 1).mod_floor(&I256::new(2)), 1);
+    }
+
+    #[test]
+    fn signed_integer() {
+        let (a, b) = (I256::new(-84), I256::new(36));
+        assert_eq!(a.gcd(&b), 12);
+        assert_eq!(a.lcm(&b), 252);
+        assert_eq!(a.gcd(&I256::ZERO), 84);
+        assert_eq!(I256::MIN.gcd(&I256::new(6)), 2);
+        assert!(a.is_even());
+        assert!(I256::MINUS_ONE.is_odd());
+        assert!(I256::MIN.is_multiple_of(&I256::MINUS_ONE));
+        assert!(a.is_multiple_of(&I256::new(-7)));
+        assert!(!a.is_multiple_of(&I256::new(5)));
+    }
+
+    #[test]
+    fn roots() {
+        assert_eq!(U256::MAX.sqrt(), U256::new(u128::MAX));
+        assert_eq!(U256::MAX.nth_root(255), 2);
+        assert_eq!(U256::MAX.nth_root(256), 1);
+        assert_eq!(U256::ZERO.nth_root(5), 0);
+        assert_eq!(U256::ONE.nth_root(300), 1);
+
+        for n in 2..20 {
+            for x in [U256::new(3), U256::new(1000), U256::MAX / 7, U256::MAX] {
+                let root = x.nth_root(n);
+                assert!(root.pow(n) <= x, "{x} nth_root {n}");
+                assert!(
+                    (root + 1).checked_pow(n).is_none_or(|pow| pow > x),
+                    "{x} nth_root {n}"
+                );
+            }
+        }
+
+        assert_eq!(I256::new(-27).cbrt(), -3);
+        assert_eq!(I256::new(-28).cbrt(), -3);
+        assert_eq!(I256::new(-32).nth_root(5), -2);
+        assert_eq!(I256::MIN.nth_root(255), -2);
+        let root = I256::MAX.sqrt();
+        assert!(root * root <= I256::MAX);
+        assert!((root + 1).checked_mul(root + 1).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "imaginary")]
+    fn negative_sqrt() {
+        I256::MINUS_ONE.sqrt();
+    }
+
+    #[test]
+    fn rational() {
+        let third = Ratio::new(I256::ONE, I256::new(3));
+        let sixth = Ratio::new(I256::new(-1), I256::new(-6));
+        assert_eq!(third + sixth, Ratio::new(I256::ONE, I256::new(2)));
+        assert_eq!(
+            third - sixth * I256::new(4),
+            Ratio::new(I256::new(-1), I256::new(3))
+        );
+        assert_eq!((third * sixth).recip(), Ratio::from_integer(I256::new(18)));
+        assert_eq!(
+            Ratio::new(I256::new(-7), I256::new(2)).floor().to_integer(),
+            -4
+        );
+
+        let big = Ratio::new(U256::MAX, U256::MAX - 1);
+        assert_eq!(
+            big - Ratio::from_integer(U256::ONE),
+            Ratio::new(U256::ONE, U256::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn binomial() {
+        assert_eq!(
+            num_integer::binomial(U256::new(100), U256::new(50)),
+            U256::new(100891344545564193334812497256),
+        );
+        assert_eq!(
+            num_integer::binomial(I256::new(200), I256::new(100)),
+            I256::from_str_radix(
+                "90548514656103281165404177077484163874504589675413336841320",
+                10
+            )
+            .unwrap(),
+        );
+    }
+}
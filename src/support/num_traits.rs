@@ -0,0 +1,469 @@
+//! Module that implements support for the
+//! [`num-traits`](https://crates.io/crates/num-traits) crate.
+
+use crate::{I256, U256};
+use core::num::ParseIntError;
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedShr,
+    CheckedSub, FromPrimitive, Num, One, Pow, SaturatingAdd, SaturatingMul, SaturatingSub, Signed,
+    ToPrimitive, Unsigned, WrappingAdd, WrappingMul, WrappingNeg, WrappingShl, WrappingShr,
+    WrappingSub, Zero,
+};
+
+macro_rules! impl_num_traits {
+    ($($t:ident),*) => {$(
+        impl Zero for $t {
+            #[inline]
+            fn zero() -> Self {
+                $t::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == $t::ZERO
+            }
+        }
+
+        impl One for $t {
+            #[inline]
+            fn one() -> Self {
+                $t::ONE
+            }
+
+            #[inline]
+            fn is_one(&self) -> bool {
+                *self == $t::ONE
+            }
+        }
+
+        impl Num for $t {
+            type FromStrRadixErr = ParseIntError;
+
+            #[inline]
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                $t::from_str_radix(src, radix)
+            }
+        }
+
+        impl Bounded for $t {
+            #[inline]
+            fn min_value() -> Self {
+                $t::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                $t::MAX
+            }
+        }
+    )*};
+}
+
+impl_num_traits!(U256, I256);
+
+macro_rules! impl_ops {
+    ($t:ident; $($trait:ident::$method:ident($($arg:ident: $arg_t:ty => $val:expr)?) -> $ret:ty;)*) => {$(
+        impl $trait for $t {
+            #[inline]
+            fn $method(&self, $($arg: $arg_t)?) -> $ret {
+                $t::$method(*self, $($val)?)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_num_ops {
+    ($($t:ident),*) => {$(
+        impl_ops! {
+            $t;
+            CheckedAdd::checked_add(v: &Self => *v) -> Option<Self>;
+            CheckedSub::checked_sub(v: &Self => *v) -> Option<Self>;
+            CheckedMul::checked_mul(v: &Self => *v) -> Option<Self>;
+            CheckedDiv::checked_div(v: &Self => *v) -> Option<Self>;
+            CheckedRem::checked_rem(v: &Self => *v) -> Option<Self>;
+            CheckedNeg::checked_neg() -> Option<Self>;
+            CheckedShl::checked_shl(rhs: u32 => rhs) -> Option<Self>;
+            CheckedShr::checked_shr(rhs: u32 => rhs) -> Option<Self>;
+            WrappingAdd::wrapping_add(v: &Self => *v) -> Self;
+            WrappingSub::wrapping_sub(v: &Self => *v) -> Self;
+            WrappingMul::wrapping_mul(v: &Self => *v) -> Self;
+            WrappingNeg::wrapping_neg() -> Self;
+            WrappingShl::wrapping_shl(rhs: u32 => rhs) -> Self;
+            WrappingShr::wrapping_shr(rhs: u32 => rhs) -> Self;
+            SaturatingAdd::saturating_add(v: &Self => *v) -> Self;
+            SaturatingSub::saturating_sub(v: &Self => *v) -> Self;
+            SaturatingMul::saturating_mul(v: &Self => *v) -> Self;
+            OverflowingAdd::overflowing_add(v: &Self => *v) -> (Self, bool);
+            OverflowingSub::overflowing_sub(v: &Self => *v) -> (Self, bool);
+            OverflowingMul::overflowing_mul(v: &Self => *v) -> (Self, bool);
+        }
+
+        impl Pow<u32> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn pow(self, rhs: u32) -> $t {
+                $t::pow(self, rhs)
+            }
+        }
+
+        impl Pow<&u32> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn pow(self, rhs: &u32) -> $t {
+                $t::pow(self, *rhs)
+            }
+        }
+
+        impl Pow<u32> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn pow(self, rhs: u32) -> $t {
+                $t::pow(*self, rhs)
+            }
+        }
+
+        impl Pow<&u32> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn pow(self, rhs: &u32) -> $t {
+                $t::pow(*self, *rhs)
+            }
+        }
+    )*};
+}
+
+impl_num_ops!(U256, I256);
+
+macro_rules! impl_primitive {
+    ($($t:ident => $from_f64:expr;)*) => {$(
+        impl ToPrimitive for $t {
+            impl_primitive! {
+                @to
+                to_isize => isize, to_i8 => i8, to_i16 => i16, to_i32 => i32,
+                to_i64 => i64, to_i128 => i128,
+                to_usize => usize, to_u8 => u8, to_u16 => u16, to_u32 => u32,
+                to_u64 => u64, to_u128 => u128,
+            }
+
+            /// Converts the value to the nearest `f32`, returning `None` if it
+            /// exceeds the range of `f32`.
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                Some(self.as_f32()).filter(|f| f.is_finite())
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.as_f64())
+            }
+        }
+
+        impl FromPrimitive for $t {
+            // NOTE: The `isize` and `usize` conversions default to going
+            // through `i64` and `u64`, as there are no `TryFrom` implementations
+            // for them.
+            impl_primitive! {
+                @from
+                from_i8 => i8, from_i16 => i16, from_i32 => i32, from_i64 => i64,
+                from_i128 => i128,
+                from_u8 => u8, from_u16 => u16, from_u32 => u32, from_u64 => u64,
+                from_u128 => u128,
+            }
+
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                Self::from_f64(n as f64)
+            }
+
+            /// Converts a floating point value, truncating any fractional part,
+            /// returning `None` for `NaN` and values that are out of range
+            /// after truncation.
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                $from_f64(n)
+            }
+        }
+    )*};
+    (@to $($method:ident => $p:ty,)*) => {$(
+        #[inline]
+        fn $method(&self) -> Option<$p> {
+            <$p>::try_from(*self).ok()
+        }
+    )*};
+    (@from $($method:ident => $p:ty,)*) => {$(
+        #[inline]
+        fn $method(n: $p) -> Option<Self> {
+            Self::try_from(n).ok()
+        }
+    )*};
+}
+
+impl_primitive! {
+    // NOTE: Values in `(-1, 0)` truncate to zero, which is in range.
+    U256 => |n: f64| U256::try_from(if n > -1.0 && n < 0.0 { 0.0 } else { n }).ok();
+    I256 => |n: f64| I256::try_from(n).ok();
+}
+
+impl Unsigned for U256 {}
+
+impl Signed for I256 {
+    #[inline]
+    fn abs(&self) -> Self {
+        I256::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            I256::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        I256::signum(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        I256::is_positive(*self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        I256::is_negative(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AsI256 as _, AsU256 as _};
+    use core::fmt::Debug;
+
+    /// Sums the digits of a number written in the given radix, saturating at
+    /// the maximum value.
+    fn digit_sum<T>(src: &str, radix: u32) -> T
+    where
+        T: Num + Bounded + PartialOrd + Copy + Debug,
+        T::FromStrRadixErr: Debug,
+    {
+        let mut sum = T::zero();
+        for digit in src.chars() {
+            let digit = T::from_str_radix(digit.encode_utf8(&mut [0; 4]), radix).unwrap();
+            if sum > T::max_value() - digit {
+                return T::max_value();
+            }
+            sum = sum + digit;
+        }
+        assert!(sum >= T::min_value());
+        sum
+    }
+
+    #[test]
+    fn generic_num_bounded() {
+        assert_eq!(digit_sum::<U256>("1234", 10), U256::new(10));
+        assert_eq!(digit_sum::<I256>("ff", 16), I256::new(30));
+        assert_eq!(digit_sum::<U256>("", 10), U256::ZERO);
+        assert_eq!(<U256 as Bounded>::min_value(), U256::ZERO);
+        assert_eq!(<I256 as Bounded>::min_value(), I256::MIN);
+    }
+
+    #[test]
+    fn zero_one() {
+        assert!(U256::zero().is_zero());
+        assert!(I256::one().is_one());
+        assert!(!U256::one().is_zero());
+        assert!(!I256::MINUS_ONE.is_one());
+    }
+
+    #[test]
+    fn from_str_radix_errors() {
+        assert_eq!(
+            <U256 as Num>::from_str_radix("-1", 10),
+            U256::from_str_radix("-1", 10)
+        );
+        assert!(<I256 as Num>::from_str_radix("zz", 16).is_err());
+        assert_eq!(
+            <I256 as Num>::from_str_radix("-ff", 16).unwrap(),
+            I256::new(-255)
+        );
+    }
+
+    #[test]
+    fn signed() {
+        let values = [I256::MIN + 1, I256::new(-3), I256::ZERO, I256::new(7)];
+        for value in values {
+            assert_eq!(Signed::abs(&value), value.abs());
+            assert_eq!(Signed::signum(&value), value.signum());
+            assert_eq!(Signed::is_positive(&value), value > 0);
+            assert_eq!(Signed::is_negative(&value), value < 0);
+        }
+        assert_eq!(I256::new(5).abs_sub(&I256::new(2)), I256::new(3));
+        assert_eq!(I256::new(2).abs_sub(&I256::new(5)), I256::ZERO);
+    }
+
+    /// A minimal fixed-point multiplication with 18 decimals, bounded on the
+    /// operator traits like generic fixed-point libraries.
+    fn fixed_mul<T>(a: T, b: T) -> Option<T>
+    where
+        T: Num + CheckedMul + CheckedDiv + Pow<u32, Output = T> + From<u8>,
+    {
+        let scale = T::from(10).pow(18);
+        a.checked_mul(&b)?.checked_div(&scale)
+    }
+
+    #[test]
+    fn generic_fixed_point() {
+        let one = U256::new(10).pow(18);
+        assert_eq!(fixed_mul(one * 3, one / 2), Some(one * 3 / 2));
+        assert_eq!(fixed_mul(U256::MAX, one), None);
+        let one = I256::new(10).pow(18);
+        assert_eq!(fixed_mul(-one * 3, one / 2), Some(-one * 3 / 2));
+    }
+
+    #[test]
+    fn operator_traits_delegate() {
+        let values = [
+            U256::ZERO,
+            U256::ONE,
+            U256::new(1000),
+            U256::from_words(1, 0),
+            U256::MAX,
+        ];
+        for a in values {
+            for b in values {
+                assert_eq!(CheckedAdd::checked_add(&a, &b), a.checked_add(b));
+                assert_eq!(CheckedSub::checked_sub(&a, &b), a.checked_sub(b));
+                assert_eq!(CheckedMul::checked_mul(&a, &b), a.checked_mul(b));
+                assert_eq!(CheckedDiv::checked_div(&a, &b), a.checked_div(b));
+                assert_eq!(CheckedRem::checked_rem(&a, &b), a.checked_rem(b));
+                assert_eq!(WrappingAdd::wrapping_add(&a, &b), a.wrapping_add(b));
+                assert_eq!(WrappingSub::wrapping_sub(&a, &b), a.wrapping_sub(b));
+                assert_eq!(WrappingMul::wrapping_mul(&a, &b), a.wrapping_mul(b));
+                assert_eq!(SaturatingAdd::saturating_add(&a, &b), a.saturating_add(b));
+                assert_eq!(SaturatingSub::saturating_sub(&a, &b), a.saturating_sub(b));
+                assert_eq!(SaturatingMul::saturating_mul(&a, &b), a.saturating_mul(b));
+                assert_eq!(
+                    OverflowingAdd::overflowing_add(&a, &b),
+                    a.overflowing_add(b)
+                );
+                assert_eq!(
+                    OverflowingSub::overflowing_sub(&a, &b),
+                    a.overflowing_sub(b)
+                );
+                assert_eq!(
+                    OverflowingMul::overflowing_mul(&a, &b),
+                    a.overflowing_mul(b)
+                );
+            }
+            assert_eq!(CheckedNeg::checked_neg(&a), a.checked_neg());
+            assert_eq!(WrappingNeg::wrapping_neg(&a), a.wrapping_neg());
+            for shift in [0, 1, 255, 256] {
+                assert_eq!(CheckedShl::checked_shl(&a, shift), a.checked_shl(shift));
+                assert_eq!(CheckedShr::checked_shr(&a, shift), a.checked_shr(shift));
+                assert_eq!(WrappingShl::wrapping_shl(&a, shift), a.wrapping_shl(shift));
+                assert_eq!(WrappingShr::wrapping_shr(&a, shift), a.wrapping_shr(shift));
+            }
+        }
+
+        let (min, max) = (I256::MIN, I256::MAX);
+        assert_eq!(CheckedNeg::checked_neg(&min), None);
+        assert_eq!(WrappingNeg::wrapping_neg(&min), min);
+        assert_eq!(CheckedDiv::checked_div(&min, &I256::MINUS_ONE), None);
+        assert_eq!(SaturatingMul::saturating_mul(&min, &I256::new(2)), min);
+        assert_eq!(SaturatingSub::saturating_sub(&max, &I256::MINUS_ONE), max);
+        assert_eq!(
+            OverflowingAdd::overflowing_add(&max, &I256::ONE),
+            (min, true)
+        );
+        assert_eq!(WrappingShr::wrapping_shr(&min, 255), I256::MINUS_ONE);
+    }
+
+    #[test]
+    fn to_primitive() {
+        macro_rules! assert_boundaries {
+            ($($method:ident => $p:ty),*) => {$(
+                let max = <$p>::MAX.as_u256();
+                assert_eq!(max.$method(), Some(<$p>::MAX));
+                assert_eq!((max + 1).$method(), None);
+                assert_eq!(U256::MAX.$method(), None);
+
+                let (min, max) = (<$p>::MIN.as_i256(), <$p>::MAX.as_i256());
+                assert_eq!(min.$method(), Some(<$p>::MIN));
+                assert_eq!(max.$method(), Some(<$p>::MAX));
+                assert_eq!((min - 1).$method(), None);
+                assert_eq!((max + 1).$method(), None);
+            )*};
+        }
+        assert_boundaries! {
+            to_i8 => i8, to_i16 => i16, to_i32 => i32, to_i64 => i64, to_i128 => i128,
+            to_isize => isize, to_u8 => u8, to_u16 => u16, to_u32 => u32, to_u64 => u64,
+            to_u128 => u128, to_usize => usize
+        }
+        assert_eq!(I256::MINUS_ONE.to_u8(), None);
+        assert_eq!(I256::MINUS_ONE.to_i8(), Some(-1));
+
+        assert_eq!(U256::MAX.to_f64(), Some(U256::MAX.as_f64()));
+        assert_eq!(U256::MAX.to_f32(), None);
+        assert_eq!(I256::MIN.to_f32(), None);
+        assert_eq!(U256::new(u128::MAX).to_f32(), None);
+        assert_eq!(U256::new(f32::MAX as u128).to_f32(), Some(f32::MAX));
+        assert_eq!(
+            U256::new((1 << 64) + (1 << 11)).to_f64(),
+            Some(18446744073709551616.0)
+        );
+        assert_eq!(
+            U256::new((1 << 64) + (1 << 11) + 1).to_f64(),
+            Some(18446744073709555712.0)
+        );
+        assert_eq!(I256::new(-3).to_f64(), Some(-3.0));
+    }
+
+    #[test]
+    fn from_primitive() {
+        for n in [-1i64, i64::MIN] {
+            assert_eq!(U256::from_i64(n), None);
+            assert_eq!(U256::from_i128(n as i128), None);
+            assert_eq!(U256::from_isize(n as isize), None);
+            assert_eq!(I256::from_i64(n), Some(I256::from(n)));
+        }
+        assert_eq!(U256::from_i8(-1), None);
+        assert_eq!(U256::from_i8(i8::MIN), None);
+        assert_eq!(U256::from_u128(u128::MAX), Some(U256::new(u128::MAX)));
+        assert_eq!(I256::from_i128(i128::MIN), Some(I256::new(i128::MIN)));
+        assert_eq!(
+            U256::from_i128(i128::MAX),
+            Some(U256::new(i128::MAX as u128))
+        );
+
+        assert_eq!(U256::from_f64(1.9), Some(U256::ONE));
+        assert_eq!(U256::from_f64(-0.9), Some(U256::ZERO));
+        assert_eq!(U256::from_f64(-1.0), None);
+        assert_eq!(U256::from_f64(f64::NAN), None);
+        assert_eq!(U256::from_f64(f64::INFINITY), None);
+        assert_eq!(U256::from_f64(U256::MAX.as_f64()), None);
+        assert_eq!(I256::from_f64(-1.9), Some(I256::MINUS_ONE));
+        assert_eq!(I256::from_f64(I256::MIN.as_f64()), Some(I256::MIN));
+        assert_eq!(I256::from_f64(-I256::MIN.as_f64()), None);
+        assert_eq!(I256::from_f32(f32::MAX), Some(f32::MAX.as_i256()));
+        assert_eq!(U256::from_f32(f32::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn pow() {
+        let two = U256::new(2);
+        assert_eq!(Pow::pow(two, 255u32), U256::ONE << 255u32);
+        assert_eq!(Pow::pow(&two, &8u32), U256::new(256));
+        let minus_three = I256::new(-3);
+        assert_eq!(Pow::pow(&minus_three, 3u32), I256::new(-27));
+        assert_eq!(Pow::pow(minus_three, &0u32), I256::ONE);
+    }
+}
@@ -0,0 +1,81 @@
+//! Module that implements support for the `uint` crate's
+//! [`construct_uint!`](https://docs.rs/uint/latest/uint/macro.construct_uint.html)
+//! types, enabled with the `parity-uint` feature.
+//!
+//! Since these types are defined in downstream crates, conversions can't be
+//! implemented here directly. Instead, a macro is provided that generates them.
+
+/// Implements `From` conversions in both directions between [`U256`] and a
+/// 256-bit `uint::construct_uint!` type.
+///
+/// The conversions go through the little endian `[u64; 4]` limb layout used
+/// by `construct_uint!` types, so they are lossless and independent of the
+/// target platform's endianness.
+///
+/// # Examples
+///
+/// ```
+/// # use ethnum::U256;
+/// uint::construct_uint! {
+///     pub struct ParityU256(4);
+/// }
+/// ethnum::impl_uint_conversions!(ParityU256);
+///
+/// let x = U256::from_words(1, 2);
+/// let y = ParityU256::from(x);
+/// assert_eq!(y, (ParityU256::from(1) << 128) + 2);
+/// assert_eq!(U256::from(y), x);
+/// ```
+///
+/// [`U256`]: crate::U256
+#[macro_export]
+macro_rules! impl_uint_conversions {
+    ($t:ty) => {
+        impl ::core::convert::From<$t> for $crate::U256 {
+            #[inline]
+            fn from(value: $t) -> Self {
+                $crate::U256::from_limbs(value.0)
+            }
+        }
+
+        impl ::core::convert::From<$crate::U256> for $t {
+            #[inline]
+            fn from(value: $crate::U256) -> Self {
+                Self($crate::U256::into_limbs(value))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::U256;
+
+    #[allow(clippy::all)]
+    mod parity {
+        uint::construct_uint! {
+            pub struct ParityU256(4);
+        }
+    }
+    use parity::ParityU256;
+
+    impl_uint_conversions!(ParityU256);
+
+    #[test]
+    fn limb_boundaries() {
+        for shift in [0u32, 63, 64, 127, 128, 191, 192, 255] {
+            for x in [
+                U256::ONE << shift,
+                (U256::ONE << shift) - 1,
+                (U256::ONE << shift).wrapping_add(U256::ONE),
+            ] {
+                let p = ParityU256::from(x);
+                assert_eq!(p, ParityU256::from_big_endian(&x.to_be_bytes()));
+                assert_eq!(U256::from(p), x);
+            }
+        }
+
+        assert_eq!(ParityU256::from(U256::MAX), ParityU256::MAX);
+        assert_eq!(U256::from(ParityU256::zero()), U256::ZERO);
+    }
+}
@@ -0,0 +1,342 @@
+//! Module containing the encoding of 256-bit integers as PostgreSQL `NUMERIC`
+//! values that is shared by the PostgreSQL integrations.
+//!
+//! The binary wire format of a `NUMERIC` is a header with the number of
+//! digits, the weight of the first digit, the sign and the display scale,
+//! followed by base 10000 digits from most to least significant. Values with
+//! fractional digits, `NaN` and infinities are rejected when decoding.
+
+use crate::{I256, U256};
+use core::ops::Deref;
+
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xc000;
+const NUMERIC_PINF: u16 = 0xd000;
+const NUMERIC_NINF: u16 = 0xf000;
+
+/// The base of `NUMERIC` digits.
+const NBASE: u64 = 10000;
+
+/// The maximum number of base 10000 digits of a 256-bit magnitude.
+const MAX_DIGITS: usize = 20;
+
+const ERR_NAN: &str = "cannot convert NaN or infinite NUMERIC to a 256-bit integer";
+const ERR_FRACTIONAL: &str = "cannot convert NUMERIC with a fractional part to an integer";
+const ERR_NEGATIVE: &str = "cannot convert negative NUMERIC to an unsigned 256-bit integer";
+const ERR_OUT_OF_RANGE: &str = "NUMERIC value out of range for a 256-bit integer";
+
+/// 256-bit integer types that can be converted to and from `NUMERIC` values.
+pub(crate) trait Numeric: Sized {
+    /// Returns the sign and magnitude of the integer.
+    fn to_parts(&self) -> (bool, U256);
+
+    /// Creates an integer from its sign and magnitude.
+    fn from_parts(negative: bool, magnitude: U256) -> Result<Self, &'static str>;
+}
+
+impl Numeric for U256 {
+    fn to_parts(&self) -> (bool, U256) {
+        (false, *self)
+    }
+
+    fn from_parts(negative: bool, magnitude: U256) -> Result<Self, &'static str> {
+        if negative && magnitude != 0 {
+            return Err(ERR_NEGATIVE);
+        }
+        Ok(magnitude)
+    }
+}
+
+impl Numeric for I256 {
+    fn to_parts(&self) -> (bool, U256) {
+        (self.is_negative(), self.unsigned_abs())
+    }
+
+    fn from_parts(negative: bool, magnitude: U256) -> Result<Self, &'static str> {
+        let negative = negative && magnitude != 0;
+        let value = if negative {
+            magnitude.as_i256().wrapping_neg()
+        } else {
+            magnitude.as_i256()
+        };
+        if value.is_negative() != negative {
+            return Err(ERR_OUT_OF_RANGE);
+        }
+        Ok(value)
+    }
+}
+
+/// A buffer containing a binary `NUMERIC` value.
+pub(crate) struct Buffer {
+    bytes: [u8; 8 + 2 * MAX_DIGITS],
+    len: usize,
+}
+
+impl Deref for Buffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes[..self.len]
+    }
+}
+
+/// Encodes an integer as a binary `NUMERIC` value.
+pub(crate) fn encode<T: Numeric>(value: &T) -> Buffer {
+    let (negative, mut magnitude) = value.to_parts();
+
+    // Collect digits from least to most significant.
+    let mut digits = [0u16; MAX_DIGITS];
+    let mut len = 0;
+    while magnitude != 0 {
+        let (q, r) = magnitude.div_rem_u64(NBASE);
+        digits[len] = r as _;
+        len += 1;
+        magnitude = q;
+    }
+
+    // Trailing zero digits are implied by the weight, so omit them like
+    // PostgreSQL does.
+    let trailing = digits[..len].iter().take_while(|&&d| d == 0).count();
+    let weight = len.saturating_sub(1);
+    let sign = if negative && len > 0 {
+        NUMERIC_NEG
+    } else {
+        NUMERIC_POS
+    };
+
+    let mut buffer = Buffer {
+        bytes: [0; 8 + 2 * MAX_DIGITS],
+        len: 0,
+    };
+    let fields = [(len - trailing) as u16, weight as u16, sign, 0]
+        .into_iter()
+        .chain(digits[trailing..len].iter().rev().copied());
+    for (chunk, field) in buffer.bytes.chunks_exact_mut(2).zip(fields) {
+        chunk.copy_from_slice(&field.to_be_bytes());
+        buffer.len += 2;
+    }
+    buffer
+}
+
+/// Decodes an integer from a binary `NUMERIC` value.
+pub(crate) fn decode<T: Numeric>(raw: &[u8]) -> Result<T, &'static str> {
+    let field = |i: usize| u16::from_be_bytes([raw[2 * i], raw[2 * i + 1]]);
+    if raw.len() < 8 {
+        return Err("invalid buffer size");
+    }
+    let (ndigits, weight, sign) = (field(0) as usize, field(1) as i16, field(2));
+    if raw.len() != 8 + 2 * ndigits {
+        return Err("invalid buffer size");
+    }
+    let negative = match sign {
+        NUMERIC_POS => false,
+        NUMERIC_NEG => true,
+        NUMERIC_NAN | NUMERIC_PINF | NUMERIC_NINF => return Err(ERR_NAN),
+        _ => return Err("invalid NUMERIC sign"),
+    };
+
+    let mut magnitude = U256::ZERO;
+    for i in 0..ndigits {
+        let digit = field(4 + i);
+        if u64::from(digit) >= NBASE {
+            return Err("invalid NUMERIC digit");
+        }
+        if i as i32 > weight as i32 {
+            if digit != 0 {
+                return Err(ERR_FRACTIONAL);
+            }
+            continue;
+        }
+        magnitude = magnitude
+            .checked_mul_add_u64(NBASE, digit.into())
+            .ok_or(ERR_OUT_OF_RANGE)?;
+    }
+    if magnitude != 0 {
+        for _ in ndigits as i32..=weight as i32 {
+            magnitude = magnitude
+                .checked_mul_add_u64(NBASE, 0)
+                .ok_or(ERR_OUT_OF_RANGE)?;
+        }
+    }
+
+    T::from_parts(negative, magnitude)
+}
+
+/// Decodes an integer from a textual `NUMERIC` value, such as `-42` or
+/// `1000.00`.
+#[cfg(feature = "sqlx-postgres")]
+pub(crate) fn decode_text<T: Numeric>(text: &str) -> Result<T, &'static str> {
+    if matches!(text, "NaN" | "Infinity" | "-Infinity") {
+        return Err(ERR_NAN);
+    }
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let digits = match text.split_once('.') {
+        Some((_, fraction)) if fraction.bytes().any(|b| b != b'0') => {
+            return Err(ERR_FRACTIONAL);
+        }
+        Some((digits, _)) => digits,
+        None => text,
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("invalid NUMERIC text value");
+    }
+    let magnitude = U256::from_str_radix(digits, 10).map_err(|_| ERR_OUT_OF_RANGE)?;
+    T::from_parts(negative, magnitude)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn numeric(weight: i16, sign: u16, dscale: u16, digits: &[u16]) -> Vec<u8> {
+        let mut raw = Vec::new();
+        for field in [digits.len() as u16, weight as u16, sign, dscale]
+            .iter()
+            .chain(digits)
+        {
+            raw.extend_from_slice(&field.to_be_bytes());
+        }
+        raw
+    }
+
+    #[test]
+    fn binary_fixtures() {
+        for (value, raw) in [
+            (U256::ZERO, &[0, 0, 0, 0, 0, 0, 0, 0][..]),
+            (U256::new(1), &[0, 1, 0, 0, 0, 0, 0, 0, 0, 1]),
+            (U256::new(10000), &[0, 1, 0, 1, 0, 0, 0, 0, 0, 1]),
+            (
+                U256::new(12345678),
+                &[0, 2, 0, 1, 0, 0, 0, 0, 0x04, 0xd2, 0x16, 0x2e],
+            ),
+        ] {
+            assert_eq!(&*encode(&value), raw);
+            assert_eq!(decode::<U256>(raw).unwrap(), value);
+        }
+
+        let max = numeric(
+            19,
+            NUMERIC_POS,
+            0,
+            &[
+                11, 5792, 892, 3731, 6195, 4235, 7098, 5008, 6879, 785, 3269, 9846, 6564, 564, 394,
+                5758, 4007, 9131, 2963, 9935,
+            ],
+        );
+        assert_eq!(*encode(&U256::MAX), max);
+        assert_eq!(decode::<U256>(&max).unwrap(), U256::MAX);
+
+        let min = numeric(
+            19,
+            NUMERIC_NEG,
+            0,
+            &[
+                5, 7896, 446, 1865, 8097, 7117, 8549, 2504, 3439, 5392, 6634, 9923, 3282, 282, 197,
+                2879, 2003, 9565, 6481, 9968,
+            ],
+        );
+        assert_eq!(*encode(&I256::MIN), min);
+        assert_eq!(decode::<I256>(&min).unwrap(), I256::MIN);
+
+        let minus_one = [0, 1, 0, 0, 0x40, 0, 0, 0, 0, 1];
+        assert_eq!(*encode(&I256::MINUS_ONE), minus_one);
+        assert_eq!(decode::<I256>(&minus_one).unwrap(), I256::MINUS_ONE);
+    }
+
+    #[test]
+    fn round_trips() {
+        for value in [
+            U256::new(42),
+            U256::new(1_0000_0000),
+            U256::new(u128::MAX),
+            U256::MAX / 3,
+            U256::ONE << 255u32,
+        ] {
+            assert_eq!(decode::<U256>(&encode(&value)).unwrap(), value);
+        }
+        for value in [I256::MAX, I256::new(-1_0000), I256::new(i128::MIN)] {
+            assert_eq!(decode::<I256>(&encode(&value)).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn decoding() {
+        // `5.00` has a display scale but no fractional digits.
+        let raw = numeric(0, NUMERIC_POS, 2, &[5]);
+        assert_eq!(decode::<U256>(&raw).unwrap(), 5);
+        // `1.0000` with an explicit zero fractional digit.
+        let raw = numeric(0, NUMERIC_POS, 4, &[1, 0]);
+        assert_eq!(decode::<U256>(&raw).unwrap(), 1);
+        // `1e8` with implied trailing zero digits.
+        let raw = numeric(2, NUMERIC_POS, 0, &[1]);
+        assert_eq!(decode::<U256>(&raw).unwrap(), 1_0000_0000);
+        // Negative zero is decoded as zero.
+        let raw = numeric(0, NUMERIC_NEG, 0, &[]);
+        assert_eq!(decode::<U256>(&raw).unwrap(), 0);
+
+        for (raw, err) in [
+            (numeric(0, NUMERIC_POS, 1, &[1, 5000]), ERR_FRACTIONAL),
+            (numeric(-1, NUMERIC_POS, 4, &[1]), ERR_FRACTIONAL),
+            (numeric(0, NUMERIC_NAN, 0, &[]), ERR_NAN),
+            (numeric(0, NUMERIC_PINF, 0, &[]), ERR_NAN),
+            (numeric(0, NUMERIC_NEG, 0, &[1]), ERR_NEGATIVE),
+            (numeric(20, NUMERIC_POS, 0, &[1]), ERR_OUT_OF_RANGE),
+            (
+                numeric(0, NUMERIC_POS, 0, &[10000]),
+                "invalid NUMERIC digit",
+            ),
+            (numeric(0, 0x1234, 0, &[1]), "invalid NUMERIC sign"),
+            (alloc::vec![0, 1, 0, 0, 0, 0, 0, 0], "invalid buffer size"),
+            (alloc::vec![0, 0, 0], "invalid buffer size"),
+        ] {
+            assert_eq!(decode::<U256>(&raw).unwrap_err(), err);
+        }
+
+        let max = encode(&(I256::MAX.as_u256() + 1));
+        assert_eq!(decode::<I256>(&max).unwrap_err(), ERR_OUT_OF_RANGE);
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    #[test]
+    fn text_decoding() {
+        assert_eq!(decode_text::<U256>("0").unwrap(), 0);
+        assert_eq!(decode_text::<U256>("1000.000").unwrap(), 1000);
+        assert_eq!(
+            decode_text::<U256>(&alloc::format!("{}", U256::MAX)).unwrap(),
+            U256::MAX
+        );
+        assert_eq!(
+            decode_text::<I256>(&alloc::format!("{}", I256::MIN)).unwrap(),
+            I256::MIN
+        );
+        assert_eq!(decode_text::<I256>("-42.0").unwrap(), -42);
+
+        for (text, err) in [
+            ("1.5", ERR_FRACTIONAL),
+            ("NaN", ERR_NAN),
+            ("-Infinity", ERR_NAN),
+            ("-1", ERR_NEGATIVE),
+            (
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+                ERR_OUT_OF_RANGE,
+            ),
+            ("", "invalid NUMERIC text value"),
+            ("+1", "invalid NUMERIC text value"),
+            ("1e3", "invalid NUMERIC text value"),
+        ] {
+            assert_eq!(decode_text::<U256>(text).unwrap_err(), err);
+        }
+        assert_eq!(
+            decode_text::<I256>(
+                "57896044618658097711785492504343953926634992332820282019728792003956564819968"
+            )
+            .unwrap_err(),
+            ERR_OUT_OF_RANGE
+        );
+    }
+}
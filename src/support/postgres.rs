@@ -0,0 +1,113 @@
+//! Module that implements support for the
+//! [`postgres-types`](https://crates.io/crates/postgres-types) crate, which
+//! provides the `ToSql` and `FromSql` traits used by `postgres` and
+//! `tokio-postgres`.
+//!
+//! The 256-bit integer types are mapped to the `NUMERIC` type, using its binary
+//! wire format. Values with fractional digits, `NaN` and infinities are
+//! rejected when decoding.
+
+use super::pg_numeric;
+use crate::{I256, U256};
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::{boxed::Box, error::Error};
+
+macro_rules! impl_postgres {
+    ($($t:ident),*) => {$(
+        impl ToSql for $t {
+            fn to_sql(
+                &self,
+                _: &Type,
+                out: &mut BytesMut,
+            ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+                out.extend_from_slice(&pg_numeric::encode(self));
+                Ok(IsNull::No)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                *ty == Type::NUMERIC
+            }
+
+            to_sql_checked!();
+        }
+
+        impl<'a> FromSql<'a> for $t {
+            fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                Ok(pg_numeric::decode(raw)?)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                *ty == Type::NUMERIC
+            }
+        }
+    )*};
+}
+
+impl_postgres!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{string::ToString, vec::Vec};
+
+    fn to_sql<T: ToSql>(value: T) -> Vec<u8> {
+        let mut out = BytesMut::new();
+        value.to_sql_checked(&Type::NUMERIC, &mut out).unwrap();
+        out.to_vec()
+    }
+
+    #[test]
+    fn binary_fixtures() {
+        for (value, raw) in [
+            (U256::ZERO, &[0, 0, 0, 0, 0, 0, 0, 0][..]),
+            (
+                U256::new(12345678),
+                &[0, 2, 0, 1, 0, 0, 0, 0, 0x04, 0xd2, 0x16, 0x2e],
+            ),
+        ] {
+            assert_eq!(to_sql(value), raw);
+            assert_eq!(U256::from_sql(&Type::NUMERIC, raw).unwrap(), value);
+        }
+
+        let minus_one = [0, 1, 0, 0, 0x40, 0, 0, 0, 0, 1];
+        assert_eq!(to_sql(I256::MINUS_ONE), minus_one);
+        assert_eq!(
+            I256::from_sql(&Type::NUMERIC, &minus_one).unwrap(),
+            I256::MINUS_ONE
+        );
+        assert_eq!(
+            U256::from_sql(&Type::NUMERIC, &minus_one)
+                .unwrap_err()
+                .to_string(),
+            "cannot convert negative NUMERIC to an unsigned 256-bit integer"
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for value in [U256::new(42), U256::MAX / 3, U256::MAX] {
+            assert_eq!(
+                U256::from_sql(&Type::NUMERIC, &to_sql(value)).unwrap(),
+                value
+            );
+        }
+        for value in [I256::MIN, I256::new(-1_0000), I256::MAX] {
+            assert_eq!(
+                I256::from_sql(&Type::NUMERIC, &to_sql(value)).unwrap(),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn accepts_numeric() {
+        assert!(<U256 as ToSql>::accepts(&Type::NUMERIC));
+        assert!(<I256 as FromSql>::accepts(&Type::NUMERIC));
+        assert!(!<U256 as FromSql>::accepts(&Type::INT8));
+        assert!(!<I256 as ToSql>::accepts(&Type::TEXT));
+
+        let mut out = BytesMut::new();
+        assert!(U256::ONE.to_sql_checked(&Type::INT8, &mut out).is_err());
+    }
+}
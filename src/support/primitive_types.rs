@@ -0,0 +1,98 @@
+//! Module that implements conversions to and from the
+//! [`primitive-types`](https://crates.io/crates/primitive-types) crate's types.
+//!
+//! Hashes (`H256`) are interpreted as big endian numbers.
+
+use crate::{error, U256};
+use core::num::TryFromIntError;
+use primitive_types::{H256, U512};
+
+impl From<primitive_types::U256> for U256 {
+    fn from(value: primitive_types::U256) -> Self {
+        let mut bytes = [0; 32];
+        value.to_big_endian(&mut bytes);
+        U256::from_be_bytes(bytes)
+    }
+}
+
+impl From<U256> for primitive_types::U256 {
+    fn from(value: U256) -> Self {
+        primitive_types::U256::from_big_endian(&value.to_be_bytes())
+    }
+}
+
+impl TryFrom<U512> for U256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: U512) -> Result<Self, Self::Error> {
+        let mut bytes = [0; 64];
+        value.to_big_endian(&mut bytes);
+        let (hi, lo) = bytes.split_at(32);
+        if hi.iter().any(|&b| b != 0) {
+            return Err(error::tfie());
+        }
+
+        let mut bytes = [0; 32];
+        bytes.copy_from_slice(lo);
+        Ok(U256::from_be_bytes(bytes))
+    }
+}
+
+impl From<U256> for U512 {
+    fn from(value: U256) -> Self {
+        let mut bytes = [0; 64];
+        bytes[32..].copy_from_slice(&value.to_be_bytes());
+        U512::from_big_endian(&bytes)
+    }
+}
+
+impl From<H256> for U256 {
+    fn from(value: H256) -> Self {
+        U256::from_be_bytes(value.to_fixed_bytes())
+    }
+}
+
+impl From<U256> for H256 {
+    fn from(value: U256) -> Self {
+        H256(value.to_be_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn conversions() {
+        let x = U256::from_words(0x0102030405060708090a0b0c0d0e0f10, 42);
+        let p = primitive_types::U256::from(x);
+        assert_eq!(p.0, x.into_limbs());
+        assert_eq!(U256::from(p), x);
+
+        let h = H256::from(x);
+        assert_eq!(h.0, x.to_be_bytes());
+        assert_eq!(h.0[0], 0x01);
+        assert_eq!(U256::from(h), x);
+
+        assert_eq!(U256::try_from(U512::from(x)).unwrap(), x);
+        assert_eq!(U256::try_from(U512::from(U256::MAX)).unwrap(), U256::MAX);
+        assert!(U256::try_from(U512::from(U256::MAX) + 1).is_err());
+        assert!(U256::try_from(U512::MAX).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(bytes in any::<[u8; 32]>()) {
+            let x = U256::from_be_bytes(bytes);
+            prop_assert_eq!(U256::from(primitive_types::U256::from(x)), x);
+            prop_assert_eq!(U256::from(H256::from(x)), x);
+            prop_assert_eq!(U256::try_from(U512::from(x)).unwrap(), x);
+
+            let p = primitive_types::U256::from_big_endian(&bytes);
+            prop_assert_eq!(primitive_types::U256::from(U256::from(p)), p);
+            let h = H256(bytes);
+            prop_assert_eq!(H256::from(U256::from(h)), h);
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Module that implements support for the [`proptest`](https://crates.io/crates/proptest) crate.
 
 use proptest::{
-    arbitrary::Arbitrary, strategy::{BoxedStrategy, Strategy}, array::uniform2,
+    arbitrary::Arbitrary,
+    array::uniform2,
+    strategy::{BoxedStrategy, Strategy},
 };
 
 use crate::{I256, U256};
@@ -11,7 +13,9 @@ impl Arbitrary for U256 {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(_: Self::Parameters) -> BoxedStrategy<Self> {
-        uniform2(u128::arbitrary()).prop_map(|[hi, lo]| U256::from_words(hi, lo)).boxed()
+        uniform2(u128::arbitrary())
+            .prop_map(|[hi, lo]| U256::from_words(hi, lo))
+            .boxed()
     }
 }
 
@@ -20,6 +24,8 @@ impl Arbitrary for I256 {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(_: Self::Parameters) -> BoxedStrategy<Self> {
-        uniform2(i128::arbitrary()).prop_map(|[hi, lo]| I256::from_words(hi, lo)).boxed()
+        uniform2(i128::arbitrary())
+            .prop_map(|[hi, lo]| I256::from_words(hi, lo))
+            .boxed()
     }
-}
\ No newline at end of file
+}
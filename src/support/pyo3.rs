@@ -0,0 +1,136 @@
+//! Module that implements support for the [`pyo3`](https://crates.io/crates/pyo3)
+//! crate.
+//!
+//! The 256-bit integer types convert losslessly to and from Python's
+//! arbitrary-precision `int`, through its `from_bytes` and `to_bytes` methods
+//! with 32 little endian bytes. This also works with the limited Python API
+//! (`abi3`) and PyPy.
+//!
+//! Extracting a value raises `OverflowError` if the Python integer is out of
+//! range, including negative integers for `U256`, and `TypeError` if the
+//! object is not an `int`.
+
+use crate::{I256, U256};
+use pyo3::{
+    types::{IntoPyDict as _, PyAnyMethods as _, PyBytes, PyBytesMethods as _, PyLong},
+    Bound, FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject,
+};
+
+macro_rules! impl_pyo3 {
+    ($($t:ident => $signed:literal),*) => {$(
+        impl ToPyObject for $t {
+            fn to_object(&self, py: Python<'_>) -> PyObject {
+                let bytes = PyBytes::new_bound(py, &self.to_le_bytes());
+                let kwargs = [("signed", $signed)].into_py_dict_bound(py);
+                py.get_type_bound::<PyLong>()
+                    .call_method("from_bytes", (bytes, "little"), Some(&kwargs))
+                    .expect("failed to convert bytes to a Python int")
+                    .unbind()
+            }
+        }
+
+        impl IntoPy<PyObject> for $t {
+            fn into_py(self, py: Python<'_>) -> PyObject {
+                self.to_object(py)
+            }
+        }
+
+        impl FromPyObject<'_> for $t {
+            fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+                let kwargs = [("signed", $signed)].into_py_dict_bound(ob.py());
+                let bytes = ob
+                    .downcast::<PyLong>()?
+                    .call_method("to_bytes", (32, "little"), Some(&kwargs))?;
+                let bytes = bytes.downcast::<PyBytes>()?.as_bytes();
+                Ok($t::from_le_bytes(
+                    bytes.try_into().expect("int.to_bytes returned the wrong length"),
+                ))
+            }
+        }
+    )*};
+}
+
+impl_pyo3!(U256 => false, I256 => true);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::exceptions::{PyOverflowError, PyTypeError};
+    use std::{format, string::ToString};
+
+    fn with_gil(f: impl FnOnce(Python<'_>)) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(f);
+    }
+
+    #[test]
+    fn round_trips() {
+        with_gil(|py| {
+            for value in [U256::ZERO, U256::new(42), U256::from_words(1, 0), U256::MAX] {
+                let object = value.to_object(py);
+                assert_eq!(object.to_string(), value.to_string());
+                assert_eq!(object.extract::<U256>(py).unwrap(), value);
+            }
+            for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+                let object = value.into_py(py);
+                assert_eq!(object.to_string(), value.to_string());
+                assert_eq!(object.extract::<I256>(py).unwrap(), value);
+            }
+
+            let max = py.eval_bound("2**256 - 1", None, None).unwrap();
+            assert_eq!(max.extract::<U256>().unwrap(), U256::MAX);
+            let min = py.eval_bound("-2**255", None, None).unwrap();
+            assert_eq!(min.extract::<I256>().unwrap(), I256::MIN);
+            assert!(min.eq(I256::MIN.to_object(py)).unwrap());
+        });
+    }
+
+    #[test]
+    fn range_errors() {
+        with_gil(|py| {
+            for (expr, u256_overflows, i256_overflows) in [
+                ("-1", true, false),
+                ("2**255", false, true),
+                ("-2**255 - 1", true, true),
+                ("2**256", true, true),
+            ] {
+                let value = py.eval_bound(expr, None, None).unwrap();
+                let err = value
+                    .extract::<U256>()
+                    .map(|_| ())
+                    .map_err(|e| e.is_instance_of::<PyOverflowError>(py));
+                assert_eq!(
+                    err,
+                    if u256_overflows { Err(true) } else { Ok(()) },
+                    "{expr}"
+                );
+                let err = value
+                    .extract::<I256>()
+                    .map(|_| ())
+                    .map_err(|e| e.is_instance_of::<PyOverflowError>(py));
+                assert_eq!(
+                    err,
+                    if i256_overflows { Err(true) } else { Ok(()) },
+                    "{expr}"
+                );
+            }
+
+            let err = py
+                .eval_bound("-1", None, None)
+                .unwrap()
+                .extract::<U256>()
+                .unwrap_err();
+            assert_eq!(
+                format!("{err}"),
+                "OverflowError: can't convert negative int to unsigned"
+            );
+
+            let err = py
+                .eval_bound("'1'", None, None)
+                .unwrap()
+                .extract::<U256>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+        });
+    }
+}
@@ -0,0 +1,156 @@
+//! Module that implements support for the [`quickcheck`](https://crates.io/crates/quickcheck)
+//! crate.
+//!
+//! Values are generated from two arbitrary 128-bit words, so the edge cases
+//! that `quickcheck` favours for `u128` and `i128` (zero, one, the minimum and
+//! maximum values) show up in each word independently.
+//!
+//! Shrinking moves towards small, readable numbers: the candidates are zero,
+//! the value with its high word cleared, successive halvings of the value, and
+//! finally the value one step closer to zero. For `I256`, negative values also
+//! try their absolute value.
+
+use crate::{I256, U256};
+use core::iter;
+use quickcheck::{empty_shrinker, Arbitrary, Gen};
+use std::boxed::Box;
+
+impl Arbitrary for U256 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        U256::from_words(u128::arbitrary(g), u128::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let value = *self;
+        if value == U256::ZERO {
+            return empty_shrinker();
+        }
+
+        let (hi, lo) = value.into_words();
+        Box::new(
+            iter::once(U256::ZERO)
+                .chain((hi != 0 && lo != 0).then(|| U256::new(lo)))
+                .chain(
+                    iter::successors(Some(value >> 1u32), |x| Some(*x >> 1u32))
+                        .take_while(|x| *x != 0),
+                )
+                .chain((value > 2).then(|| value - 1)),
+        )
+    }
+}
+
+impl Arbitrary for I256 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        I256::from_words(i128::arbitrary(g), i128::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let value = *self;
+        if value == I256::ZERO {
+            return empty_shrinker();
+        }
+
+        let low = I256::new(value.as_i128());
+        Box::new(
+            iter::once(I256::ZERO)
+                .chain((value.is_negative() && value != I256::MIN).then(|| -value))
+                .chain((low != I256::ZERO && low != value).then_some(low))
+                .chain(iter::successors(Some(value / 2), |x| Some(*x / 2)).take_while(|x| *x != 0))
+                .chain((value.unsigned_abs() > 2).then(|| value - value.signum())),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::{quickcheck, TestResult};
+    use std::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    #[test]
+    fn add_sub_round_trip() {
+        fn prop_u256(a: U256, b: U256) -> bool {
+            a.wrapping_add(b).wrapping_sub(b) == a
+        }
+        fn prop_i256(a: I256, b: I256) -> bool {
+            a.wrapping_add(b).wrapping_sub(b) == a
+        }
+
+        quickcheck(prop_u256 as fn(U256, U256) -> bool);
+        quickcheck(prop_i256 as fn(I256, I256) -> bool);
+    }
+
+    #[test]
+    fn parse_format_round_trip() {
+        fn prop_u256(a: U256) -> bool {
+            a.to_string().parse::<U256>() == Ok(a)
+                && U256::from_str_hex(&format!("{a:#x}")) == Ok(a)
+        }
+        fn prop_i256(a: I256) -> bool {
+            a.to_string().parse::<I256>() == Ok(a)
+        }
+
+        quickcheck(prop_u256 as fn(U256) -> bool);
+        quickcheck(prop_i256 as fn(I256) -> bool);
+    }
+
+    #[test]
+    fn shrink_u256() {
+        let value = U256::from_words(1, 6);
+        assert_eq!(
+            value.shrink().collect::<Vec<_>>()[..4],
+            [
+                U256::ZERO,
+                U256::new(6),
+                U256::from_words(0, (1 << 127) | 3),
+                U256::from_words(0, (1 << 126) | 1),
+            ]
+        );
+        assert!(value.shrink().all(|x| x < value));
+        assert_eq!(value.shrink().last(), Some(value - 1));
+
+        assert_eq!(
+            U256::new(12).shrink().collect::<Vec<_>>(),
+            [0, 6, 3, 1, 11].map(U256::new)
+        );
+        assert_eq!(U256::ONE.shrink().collect::<Vec<_>>(), [U256::ZERO]);
+        assert_eq!(U256::ZERO.shrink().count(), 0);
+    }
+
+    #[test]
+    fn shrink_i256() {
+        assert_eq!(
+            I256::new(-12).shrink().collect::<Vec<_>>(),
+            [0, 12, -6, -3, -1, -11].map(I256::new)
+        );
+        assert_eq!(
+            I256::from_words(-2, 5).shrink().take(3).collect::<Vec<_>>(),
+            [I256::ZERO, I256::from_words(1, -5), I256::new(5)]
+        );
+        assert!(I256::MIN
+            .shrink()
+            .all(|x| x.unsigned_abs() < I256::MIN.unsigned_abs()));
+        assert_eq!(
+            I256::MINUS_ONE.shrink().collect::<Vec<_>>(),
+            [I256::ZERO, I256::ONE]
+        );
+        assert_eq!(I256::ZERO.shrink().count(), 0);
+    }
+
+    #[test]
+    fn shrinks_failures() {
+        // A property that fails for all large values minimizes to the
+        // smallest counterexample instead of a 77 digit number.
+        fn prop(a: U256) -> TestResult {
+            TestResult::from_bool(a < 1000)
+        }
+
+        let result = std::panic::catch_unwind(|| quickcheck(prop as fn(U256) -> TestResult));
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("(1000)"), "{message}");
+    }
+}
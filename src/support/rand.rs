@@ -0,0 +1,246 @@
+//! Module that implements support for the [`rand`](https://crates.io/crates/rand)
+//! crate.
+//!
+//! The [`Standard`] distribution samples 256-bit integers uniformly over their
+//! entire range, so `rng.gen::<U256>()` and `rng.gen::<I256>()` work with any
+//! random number generator. This does not require the `std` feature of `rand`.
+//!
+//! The types also implement [`SampleUniform`], so they can be used with
+//! `rng.gen_range(..)` and [`Uniform`](rand::distributions::Uniform). Values
+//! are sampled without modulo bias through the [`UniformU256`] and
+//! [`UniformI256`] samplers.
+
+use crate::{AsU256, I256, U256};
+use rand::{
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformSampler},
+        Distribution, Standard,
+    },
+    Rng,
+};
+
+macro_rules! impl_rand {
+    ($($t:ident),*) => {$(
+        impl Distribution<$t> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                let mut bytes = [0; 32];
+                rng.fill_bytes(&mut bytes);
+                $t::from_le_bytes(bytes)
+            }
+        }
+    )*};
+}
+
+impl_rand!(U256, I256);
+
+macro_rules! impl_rand_uniform {
+    ($($t:ident => $sampler:ident, $as:ident;)*) => {$(
+        #[doc = concat!("The back-end for sampling uniformly distributed [`", stringify!($t), "`] values.")]
+        ///
+        /// Values are sampled by rejection: random values are masked to the
+        /// bit width of the range and rejected when they are past its end, so
+        /// fewer than two random values are needed on average.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub struct $sampler {
+            low: $t,
+            max_offset: U256,
+            mask: U256,
+        }
+
+        impl SampleUniform for $t {
+            type Sampler = $sampler;
+        }
+
+        impl UniformSampler for $sampler {
+            type X = $t;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<$t> + Sized,
+                B2: SampleBorrow<$t> + Sized,
+            {
+                let (low, high) = (*low.borrow(), *high.borrow());
+                assert!(low < high, "Uniform::new called with `low >= high`");
+                Self::new_inclusive(low, high - 1)
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<$t> + Sized,
+                B2: SampleBorrow<$t> + Sized,
+            {
+                let (low, high) = (*low.borrow(), *high.borrow());
+                assert!(low <= high, "Uniform::new_inclusive called with `low > high`");
+                let max_offset = high.wrapping_sub(low).as_u256();
+                Self {
+                    low,
+                    max_offset,
+                    mask: U256::MAX.checked_shr(max_offset.leading_zeros()).unwrap_or_default(),
+                }
+            }
+
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                loop {
+                    let offset = rng.gen::<U256>() & self.mask;
+                    if offset <= self.max_offset {
+                        return self.low.wrapping_add(offset.$as());
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+impl_rand_uniform! {
+    U256 => UniformU256, as_u256;
+    I256 => UniformI256, as_i256;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{distributions::Uniform, rngs::StdRng, RngCore, SeedableRng};
+
+    #[test]
+    fn bits_are_balanced() {
+        const SAMPLES: u32 = 10_000;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = [0u32; 256];
+        for _ in 0..SAMPLES {
+            let value = rng.gen::<U256>();
+            for (bit, count) in counts.iter_mut().enumerate() {
+                if value & (U256::ONE << bit as u32) != 0 {
+                    *count += 1;
+                }
+            }
+        }
+
+        // The standard deviation of each count is 50, so this is an 8 sigma
+        // bound which a correctly seeded generator will never exceed.
+        for count in counts {
+            assert!(count.abs_diff(SAMPLES / 2) < 400, "{count}");
+        }
+    }
+
+    #[test]
+    fn signed_values_are_balanced() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let negative = (0..10_000)
+            .filter(|_| rng.gen::<I256>().is_negative())
+            .count();
+        assert!(negative.abs_diff(5_000) < 400, "{negative}");
+    }
+
+    #[test]
+    fn deterministic() {
+        let mut a = StdRng::seed_from_u64(1337);
+        let mut b = StdRng::seed_from_u64(1337);
+        for _ in 0..16 {
+            assert_eq!(a.gen::<U256>(), b.gen::<U256>());
+            assert_eq!(a.gen::<I256>(), b.gen::<I256>());
+        }
+
+        // Sampling is equivalent to filling 32 bytes from the generator.
+        let mut rng = StdRng::seed_from_u64(1337);
+        let mut bytes = [0; 32];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(
+            StdRng::seed_from_u64(1337).gen::<U256>(),
+            U256::from_le_bytes(bytes)
+        );
+        rng.fill_bytes(&mut bytes);
+        let mut other = StdRng::seed_from_u64(1337);
+        other.gen::<U256>();
+        assert_eq!(other.gen::<I256>(), I256::from_le_bytes(bytes));
+    }
+
+    #[test]
+    fn uniform_in_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let max = U256::MAX;
+        for (low, high) in [
+            (U256::ZERO, U256::ONE),
+            (U256::new(10), U256::new(17)),
+            (U256::ZERO, U256::ONE << 255u32),
+            (U256::new(u128::MAX), U256::from_words(1, 1)),
+            (max - 5, max),
+        ] {
+            for _ in 0..1000 {
+                let value = rng.gen_range(low..high);
+                assert!(low <= value && value < high, "{value}");
+                let value = rng.gen_range(low..=high);
+                assert!(low <= value && value <= high, "{value}");
+            }
+        }
+
+        assert_eq!(rng.gen_range(max..=max), max);
+        assert!((0..100).any(|_| rng.gen_range(max - 1..=max) == max));
+
+        for (low, high) in [
+            (I256::new(-3), I256::new(3)),
+            (I256::MIN, I256::MIN + 2),
+            (I256::MAX - 2, I256::MAX),
+            (I256::MIN, I256::ZERO),
+            (I256::new(-1), I256::MAX),
+        ] {
+            for _ in 0..1000 {
+                let value = rng.gen_range(low..=high);
+                assert!(low <= value && value <= high, "{value}");
+            }
+        }
+    }
+
+    #[test]
+    fn uniform_full_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let uniform = Uniform::new_inclusive(I256::MIN, I256::MAX);
+        let negative = (0..10_000)
+            .filter(|_| rng.sample(uniform).is_negative())
+            .count();
+        assert!(negative.abs_diff(5_000) < 400, "{negative}");
+
+        let uniform = Uniform::new_inclusive(U256::ZERO, U256::MAX);
+        let high = (0..10_000)
+            .filter(|_| rng.sample(uniform) > U256::MAX / 2)
+            .count();
+        assert!(high.abs_diff(5_000) < 400, "{high}");
+    }
+
+    #[test]
+    fn uniform_counts() {
+        const SAMPLES: usize = 30_000;
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut counts = [0usize; 3];
+        for _ in 0..SAMPLES {
+            counts[rng.gen_range(U256::ZERO..U256::new(3)).as_usize()] += 1;
+        }
+        // The standard deviation of each count is about 82.
+        for count in counts {
+            assert!(count.abs_diff(SAMPLES / 3) < 650, "{counts:?}");
+        }
+
+        let mut counts = [0usize; 3];
+        for _ in 0..SAMPLES {
+            counts[(rng.gen_range(I256::new(-1)..=I256::ONE) + 1).as_usize()] += 1;
+        }
+        for count in counts {
+            assert!(count.abs_diff(SAMPLES / 3) < 650, "{counts:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot sample empty range")]
+    fn uniform_empty_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        rng.gen_range(U256::ONE..U256::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Uniform::new_inclusive called with `low > high`")]
+    fn uniform_inverted_range() {
+        Uniform::new_inclusive(I256::ONE, I256::MINUS_ONE);
+    }
+}
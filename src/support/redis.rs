@@ -0,0 +1,142 @@
+//! Module that implements support for the [`redis`](https://crates.io/crates/redis)
+//! crate.
+//!
+//! Integers are written as decimal string arguments, the same way Redis
+//! represents numbers, and can be read back from bulk strings, simple strings
+//! and integer replies. Parsing errors include the offending reply, since the
+//! generic `redis` errors don't.
+
+use crate::{I256, U256};
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+use std::{format, str, string::String};
+
+macro_rules! impl_redis {
+    ($($t:ident),*) => {$(
+        impl ToRedisArgs for $t {
+            fn write_redis_args<W>(&self, out: &mut W)
+            where
+                W: ?Sized + RedisWrite,
+            {
+                out.write_arg_fmt(self)
+            }
+        }
+
+        impl ToSingleRedisArg for $t {}
+
+        impl FromRedisValue for $t {
+            fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+                let parse = |s: &str| {
+                    $t::from_str_radix(s, 10).map_err(|err| {
+                        ParsingError::from(format!(
+                            concat!("could not parse {:?} as ", stringify!($t), ": {}"),
+                            s, err,
+                        ))
+                    })
+                };
+
+                match v {
+                    Value::BulkString(bytes) => parse(str::from_utf8(bytes).map_err(|_| {
+                        ParsingError::from(format!(
+                            concat!("could not parse {:?} as ", stringify!($t), ": invalid UTF-8"),
+                            String::from_utf8_lossy(bytes),
+                        ))
+                    })?),
+                    Value::SimpleString(s) => parse(s),
+                    Value::VerbatimString { text, .. } => parse(text),
+                    Value::Int(i) => $t::try_from(*i).map_err(|_| {
+                        ParsingError::from(format!(
+                            concat!("integer {} is out of range for ", stringify!($t)),
+                            i,
+                        ))
+                    }),
+                    Value::Nil => Err(ParsingError::from(concat!(
+                        "expected ",
+                        stringify!($t),
+                        ", got nil",
+                    ))),
+                    _ => Err(ParsingError::from(format!(
+                        concat!("expected ", stringify!($t), " as a string or integer, got {:?}"),
+                        v,
+                    ))),
+                }
+            }
+
+            fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+                Self::from_redis_value_ref(&v)
+            }
+        }
+    )*};
+}
+
+impl_redis!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{string::ToString, vec, vec::Vec};
+
+    fn args(value: impl ToRedisArgs) -> Vec<Vec<u8>> {
+        value.to_redis_args()
+    }
+
+    fn bulk(s: &str) -> Value {
+        Value::BulkString(s.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn writes_decimal_strings() {
+        assert_eq!(args(U256::MAX), vec![U256::MAX.to_string().into_bytes()]);
+        assert_eq!(args(I256::MIN), vec![I256::MIN.to_string().into_bytes()]);
+        assert_eq!(
+            args(&[U256::ONE, U256::ZERO][..]),
+            vec![b"1".to_vec(), b"0".to_vec()]
+        );
+    }
+
+    #[test]
+    fn reads_values() {
+        assert_eq!(
+            U256::from_redis_value(bulk(&U256::MAX.to_string())).unwrap(),
+            U256::MAX
+        );
+        assert_eq!(
+            I256::from_redis_value_ref(&bulk(&I256::MIN.to_string())).unwrap(),
+            I256::MIN
+        );
+        assert_eq!(
+            U256::from_redis_value(Value::SimpleString("42".to_string())).unwrap(),
+            42
+        );
+        assert_eq!(I256::from_redis_value(Value::Int(-42)).unwrap(), -42);
+        assert_eq!(Option::<U256>::from_redis_value(Value::Nil).unwrap(), None);
+        assert_eq!(
+            Vec::<U256>::from_redis_value(Value::Array(vec![bulk("1"), Value::Int(2)])).unwrap(),
+            vec![U256::ONE, U256::new(2)]
+        );
+    }
+
+    #[test]
+    fn errors_name_the_value() {
+        let err = |v: Value| U256::from_redis_value(v).unwrap_err().to_string();
+        assert_eq!(
+            err(bulk("12abc")),
+            "Incompatible type - could not parse \"12abc\" as U256: invalid digit found in string"
+        );
+        assert_eq!(
+            err(Value::Int(-1)),
+            "Incompatible type - integer -1 is out of range for U256"
+        );
+        assert_eq!(
+            err(Value::Nil),
+            "Incompatible type - expected U256, got nil"
+        );
+        assert_eq!(
+            err(Value::BulkString(vec![0xff])),
+            "Incompatible type - could not parse \"\u{fffd}\" as U256: invalid UTF-8"
+        );
+        assert_eq!(
+            err(Value::Boolean(true)),
+            "Incompatible type - expected U256 as a string or integer, got boolean(true)"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Module that implements support for the [`rkyv`](https://crates.io/crates/rkyv)
+//! crate.
+//!
+//! The 256-bit integer types are archived as [`ArchivedU256`] and
+//! [`ArchivedI256`], which store the integer as 32 little endian bytes (two's
+//! complement for `I256`). The archived representation is independent of the
+//! target's endianness and has an alignment of 1, so every bit pattern is a
+//! valid archived value and integers can be read directly from archived bytes
+//! with [`ArchivedU256::to_native`].
+
+use crate::{I256, U256};
+use core::{cmp::Ordering, fmt};
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::Fallible,
+    traits::{NoUndef, Portable},
+    Archive, Deserialize, Place, Serialize,
+};
+
+macro_rules! impl_rkyv {
+    ($(
+        $(#[$attr:meta])*
+        $archived:ident($t:ident);
+    )*) => {$(
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+        #[repr(transparent)]
+        pub struct $archived([u8; 32]);
+
+        impl $archived {
+            /// Creates an archived integer from its little endian bytes.
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the little endian bytes of the archived integer.
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; 32] {
+                self.0
+            }
+
+            /// Returns the archived integer as a native integer.
+            #[inline]
+            pub fn to_native(&self) -> $t {
+                $t::from_le_bytes(self.0)
+            }
+        }
+
+        impl From<$t> for $archived {
+            #[inline]
+            fn from(value: $t) -> Self {
+                Self(value.to_le_bytes())
+            }
+        }
+
+        impl From<$archived> for $t {
+            #[inline]
+            fn from(value: $archived) -> Self {
+                value.to_native()
+            }
+        }
+
+        impl PartialEq<$t> for $archived {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.to_native() == *other
+            }
+        }
+
+        impl PartialEq<$archived> for $t {
+            #[inline]
+            fn eq(&self, other: &$archived) -> bool {
+                *self == other.to_native()
+            }
+        }
+
+        impl PartialOrd for $archived {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $archived {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.to_native().cmp(&other.to_native())
+            }
+        }
+
+        impl fmt::Debug for $archived {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_native(), f)
+            }
+        }
+
+        impl fmt::Display for $archived {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.to_native(), f)
+            }
+        }
+
+        // SAFETY: the archived type is a byte array, so it has the same
+        // layout on all targets and no padding.
+        unsafe impl Portable for $archived {}
+
+        // SAFETY: see above.
+        unsafe impl NoUndef for $archived {}
+
+        // SAFETY: all bit patterns are valid archived integers.
+        unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for $archived {
+            #[inline]
+            unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+                Ok(())
+            }
+        }
+
+        impl Archive for $t {
+            type Archived = $archived;
+            type Resolver = ();
+
+            #[inline]
+            fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+                out.write($archived::from(*self));
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for $t {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: Fallible + ?Sized> Deserialize<$t, D> for $archived {
+            #[inline]
+            fn deserialize(&self, _: &mut D) -> Result<$t, D::Error> {
+                Ok(self.to_native())
+            }
+        }
+    )*};
+}
+
+impl_rkyv! {
+    /// An archived [`U256`].
+    ArchivedU256(U256);
+
+    /// An archived [`I256`].
+    ArchivedI256(I256);
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use rkyv::{rancor::Error, vec::ArchivedVec};
+
+    #[test]
+    fn zero_copy_access() {
+        let values = (0..100u32)
+            .map(|i| (U256::ONE << (i * 5 % 256)).wrapping_sub(U256::from(i)))
+            .collect::<Vec<_>>();
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+
+        // The elements are laid out as 32 little endian bytes each.
+        for (chunk, value) in bytes.chunks(32).zip(&values) {
+            assert_eq!(chunk, value.to_le_bytes());
+        }
+
+        let archived = rkyv::access::<ArchivedVec<ArchivedU256>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), values.len());
+        for (archived, value) in archived.iter().zip(&values) {
+            assert_eq!(archived.to_native(), *value);
+            assert_eq!(archived, value);
+        }
+
+        let deserialized = rkyv::deserialize::<Vec<U256>, Error>(archived).unwrap();
+        assert_eq!(deserialized, values);
+    }
+
+    #[test]
+    fn signed_round_trip() {
+        for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+            assert_eq!(bytes[..], value.to_le_bytes());
+            let archived = rkyv::access::<ArchivedI256, Error>(&bytes).unwrap();
+            assert_eq!(archived.to_native(), value);
+            assert_eq!(rkyv::from_bytes::<I256, Error>(&bytes).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn archived_ordering() {
+        let (a, b) = (
+            ArchivedU256::from(U256::ONE << 8),
+            ArchivedU256::from(U256::MAX - 1),
+        );
+        assert!(a < b);
+        assert!(ArchivedI256::from(I256::MINUS_ONE) < ArchivedI256::from(I256::ONE));
+        assert_eq!(alloc::format!("{a} {a:?}"), "256 256");
+    }
+}
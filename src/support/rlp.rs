@@ -0,0 +1,86 @@
+//! Module that implements support for the [`rlp`](https://crates.io/crates/rlp)
+//! crate.
+//!
+//! `U256` values are encoded as canonical RLP integers: big endian byte
+//! strings with no leading zeros, where zero is encoded as the empty string.
+//! Decoding rejects encodings with leading zeros as well as strings that are
+//! longer than 32 bytes.
+
+use crate::U256;
+use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+impl Encodable for U256 {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        let bytes = self.to_be_bytes();
+        s.encoder()
+            .encode_value(&bytes[self.leading_zeros() as usize / 8..]);
+    }
+}
+
+impl Decodable for U256 {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        rlp.decoder().decode_value(|bytes| {
+            if bytes.len() > 32 {
+                return Err(DecoderError::RlpIsTooBig);
+            }
+            if bytes.first() == Some(&0) {
+                return Err(DecoderError::RlpInvalidIndirection);
+            }
+            let mut buffer = [0; 32];
+            buffer[32 - bytes.len()..].copy_from_slice(bytes);
+            Ok(U256::from_be_bytes(buffer))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ethereum_test_vectors() {
+        // Integer vectors from `RLPTests/rlptest.json` of the Ethereum tests.
+        for (value, encoded) in [
+            (U256::ZERO, &b"\x80"[..]),
+            (U256::new(1), b"\x01"),
+            (U256::new(16), b"\x10"),
+            (U256::new(79), b"\x4f"),
+            (U256::new(127), b"\x7f"),
+            (U256::new(128), b"\x81\x80"),
+            (U256::new(1000), b"\x82\x03\xe8"),
+            (U256::new(100000), b"\x83\x01\x86\xa0"),
+            (
+                U256::new(83729609699884896815286331701780722),
+                b"\x8f\x10\x20\x30\x40\x50\x60\x70\x80\x90\xa0\xb0\xc0\xd0\xe0\xf2",
+            ),
+            (
+                "105315505618206987246253880190783558935785933862974822347068935681"
+                    .parse()
+                    .unwrap(),
+                b"\x9c\x01\x00\x02\x00\x03\x00\x04\x00\x05\x00\x06\x00\x07\
+                  \x00\x08\x00\x09\x00\x0a\x00\x0b\x00\x0c\x00\x0d\x00\x0e\x01",
+            ),
+            (U256::MAX, &[&b"\xa0"[..], &[0xff; 32]].concat()),
+        ] {
+            assert_eq!(rlp::encode(&value), encoded);
+            assert_eq!(rlp::decode::<U256>(encoded).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn rejects_non_canonical() {
+        for (encoded, err) in [
+            (&b"\x00"[..], DecoderError::RlpInvalidIndirection),
+            (b"\x81\x00", DecoderError::RlpInvalidIndirection),
+            (b"\x81\x05", DecoderError::RlpInvalidIndirection),
+            (b"\x82\x00\x80", DecoderError::RlpInvalidIndirection),
+            (
+                &[&b"\xa1\x01"[..], &[0; 32]].concat(),
+                DecoderError::RlpIsTooBig,
+            ),
+            (b"\xc0", DecoderError::RlpExpectedToBeData),
+        ] {
+            assert_eq!(rlp::decode::<U256>(encoded).unwrap_err(), err);
+        }
+    }
+}
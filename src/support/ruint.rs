@@ -0,0 +1,83 @@
+//! Module that implements conversions to and from the
+//! [`ruint`](https://crates.io/crates/ruint) crate's `U256` type.
+//!
+//! `ruint` does not provide a signed integer type, so only conversions for
+//! [`U256`] are implemented.
+//!
+//! Note that `ruint::Uint` has an inherent `from` method that shadows
+//! `From::from`, so conversions into `ruint` types are best written with
+//! `Into::into`.
+
+use crate::U256;
+
+impl From<ruint::aliases::U256> for U256 {
+    fn from(value: ruint::aliases::U256) -> Self {
+        U256::from_limbs(value.into_limbs())
+    }
+}
+
+impl From<U256> for ruint::aliases::U256 {
+    fn from(value: U256) -> Self {
+        ruint::aliases::U256::from_limbs(value.into_limbs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn to_ruint(x: U256) -> ruint::aliases::U256 {
+        x.into()
+    }
+
+    #[test]
+    fn boundaries() {
+        for (x, r) in [
+            (U256::ZERO, ruint::aliases::U256::ZERO),
+            (U256::ONE, ruint::aliases::U256::from(1)),
+            (U256::MAX, ruint::aliases::U256::MAX),
+            (
+                U256::new(u128::MAX) + 1,
+                ruint::aliases::U256::from(1) << 128,
+            ),
+        ] {
+            assert_eq!(to_ruint(x), r);
+            assert_eq!(U256::from(r), x);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(limbs in any::<[u64; 4]>()) {
+            let x = U256::from_limbs(limbs);
+            prop_assert_eq!(U256::from(to_ruint(x)), x);
+            prop_assert_eq!(
+                to_ruint(x).to_be_bytes::<32>(),
+                x.to_be_bytes()
+            );
+        }
+
+        #[test]
+        fn converts_to_f64_like_ruint(
+            limbs in any::<[u64; 4]>(),
+            shift in 0..256u32,
+            ones in 0..256u32,
+        ) {
+            // Shifting and filling the low bits with ones produces values of
+            // every magnitude, including ones just below rounding boundaries.
+            let fill = U256::MAX.checked_shr(256 - ones).unwrap_or_default();
+            let x = (U256::from_limbs(limbs) >> shift) | fill;
+            let expected = f64::from(to_ruint(x));
+            prop_assert_eq!(x.as_f64().to_bits(), expected.to_bits());
+
+            let signed = |y: crate::I256| {
+                let magnitude = f64::from(to_ruint(y.unsigned_abs()));
+                if y < 0 { -magnitude } else { magnitude }
+            };
+            for y in [x.as_i256(), x.as_i256().wrapping_neg()] {
+                prop_assert_eq!(y.as_f64().to_bits(), signed(y).to_bits());
+            }
+        }
+    }
+}
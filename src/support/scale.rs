@@ -0,0 +1,330 @@
+//! Module that implements support for the
+//! [`parity-scale-codec`](https://crates.io/crates/parity-scale-codec) crate.
+//!
+//! The 256-bit integer types are encoded as exactly 32 bytes in little endian
+//! order (two's complement for `I256`). This is the same encoding that is used
+//! by `sp_core::U256` (and `primitive_types::U256` with its `codec` feature),
+//! so values can be decoded interchangeably.
+//!
+//! The SCALE compact encoding of `U256` values is provided by the
+//! [`CompactU256`] wrapper type.
+
+use crate::{I256, U256};
+use parity_scale_codec::{
+    ConstEncodedLen, Decode, DecodeWithMemTracking, Encode, EncodeAsRef, EncodeLike, Error, Input,
+    MaxEncodedLen, Output,
+};
+
+macro_rules! impl_scale {
+    ($($t:ident),*) => {$(
+        impl Encode for $t {
+            #[inline]
+            fn size_hint(&self) -> usize {
+                32
+            }
+
+            #[inline]
+            fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+                dest.write(&self.to_le_bytes());
+            }
+
+            #[inline]
+            fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+                f(&self.to_le_bytes())
+            }
+
+            #[inline]
+            fn encoded_size(&self) -> usize {
+                32
+            }
+        }
+
+        impl EncodeLike for $t {}
+
+        impl Decode for $t {
+            #[inline]
+            fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+                let mut bytes = [0; 32];
+                input.read(&mut bytes)?;
+                Ok($t::from_le_bytes(bytes))
+            }
+
+            #[inline]
+            fn encoded_fixed_size() -> Option<usize> {
+                Some(32)
+            }
+        }
+
+        impl DecodeWithMemTracking for $t {}
+
+        impl MaxEncodedLen for $t {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                32
+            }
+        }
+
+        impl ConstEncodedLen for $t {}
+    )*};
+}
+
+impl_scale!(U256, I256);
+
+/// A wrapper around a [`U256`] that uses the SCALE compact encoding.
+///
+/// Values that fit in 30 bits use the single, two and four byte modes, and
+/// larger values use the big-integer mode with the minimal number of little
+/// endian bytes. For values up to `u128::MAX`, the encoding is identical to
+/// that of `Compact<u128>`. Decoding rejects non-canonical encodings.
+///
+/// Because of coherence rules, `U256` cannot implement `HasCompact`, so it
+/// can't be used with the `#[codec(compact)]` attribute. Instead, fields can
+/// be annotated with `#[codec(encoded_as = "CompactU256")]`, or use this
+/// wrapper type directly.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct CompactU256(pub U256);
+
+/// The largest value that can be encoded in the four byte mode.
+const MAX_FOUR_BYTE_MODE: u32 = u32::MAX >> 2;
+
+/// Error message for non-canonical and out of range compact encodings.
+const OUT_OF_RANGE: &str = "out of range decoding Compact<U256>";
+
+impl CompactU256 {
+    /// Writes the compact encoding to the buffer, returning the encoded
+    /// length.
+    fn encode_into(&self, buffer: &mut [u8; 33]) -> usize {
+        let value = self.0;
+        if value <= (MAX_FOUR_BYTE_MODE as u128) {
+            let value = value.as_u32();
+            let (bytes, len) = match value {
+                0..=0x3f => ((value << 2).to_le_bytes(), 1),
+                0x40..=0x3fff => (((value << 2) | 0b01).to_le_bytes(), 2),
+                _ => (((value << 2) | 0b10).to_le_bytes(), 4),
+            };
+            buffer[..len].copy_from_slice(&bytes[..len]);
+            len
+        } else {
+            let len = (32 - value.leading_zeros() as usize / 8).max(4);
+            buffer[0] = (((len - 4) as u8) << 2) | 0b11;
+            buffer[1..=len].copy_from_slice(&value.to_le_bytes()[..len]);
+            len + 1
+        }
+    }
+}
+
+impl From<U256> for CompactU256 {
+    #[inline]
+    fn from(value: U256) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&'_ U256> for CompactU256 {
+    #[inline]
+    fn from(value: &U256) -> Self {
+        Self(*value)
+    }
+}
+
+impl From<CompactU256> for U256 {
+    #[inline]
+    fn from(value: CompactU256) -> Self {
+        value.0
+    }
+}
+
+impl Encode for CompactU256 {
+    fn size_hint(&self) -> usize {
+        self.encode_into(&mut [0; 33])
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.using_encoded(|bytes| dest.write(bytes));
+    }
+
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        let mut buffer = [0; 33];
+        let len = self.encode_into(&mut buffer);
+        f(&buffer[..len])
+    }
+}
+
+impl EncodeLike for CompactU256 {}
+
+impl<'a> EncodeAsRef<'a, U256> for CompactU256 {
+    type RefType = CompactU256;
+}
+
+impl Decode for CompactU256 {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let prefix = input.read_byte()?;
+        let value = match prefix & 0b11 {
+            0b00 => U256::from(prefix >> 2),
+            0b01 => {
+                let value = u16::from_le_bytes([prefix, input.read_byte()?]) >> 2;
+                if value <= 0x3f {
+                    return Err(OUT_OF_RANGE.into());
+                }
+                U256::from(value)
+            }
+            0b10 => {
+                let mut bytes = [prefix, 0, 0, 0];
+                input.read(&mut bytes[1..])?;
+                let value = u32::from_le_bytes(bytes) >> 2;
+                if value <= 0x3fff {
+                    return Err(OUT_OF_RANGE.into());
+                }
+                U256::from(value)
+            }
+            _ => {
+                let len = (prefix >> 2) as usize + 4;
+                if len > 32 {
+                    return Err("unexpected prefix decoding Compact<U256>".into());
+                }
+                let mut bytes = [0; 32];
+                input.read(&mut bytes[..len])?;
+                let value = U256::from_le_bytes(bytes);
+                // The big-integer mode must use as few bytes as possible, and
+                // is only used for values that don't fit in the four byte mode.
+                if (len == 4 && value <= (MAX_FOUR_BYTE_MODE as u128)) || bytes[len - 1] == 0 {
+                    return Err(OUT_OF_RANGE.into());
+                }
+                value
+            }
+        };
+        Ok(Self(value))
+    }
+}
+
+impl DecodeWithMemTracking for CompactU256 {}
+
+impl MaxEncodedLen for CompactU256 {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        33
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsU256;
+    use alloc::{vec, vec::Vec};
+    use parity_scale_codec::Compact;
+
+    #[test]
+    fn fixed_encoding() {
+        for value in [U256::ZERO, U256::new(42), U256::ONE << 200u32, U256::MAX] {
+            let encoded = value.encode();
+            assert_eq!(encoded, value.to_le_bytes());
+            assert_eq!(value.encoded_size(), 32);
+            assert_eq!(U256::decode(&mut &encoded[..]).unwrap(), value);
+        }
+        for value in [I256::MIN, I256::new(-42), I256::MAX] {
+            let encoded = value.encode();
+            assert_eq!(encoded, value.to_le_bytes());
+            assert_eq!(I256::decode(&mut &encoded[..]).unwrap(), value);
+        }
+
+        assert_eq!(
+            U256::new(0x0102).encode(),
+            [&[0x02, 0x01][..], &[0; 30]].concat()
+        );
+        assert_eq!(I256::MINUS_ONE.encode(), [0xff; 32]);
+        assert_eq!(U256::max_encoded_len(), 32);
+        assert_eq!(I256::max_encoded_len(), 32);
+
+        assert!(U256::decode(&mut &[0; 31][..]).is_err());
+
+        let values = vec![U256::ONE, U256::MAX];
+        let encoded = values.encode();
+        assert_eq!(encoded.len(), 1 + 64);
+        assert_eq!(Vec::<U256>::decode(&mut &encoded[..]).unwrap(), values);
+    }
+
+    #[test]
+    fn compact_fixtures() {
+        for (value, encoded) in [
+            (U256::ZERO, &[0x00][..]),
+            (U256::new(1), &[0x04]),
+            (U256::new(42), &[0xa8]),
+            (U256::new(63), &[0xfc]),
+            (U256::new(64), &[0x01, 0x01]),
+            (U256::new(69), &[0x15, 0x01]),
+            (U256::new(0x3fff), &[0xfd, 0xff]),
+            (U256::new(0x4000), &[0x02, 0x00, 0x01, 0x00]),
+            (U256::new(0x3fff_ffff), &[0xfe, 0xff, 0xff, 0xff]),
+            (U256::new(0x4000_0000), &[0x03, 0x00, 0x00, 0x00, 0x40]),
+            (
+                U256::new(0x1_0000_0000),
+                &[0x07, 0x00, 0x00, 0x00, 0x00, 0x01],
+            ),
+            (
+                U256::ONE << 128u32,
+                &[&[0x37][..], &[0; 16], &[0x01]].concat(),
+            ),
+            (U256::MAX, &[&[0x73][..], &[0xff; 32]].concat()),
+        ] {
+            let compact = CompactU256(value);
+            assert_eq!(compact.encode(), encoded);
+            assert_eq!(compact.size_hint(), encoded.len());
+            assert_eq!(CompactU256::decode(&mut &encoded[..]).unwrap(), compact);
+        }
+        assert_eq!(CompactU256::max_encoded_len(), 33);
+    }
+
+    #[test]
+    fn compact_matches_u128() {
+        let mut values = vec![0, u128::MAX];
+        for shift in 0..128 {
+            let x = 1u128 << shift;
+            values.extend([x - 1, x, x + 1]);
+        }
+        for value in values {
+            let encoded = Compact(value).encode();
+            assert_eq!(CompactU256(value.as_u256()).encode(), encoded);
+            assert_eq!(
+                CompactU256::decode(&mut &encoded[..]).unwrap().0,
+                value.as_u256()
+            );
+        }
+    }
+
+    #[test]
+    fn compact_rejects_non_canonical() {
+        for encoded in [
+            &[0x01, 0x00][..],
+            &[0xfd, 0x00],
+            &[0x02, 0x00, 0x00, 0x00],
+            &[0xfe, 0xff, 0x00, 0x00],
+            &[0x03, 0xff, 0xff, 0xff, 0x3f],
+            &[0x07, 0x00, 0x00, 0x00, 0x40, 0x00],
+            &[&[0x73][..], &[0xff; 31], &[0x00]].concat(),
+        ] {
+            assert!(CompactU256::decode(&mut &encoded[..]).is_err());
+        }
+
+        // Prefix for a 33 byte integer.
+        assert!(CompactU256::decode(&mut &[&[0x77][..], &[0xff; 33]].concat()[..]).is_err());
+        // Truncated input.
+        assert!(CompactU256::decode(&mut &[0x73, 0xff][..]).is_err());
+        assert!(CompactU256::decode(&mut &[][..]).is_err());
+    }
+
+    #[cfg(feature = "primitive-types")]
+    #[test]
+    fn matches_primitive_types() {
+        for value in [U256::ZERO, U256::new(42), U256::ONE << 200u32, U256::MAX] {
+            let other = primitive_types::U256::from(value);
+            let encoded = other.encode();
+            assert_eq!(value.encode(), encoded);
+            assert_eq!(U256::decode(&mut &encoded[..]).unwrap(), value);
+            assert_eq!(
+                primitive_types::U256::decode(&mut &value.encode()[..]).unwrap(),
+                other
+            );
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Module that implements support for the [`schemars`](https://crates.io/crates/schemars)
+//! crate.
+//!
+//! The schemas describe the integers as decimal strings (with an optional
+//! leading `-` for [`I256`]), matching the default `serde` representation and
+//! the `Uint256` type from `cosmwasm-std`.
+
+use alloc::{borrow::Cow, boxed::Box, string::String};
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+
+use crate::{I256, U256};
+
+/// Returns a string schema with the specified description and pattern.
+fn string_schema(description: &str, pattern: &str) -> Schema {
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.into()),
+            ..Default::default()
+        })),
+        instance_type: Some(InstanceType::String.into()),
+        string: Some(Box::new(StringValidation {
+            pattern: Some(pattern.into()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+impl JsonSchema for U256 {
+    fn schema_name() -> String {
+        "U256".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ethnum::U256".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_schema(
+            "A 256-bit unsigned integer encoded as a decimal string.",
+            "^[0-9]+$",
+        )
+    }
+}
+
+impl JsonSchema for I256 {
+    fn schema_name() -> String {
+        "I256".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ethnum::I256".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        string_schema(
+            "A 256-bit signed integer encoded as a decimal string.",
+            "^-?[0-9]+$",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use regex::Regex;
+    use serde_json::{json, Value};
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    struct Position {
+        amount: U256,
+        pnl: I256,
+    }
+
+    /// Validates a JSON object against the string properties of a schema.
+    fn validate(schema: &Value, document: &Value) -> bool {
+        let definitions = &schema["definitions"];
+        let properties = schema["properties"].as_object().unwrap();
+        properties.iter().all(|(name, property)| {
+            let reference = property["$ref"].as_str().unwrap();
+            let definition = &definitions[reference.trim_start_matches("#/definitions/")];
+            assert_eq!(definition["type"], "string");
+            let pattern = Regex::new(definition["pattern"].as_str().unwrap()).unwrap();
+            document[name]
+                .as_str()
+                .is_some_and(|value| pattern.is_match(value))
+        })
+    }
+
+    #[test]
+    fn schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(Position)).unwrap();
+        let mut required = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r.as_str().unwrap())
+            .collect::<Vec<_>>();
+        required.sort();
+        assert_eq!(required, ["amount", "pnl"]);
+        assert_eq!(
+            schema["definitions"]["U256"],
+            json!({
+                "description": "A 256-bit unsigned integer encoded as a decimal string.",
+                "type": "string",
+                "pattern": "^[0-9]+$",
+            })
+        );
+
+        let max = U256::MAX.to_string();
+        let min = I256::MIN.to_string();
+        assert!(validate(&schema, &json!({ "amount": max, "pnl": min })));
+        assert!(validate(&schema, &json!({ "amount": "0", "pnl": "42" })));
+        assert!(!validate(&schema, &json!({ "amount": "-1", "pnl": "0" })));
+        assert!(!validate(&schema, &json!({ "amount": "0x2a", "pnl": "0" })));
+        assert!(!validate(&schema, &json!({ "amount": "1", "pnl": "" })));
+        assert!(!validate(&schema, &json!({ "amount": 1, "pnl": "1" })));
+    }
+}
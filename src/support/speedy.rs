@@ -0,0 +1,124 @@
+//! Module that implements support for the [`speedy`](https://crates.io/crates/speedy)
+//! crate.
+//!
+//! The 256-bit integer types are encoded as fixed 32-byte little endian
+//! fields. Unlike speedy's primitive integers, the byte order does not depend
+//! on the endianness of the context, so the encoding is the same on both ends
+//! of a connection regardless of how they are configured.
+
+use crate::{I256, U256};
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+macro_rules! impl_speedy {
+    ($($t:ident),*) => {$(
+        impl<'a, C: Context> Readable<'a, C> for $t {
+            #[inline]
+            fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+                let mut bytes = [0; 32];
+                reader.read_bytes(&mut bytes)?;
+                Ok($t::from_le_bytes(bytes))
+            }
+
+            #[inline]
+            fn minimum_bytes_needed() -> usize {
+                32
+            }
+        }
+
+        impl<C: Context> Writable<C> for $t {
+            #[inline]
+            fn write_to<T: ?Sized + Writer<C>>(&self, writer: &mut T) -> Result<(), C::Error> {
+                writer.write_bytes(&self.to_le_bytes())
+            }
+
+            #[inline]
+            fn bytes_needed(&self) -> Result<usize, C::Error> {
+                Ok(32)
+            }
+        }
+    )*};
+}
+
+impl_speedy!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speedy::{BigEndian, Endianness, LittleEndian};
+
+    #[derive(Debug, PartialEq, Readable, Writable)]
+    struct Transfer {
+        nonce: u32,
+        amount: U256,
+        memo: String,
+        delta: I256,
+        fees: Vec<U256>,
+    }
+
+    #[test]
+    fn round_trips_in_both_endiannesses() {
+        let value = U256::from_words(0x0102, 0x0304);
+        let mut expected = [0; 32];
+        expected[0] = 0x04;
+        expected[1] = 0x03;
+        expected[16] = 0x02;
+        expected[17] = 0x01;
+
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            let bytes = value.write_to_vec_with_ctx(endianness).unwrap();
+            assert_eq!(bytes, expected);
+            assert_eq!(
+                U256::read_from_buffer_with_ctx(endianness, &bytes).unwrap(),
+                value
+            );
+
+            for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+                let bytes = value.write_to_vec_with_ctx(endianness).unwrap();
+                assert_eq!(bytes, value.to_le_bytes());
+                assert_eq!(
+                    I256::read_from_buffer_with_ctx(endianness, &bytes).unwrap(),
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(<U256 as Readable<LittleEndian>>::minimum_bytes_needed(), 32);
+        assert_eq!(<I256 as Readable<BigEndian>>::minimum_bytes_needed(), 32);
+        assert_eq!(
+            Writable::<LittleEndian>::bytes_needed(&U256::MAX).unwrap(),
+            32
+        );
+        assert_eq!(
+            <Transfer as Readable<LittleEndian>>::minimum_bytes_needed(),
+            4 + 32 + 4 + 32 + 4
+        );
+
+        assert!(U256::read_from_buffer(&[0; 31]).is_err());
+    }
+
+    #[test]
+    fn struct_fields() {
+        let transfer = Transfer {
+            nonce: 7,
+            amount: U256::MAX,
+            memo: "hello".into(),
+            delta: I256::new(-42),
+            fees: vec![U256::ONE, U256::from_words(1, 0)],
+        };
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            let bytes = transfer.write_to_vec_with_ctx(endianness).unwrap();
+            assert_eq!(
+                bytes.len(),
+                Writable::<Endianness>::bytes_needed(&transfer).unwrap()
+            );
+            assert_eq!(&bytes[4..36], U256::MAX.to_le_bytes());
+            assert_eq!(
+                Transfer::read_from_buffer_with_ctx(endianness, &bytes).unwrap(),
+                transfer
+            );
+        }
+    }
+}
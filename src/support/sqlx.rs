@@ -0,0 +1,274 @@
+//! Module that implements support for the [`sqlx`](https://crates.io/crates/sqlx)
+//! crate.
+//!
+//! The implementations only depend on `sqlx-core` and the database driver
+//! crates, so they don't impose an async runtime or TLS backend. The encoding
+//! used depends on the database:
+//!
+//! - **PostgreSQL** (`sqlx-postgres` feature): values are mapped to the
+//!   `NUMERIC` type, so columns can be declared as `NUMERIC(78, 0)` and used in
+//!   arithmetic and comparisons on the database side. Values with fractional
+//!   digits, `NaN` and infinities are rejected when decoding.
+//! - **SQLite** (`sqlx-sqlite-bundled` or `sqlx-sqlite-unbundled` feature):
+//!   values are stored as `TEXT` containing the decimal representation of the
+//!   integer. Note that SQLite compares text lexicographically, so ordering by
+//!   such a column is not numeric ordering. As with `sqlx`, the features
+//!   choose between compiling SQLite into the binary and linking against the
+//!   system library.
+//! - **`Any`**: values are bound and read as decimal `TEXT`, like SQLite.
+
+#[cfg(all(
+    feature = "sqlx-sqlite",
+    not(any(feature = "sqlx-sqlite-bundled", feature = "sqlx-sqlite-unbundled"))
+))]
+compile_error!(
+    "the `sqlx-sqlite` feature requires choosing how SQLite is linked with either \
+     the `sqlx-sqlite-bundled` or the `sqlx-sqlite-unbundled` feature"
+);
+
+use crate::{I256, U256};
+use sqlx_core::{
+    any::{Any, AnyArgumentBuffer, AnyTypeInfo, AnyValueRef},
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+use std::string::{String, ToString};
+
+macro_rules! impl_sqlx_any {
+    ($($t:ident),*) => {$(
+        impl Type<Any> for $t {
+            fn type_info() -> AnyTypeInfo {
+                <str as Type<Any>>::type_info()
+            }
+        }
+
+        impl<'q> Encode<'q, Any> for $t {
+            fn encode_by_ref(&self, buf: &mut AnyArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+                <String as Encode<'q, Any>>::encode(self.to_string(), buf)
+            }
+        }
+
+        impl<'r> Decode<'r, Any> for $t {
+            fn decode(value: AnyValueRef<'r>) -> Result<Self, BoxDynError> {
+                Ok(<&str as Decode<'r, Any>>::decode(value)?.parse()?)
+            }
+        }
+    )*};
+}
+
+impl_sqlx_any!(U256, I256);
+
+#[cfg(feature = "sqlx-postgres")]
+mod postgres {
+    use super::super::pg_numeric;
+    use crate::{I256, U256};
+    use sqlx_core::{
+        decode::Decode,
+        encode::{Encode, IsNull},
+        error::BoxDynError,
+        types::Type,
+    };
+    use sqlx_postgres::{
+        types::Oid, PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef,
+        Postgres,
+    };
+
+    const NUMERIC: PgTypeInfo = PgTypeInfo::with_oid(Oid(1700));
+    const NUMERIC_ARRAY: PgTypeInfo = PgTypeInfo::with_oid(Oid(1231));
+
+    macro_rules! impl_sqlx_postgres {
+        ($($t:ident),*) => {$(
+            impl Type<Postgres> for $t {
+                fn type_info() -> PgTypeInfo {
+                    NUMERIC
+                }
+            }
+
+            impl PgHasArrayType for $t {
+                fn array_type_info() -> PgTypeInfo {
+                    NUMERIC_ARRAY
+                }
+            }
+
+            impl Encode<'_, Postgres> for $t {
+                fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                    buf.extend_from_slice(&pg_numeric::encode(self));
+                    Ok(IsNull::No)
+                }
+
+                fn size_hint(&self) -> usize {
+                    pg_numeric::encode(self).len()
+                }
+            }
+
+            impl Decode<'_, Postgres> for $t {
+                fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                    Ok(match value.format() {
+                        PgValueFormat::Binary => pg_numeric::decode(value.as_bytes()?)?,
+                        PgValueFormat::Text => pg_numeric::decode_text(value.as_str()?)?,
+                    })
+                }
+            }
+        )*};
+    }
+
+    impl_sqlx_postgres!(U256, I256);
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlite {
+    use crate::{I256, U256};
+    use sqlx_core::{
+        decode::Decode,
+        encode::{Encode, IsNull},
+        error::BoxDynError,
+        types::Type,
+    };
+    use sqlx_sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+    use std::{borrow::Cow, string::ToString, vec::Vec};
+
+    macro_rules! impl_sqlx_sqlite {
+        ($($t:ident),*) => {$(
+            impl Type<Sqlite> for $t {
+                fn type_info() -> SqliteTypeInfo {
+                    <str as Type<Sqlite>>::type_info()
+                }
+
+                fn compatible(ty: &SqliteTypeInfo) -> bool {
+                    <str as Type<Sqlite>>::compatible(ty)
+                }
+            }
+
+            impl<'q> Encode<'q, Sqlite> for $t {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut Vec<SqliteArgumentValue<'q>>,
+                ) -> Result<IsNull, BoxDynError> {
+                    buf.push(SqliteArgumentValue::Text(Cow::Owned(self.to_string())));
+                    Ok(IsNull::No)
+                }
+            }
+
+            impl<'r> Decode<'r, Sqlite> for $t {
+                fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+                    Ok(<&str as Decode<'r, Sqlite>>::decode(value)?.parse()?)
+                }
+            }
+        )*};
+    }
+
+    impl_sqlx_sqlite!(U256, I256);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx_core::{
+        any::{AnyValue, AnyValueKind},
+        value::Value,
+    };
+    use std::{borrow::Cow, vec::Vec};
+
+    fn any_round_trip<T>(value: T) -> T
+    where
+        T: for<'q> Encode<'q, Any> + for<'r> Decode<'r, Any>,
+    {
+        let mut buf = AnyArgumentBuffer(Vec::new());
+        assert!(matches!(value.encode_by_ref(&mut buf).unwrap(), IsNull::No));
+        let kind = match buf.0.pop().unwrap() {
+            AnyValueKind::Text(text) => AnyValueKind::Text(Cow::Owned(text.into_owned())),
+            other => panic!("unexpected value {other:?}"),
+        };
+        let value = AnyValue { kind };
+        T::decode(value.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn any_round_trips() {
+        for value in [U256::ZERO, U256::ONE, U256::new(u128::MAX), U256::MAX] {
+            assert_eq!(any_round_trip(value), value);
+        }
+        for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            assert_eq!(any_round_trip(value), value);
+        }
+        assert_eq!(
+            <U256 as Type<Any>>::type_info(),
+            <str as Type<Any>>::type_info()
+        );
+    }
+
+    #[test]
+    fn any_rejects_invalid_text() {
+        for text in ["", "-1", "1.5", "0x10"] {
+            let value = AnyValue {
+                kind: AnyValueKind::Text(Cow::Borrowed(text)),
+            };
+            assert!(<U256 as Decode<Any>>::decode(value.as_ref()).is_err());
+        }
+        let value = AnyValue {
+            kind: AnyValueKind::Double(1.0),
+        };
+        assert!(<I256 as Decode<Any>>::decode(value.as_ref()).is_err());
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    #[test]
+    fn postgres_encoding() {
+        use sqlx_postgres::{PgArgumentBuffer, Postgres};
+
+        for (value, raw) in [
+            (U256::ZERO, &[0, 0, 0, 0, 0, 0, 0, 0][..]),
+            (
+                U256::new(12345678),
+                &[0, 2, 0, 1, 0, 0, 0, 0, 0x04, 0xd2, 0x16, 0x2e],
+            ),
+        ] {
+            let mut buf = PgArgumentBuffer::default();
+            assert!(matches!(
+                Encode::<Postgres>::encode_by_ref(&value, &mut buf).unwrap(),
+                IsNull::No
+            ));
+            assert_eq!(&buf[..], raw);
+            assert_eq!(Encode::<Postgres>::size_hint(&value), raw.len());
+        }
+
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(
+            Encode::<Postgres>::encode_by_ref(&I256::MINUS_ONE, &mut buf).unwrap(),
+            IsNull::No
+        ));
+        assert_eq!(&buf[..], [0, 1, 0, 0, 0x40, 0, 0, 0, 0, 1]);
+        assert_eq!(<U256 as Type<Postgres>>::type_info().oid().unwrap().0, 1700);
+    }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    #[test]
+    fn sqlite_encoding() {
+        use sqlx_sqlite::{Sqlite, SqliteArgumentValue};
+
+        for (value, text) in [
+            (
+                I256::MIN,
+                "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
+            ),
+            (I256::new(-42), "-42"),
+            (
+                I256::MAX,
+                "57896044618658097711785492504343953926634992332820282019728792003956564819967",
+            ),
+        ] {
+            let mut buf = Vec::new();
+            assert!(matches!(
+                Encode::<Sqlite>::encode_by_ref(&value, &mut buf).unwrap(),
+                IsNull::No
+            ));
+            assert!(matches!(&buf[..], [SqliteArgumentValue::Text(t)] if t == text));
+        }
+        assert_eq!(
+            <U256 as Type<Sqlite>>::type_info(),
+            <str as Type<Sqlite>>::type_info()
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Module that implements support for using the 256-bit integer types as
+//! [`cw-storage-plus`](https://crates.io/crates/cw-storage-plus) keys.
+//!
+//! Keys are encoded as big-endian bytes (with the sign bit flipped for
+//! [`I256`], see [`I256::to_key_bytes`]) so that ranges are iterated in
+//! numeric order. Since `cw-storage-plus` keys are limited to 128-bit values,
+//! the 32 key bytes are split into two 128-bit key segments. This means that a
+//! 256-bit integer can be used as a key on its own, as a prefix, or as the
+//! *last* element of a tuple key, but ranging over a tuple key with a 256-bit
+//! integer in any other position will fail to deserialize the keys. Use
+//! `to_key_bytes` with a `[u8; 32]` key in that case instead.
+
+use alloc::{vec, vec::Vec};
+use cw_cosmwasm_std::{StdError, StdResult};
+use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
+
+use crate::{I256, U256};
+
+/// Splits key bytes into the two key segments.
+fn key_segments<'a>(bytes: [u8; 32]) -> Vec<Key<'a>> {
+    let (hi, lo) = bytes.split_at(16);
+    vec![
+        Key::Val128(hi.try_into().unwrap()),
+        Key::Val128(lo.try_into().unwrap()),
+    ]
+}
+
+/// Reads key bytes from two joined key segments, i.e. the length-prefixed
+/// upper 16 bytes followed by the lower 16 bytes.
+fn joined_key_bytes(value: &[u8]) -> StdResult<[u8; 32]> {
+    match value {
+        [0, 16, bytes @ ..] if bytes.len() == 32 => Ok(bytes.try_into().unwrap()),
+        _ => Err(StdError::generic_err("invalid 256-bit integer key")),
+    }
+}
+
+macro_rules! impl_storage_key {
+    ($($int:ident),*) => {$(
+        impl<'a> PrimaryKey<'a> for $int {
+            type Prefix = ();
+            type SubPrefix = ();
+            type Suffix = Self;
+            type SuperSuffix = Self;
+
+            fn key(&self) -> Vec<Key<'_>> {
+                key_segments(self.to_key_bytes())
+            }
+        }
+
+        impl<'a> Prefixer<'a> for $int {
+            fn prefix(&self) -> Vec<Key<'_>> {
+                key_segments(self.to_key_bytes())
+            }
+        }
+
+        impl KeyDeserialize for $int {
+            type Output = Self;
+
+            fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+                joined_key_bytes(&value).map($int::from_key_bytes)
+            }
+        }
+    )*};
+}
+
+impl_storage_key!(U256, I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cw_cosmwasm_std::{testing::MockStorage, Order};
+    use cw_storage_plus::{Bound, Map};
+
+    #[test]
+    fn unsigned_range_is_numeric() {
+        let map = Map::<U256, u32>::new("u");
+        let mut storage = MockStorage::new();
+        let keys = [
+            U256::MAX,
+            U256::new(256),
+            U256::ZERO,
+            U256::ONE << 128,
+            U256::new(1),
+            U256::new(u128::MAX),
+        ];
+        for (i, key) in keys.iter().enumerate() {
+            map.save(&mut storage, *key, &(i as u32)).unwrap();
+        }
+
+        let ranged = map
+            .keys(&storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        let mut sorted = keys.to_vec();
+        sorted.sort();
+        assert_eq!(ranged, sorted);
+
+        let bounded = map
+            .range(
+                &storage,
+                Some(Bound::inclusive(U256::new(256))),
+                Some(Bound::exclusive(U256::MAX)),
+                Order::Descending,
+            )
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            bounded,
+            [
+                (U256::ONE << 128, 3),
+                (U256::new(u128::MAX), 5),
+                (U256::new(256), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn signed_range_is_numeric() {
+        let map = Map::<I256, u32>::new("i");
+        let mut storage = MockStorage::new();
+        let keys = [
+            I256::new(5),
+            I256::MIN,
+            I256::MINUS_ONE,
+            I256::MAX,
+            I256::ZERO,
+            I256::new(-1000) << 150,
+            I256::new(i128::MIN),
+        ];
+        for (i, key) in keys.iter().enumerate() {
+            map.save(&mut storage, *key, &(i as u32)).unwrap();
+        }
+
+        let ranged = map
+            .keys(&storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        let mut sorted = keys.to_vec();
+        sorted.sort();
+        assert_eq!(ranged, sorted);
+        assert_eq!(map.load(&storage, I256::MINUS_ONE).unwrap(), 2);
+    }
+
+    #[test]
+    fn tuple_suffix_and_prefix() {
+        let map = Map::<(u8, I256), ()>::new("t");
+        let mut storage = MockStorage::new();
+        for key in [
+            (1, I256::new(3)),
+            (0, I256::MAX),
+            (1, I256::MIN),
+            (1, I256::ZERO),
+        ] {
+            map.save(&mut storage, key, &()).unwrap();
+        }
+
+        let ranged = map
+            .keys(&storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            ranged,
+            [
+                (0, I256::MAX),
+                (1, I256::MIN),
+                (1, I256::ZERO),
+                (1, I256::new(3))
+            ]
+        );
+
+        let prefixed = map
+            .prefix(1)
+            .keys(&storage, None, None, Order::Descending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(prefixed, [I256::new(3), I256::ZERO, I256::MIN]);
+
+        let nested = Map::<(U256, u8), ()>::new("n");
+        nested.save(&mut storage, (U256::MAX, 7), &()).unwrap();
+        nested.save(&mut storage, (U256::ONE, 9), &()).unwrap();
+        let suffixes = nested
+            .prefix(U256::MAX)
+            .keys(&storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(suffixes, [7]);
+    }
+
+    #[test]
+    fn invalid_key() {
+        assert!(U256::from_slice(&[0; 32]).is_err());
+        assert!(I256::from_slice(&[0, 32, 0]).is_err());
+        let mut key = vec![0, 16];
+        key.extend(U256::new(42).to_key_bytes());
+        assert_eq!(U256::from_vec(key).unwrap(), U256::new(42));
+    }
+}
@@ -0,0 +1,187 @@
+//! Module that implements support for the [`subtle`](https://crates.io/crates/subtle)
+//! crate.
+//!
+//! The comparisons and selections are computed over both 128-bit words
+//! without branching on, or returning early because of, the values.
+
+use crate::{I256, U256};
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+/// Returns whether `a > b` for two unsigned `[high, low]` word pairs.
+fn words_gt(a: (u128, u128), b: (u128, u128)) -> Choice {
+    let (a_hi, a_lo) = a;
+    let (b_hi, b_lo) = b;
+    a_hi.ct_gt(&b_hi) | (a_hi.ct_eq(&b_hi) & a_lo.ct_gt(&b_lo))
+}
+
+impl U256 {
+    /// Returns `a` if `choice` is set and `b` otherwise, in constant time.
+    ///
+    /// This is a convenience for [`ConditionallySelectable::conditional_select`],
+    /// with the arguments in the order of a conditional expression.
+    ///
+    /// # Constant time
+    ///
+    /// Only the implementations of the `subtle` traits are constant time.
+    /// Other operations on the 256-bit integers make no such guarantees and
+    /// should not be used on secret values. In particular, this includes:
+    ///
+    /// - `==` and `<`-style comparisons from `PartialEq` and `Ord`, which may
+    ///   return as soon as the high words differ;
+    /// - division and remainder, as well as `pow`, `isqrt` and the other
+    ///   methods built on top of them;
+    /// - shifts and rotations, which branch on the shift amount;
+    /// - `leading_zeros`, `trailing_zeros` and related bit counting methods;
+    /// - `checked_*`, `saturating_*` and `overflowing_*` arithmetic, whose
+    ///   results are typically branched on;
+    /// - formatting and parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// use subtle::Choice;
+    ///
+    /// let (a, b) = (U256::new(1), U256::new(2));
+    /// assert_eq!(U256::ct_select(Choice::from(1), a, b), a);
+    /// assert_eq!(U256::ct_select(Choice::from(0), a, b), b);
+    /// ```
+    #[inline]
+    pub fn ct_select(choice: Choice, a: Self, b: Self) -> Self {
+        Self::conditional_select(&b, &a, choice)
+    }
+}
+
+impl I256 {
+    /// Returns `a` if `choice` is set and `b` otherwise, in constant time.
+    ///
+    /// See [`U256::ct_select`] for which operations are constant time.
+    #[inline]
+    pub fn ct_select(choice: Choice, a: Self, b: Self) -> Self {
+        Self::conditional_select(&b, &a, choice)
+    }
+}
+
+macro_rules! impl_subtle {
+    ($($t:ident),*) => {$(
+        impl ConstantTimeEq for $t {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> Choice {
+                let (a_hi, a_lo) = self.into_words();
+                let (b_hi, b_lo) = other.into_words();
+                a_hi.ct_eq(&b_hi) & a_lo.ct_eq(&b_lo)
+            }
+        }
+
+        impl ConditionallySelectable for $t {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                let (a_hi, a_lo) = a.into_words();
+                let (b_hi, b_lo) = b.into_words();
+                $t::from_words(
+                    ConditionallySelectable::conditional_select(&a_hi, &b_hi, choice),
+                    ConditionallySelectable::conditional_select(&a_lo, &b_lo, choice),
+                )
+            }
+        }
+
+        impl ConstantTimeLess for $t {}
+    )*};
+}
+
+impl_subtle!(U256, I256);
+
+impl ConstantTimeGreater for U256 {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        words_gt(self.into_words(), other.into_words())
+    }
+}
+
+impl ConstantTimeGreater for I256 {
+    /// Compares the values by flipping their sign bits, which maps the signed
+    /// order onto the unsigned order of the words.
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let flip = |value: &I256| {
+            let (hi, lo) = value.into_words();
+            ((hi as u128) ^ (1 << 127), lo as u128)
+        };
+        words_gt(flip(self), flip(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unsigned_values() -> [U256; 8] {
+        [
+            U256::ZERO,
+            U256::ONE,
+            U256::new(u128::MAX),
+            U256::from_words(1, 0),
+            U256::from_words(1, 1),
+            U256::from_words(u128::MAX, 0),
+            U256::MAX - 1,
+            U256::MAX,
+        ]
+    }
+
+    fn signed_values() -> [I256; 8] {
+        [
+            I256::MIN,
+            I256::MIN + 1,
+            I256::new(i128::MIN),
+            I256::MINUS_ONE,
+            I256::ZERO,
+            I256::ONE,
+            I256::from_words(0, -1),
+            I256::MAX,
+        ]
+    }
+
+    #[test]
+    fn comparisons_match_variable_time() {
+        for a in unsigned_values() {
+            for b in unsigned_values() {
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b, "{a} == {b}");
+                assert_eq!(bool::from(a.ct_gt(&b)), a > b, "{a} > {b}");
+                assert_eq!(bool::from(a.ct_lt(&b)), a < b, "{a} < {b}");
+            }
+        }
+        for a in signed_values() {
+            for b in signed_values() {
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b, "{a} == {b}");
+                assert_eq!(bool::from(a.ct_gt(&b)), a > b, "{a} > {b}");
+                assert_eq!(bool::from(a.ct_lt(&b)), a < b, "{a} < {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn selection() {
+        for a in unsigned_values() {
+            for b in unsigned_values() {
+                assert_eq!(U256::conditional_select(&a, &b, Choice::from(0)), a);
+                assert_eq!(U256::conditional_select(&a, &b, Choice::from(1)), b);
+                assert_eq!(U256::ct_select(Choice::from(1), a, b), a);
+                assert_eq!(U256::ct_select(Choice::from(0), a, b), b);
+
+                let (mut x, mut y) = (a, b);
+                U256::conditional_swap(&mut x, &mut y, Choice::from(1));
+                assert_eq!((x, y), (b, a));
+            }
+        }
+        for a in signed_values() {
+            for b in signed_values() {
+                assert_eq!(I256::ct_select(a.ct_lt(&b), a, b), a.min(b));
+                let mut x = a;
+                x.conditional_assign(&b, a.ct_gt(&b));
+                assert_eq!(x, a.min(b));
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Module that implements support for the
+//! [`wasm-bindgen`](https://crates.io/crates/wasm-bindgen) crate, converting
+//! 256-bit integers to and from JavaScript `BigInt` values.
+//!
+//! The conversions go through the 128-bit words of the integers, which
+//! `wasm-bindgen` transfers exactly, so no precision is lost. Converting a
+//! `BigInt` that is out of range for the integer type fails instead of
+//! truncating it.
+//!
+//! The 256-bit integer types can't appear in `#[wasm_bindgen]` signatures
+//! directly. Instead, exported functions can take and return `BigInt` values
+//! and convert them at the boundary:
+//!
+//! ```no_run
+//! # use ethnum::U256;
+//! use js_sys::BigInt;
+//! use wasm_bindgen::prelude::*;
+//!
+//! #[wasm_bindgen]
+//! pub fn double(value: BigInt) -> Result<BigInt, JsError> {
+//!     let value = U256::try_from(value)?;
+//!     let doubled = value
+//!         .checked_mul(U256::new(2))
+//!         .ok_or_else(|| JsError::new("overflow"))?;
+//!     Ok(doubled.into())
+//! }
+//! ```
+
+use crate::{error::tfie, I256, U256};
+use core::num::TryFromIntError;
+use js_sys::BigInt;
+use wasm_bindgen::{JsCast as _, JsValue};
+
+/// Returns the `BigInt` for the shift amount between the two 128-bit words.
+fn word_bits() -> BigInt {
+    BigInt::from(128u32)
+}
+
+impl From<U256> for BigInt {
+    fn from(value: U256) -> Self {
+        let (hi, lo) = value.into_words();
+        (BigInt::from(hi) << word_bits()) | BigInt::from(lo)
+    }
+}
+
+impl From<I256> for BigInt {
+    fn from(value: I256) -> Self {
+        let (hi, lo) = value.into_words();
+        (BigInt::from(hi) << word_bits()) | BigInt::from(lo as u128)
+    }
+}
+
+impl TryFrom<BigInt> for U256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        let lo = BigInt::as_uint_n(128., &value);
+        let hi = &value >> word_bits();
+        Ok(U256::from_words(
+            u128::try_from(hi).map_err(|_| tfie())?,
+            u128::try_from(lo).map_err(|_| tfie())?,
+        ))
+    }
+}
+
+impl TryFrom<BigInt> for I256 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        // The right shift rounds towards negative infinity, so the high word
+        // is the two's complement high word of negative values.
+        let lo = BigInt::as_uint_n(128., &value);
+        let hi = &value >> word_bits();
+        Ok(I256::from_words(
+            i128::try_from(hi).map_err(|_| tfie())?,
+            u128::try_from(lo).map_err(|_| tfie())? as i128,
+        ))
+    }
+}
+
+macro_rules! impl_js_value {
+    ($($t:ident),*) => {$(
+        impl From<$t> for JsValue {
+            fn from(value: $t) -> Self {
+                BigInt::from(value).into()
+            }
+        }
+
+        impl TryFrom<JsValue> for $t {
+            type Error = JsValue;
+
+            /// Converts a JavaScript value to a 256-bit integer, returning the
+            /// original value as the error if it is not a `BigInt` or is out
+            /// of range.
+            fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+                let bigint = value.dyn_into::<BigInt>()?;
+                $t::try_from(bigint.clone()).map_err(|_| bigint.into())
+            }
+        }
+    )*};
+}
+
+impl_js_value!(U256, I256);
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use std::{format, string::ToString};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn bigint(value: &str) -> BigInt {
+        value.parse().unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn unsigned_round_trips() {
+        for value in [
+            U256::ZERO,
+            U256::ONE,
+            U256::new(u128::MAX),
+            U256::from_words(1, 0),
+            U256::MAX,
+        ] {
+            let js = BigInt::from(value);
+            assert_eq!(format!("{js}"), value.to_string());
+            assert_eq!(U256::try_from(js).unwrap(), value);
+            assert_eq!(U256::try_from(JsValue::from(value)).unwrap(), value);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn signed_round_trips() {
+        for value in [
+            I256::MIN,
+            I256::new(i128::MIN) - 1,
+            I256::MINUS_ONE,
+            I256::ZERO,
+            I256::from_words(0, -1),
+            I256::MAX,
+        ] {
+            let js = BigInt::from(value);
+            assert_eq!(format!("{js}"), value.to_string());
+            assert_eq!(I256::try_from(js).unwrap(), value);
+            assert_eq!(I256::try_from(JsValue::from(value)).unwrap(), value);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn out_of_range() {
+        let two_pow_256 = bigint(&U256::MAX.to_string()) + BigInt::from(1u32);
+        assert!(U256::try_from(bigint("-1")).is_err());
+        assert!(U256::try_from(two_pow_256.clone()).is_err());
+        assert!(I256::try_from(bigint(&(I256::MAX.as_u256() + 1).to_string())).is_err());
+        assert!(I256::try_from(-two_pow_256).is_err());
+
+        assert!(U256::try_from(JsValue::from(1.5)).is_err());
+        assert!(I256::try_from(JsValue::from_str("1")).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+//! Module that implements support for the [`zeroize`](https://crates.io/crates/zeroize) crate.
+//!
+//! The 256-bit integer types are `Copy`, so they cannot implement `Drop` and
+//! by extension `ZeroizeOnDrop`. Wrap secret values in `zeroize::Zeroizing` to
+//! have them wiped from memory when they go out of scope:
+//!
+//! ```
+//! # use ethnum::U256;
+//! use zeroize::Zeroizing;
+//!
+//! let secret = Zeroizing::new(U256::new(42));
+//! assert_eq!(*secret, 42);
+//! ```
+//!
+//! Keep in mind that copies of the value (for example, the operands and
+//! results of arithmetic operations) are not tracked and will not be wiped.
+
+use zeroize::DefaultIsZeroes;
+
+use crate::{I256, U256};
+
+// NOTE: `DefaultIsZeroes` provides a `Zeroize` implementation that overwrites
+// the value with `Default::default()` (i.e. `0`) using a volatile write, so the
+// compiler cannot elide it.
+impl DefaultIsZeroes for U256 {}
+impl DefaultIsZeroes for I256 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zeroize::Zeroize;
+
+    #[test]
+    fn zeroize() {
+        let mut x = U256::MAX;
+        x.zeroize();
+        assert_eq!(x, U256::ZERO);
+
+        let mut x = I256::MIN;
+        x.zeroize();
+        assert_eq!(x, I256::ZERO);
+
+        let mut xs = [U256::MAX; 4];
+        xs.zeroize();
+        assert_eq!(xs, [U256::ZERO; 4]);
+    }
+}
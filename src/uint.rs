@@ -8,22 +8,24 @@ mod iter;
 mod ops;
 mod parse;
 
-pub use self::convert::AsU256;
-use crate::I256;
-use core::num::ParseIntError;
+pub use self::convert::{AsU256, TryAsU256};
+use crate::{
+    error::{tfie, tfse},
+    CastTarget, I256,
+};
+use core::{
+    array::TryFromSliceError,
+    num::{ParseIntError, TryFromIntError},
+};
 
 /// A 256-bit unsigned integer type.
-#[derive(
-    Clone,
-    Copy,
-    Default,
-    Eq,
-    Hash,
-    PartialEq,
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Numeric),
+    diesel(sql_type = diesel::sql_types::Binary)
 )]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(transparent)]
 pub struct U256(pub [u128; 2]);
 
@@ -68,6 +70,56 @@ impl U256 {
         }
     }
 
+    /// Creates a new 256-bit integer value from 64-bit limbs.
+    ///
+    /// The limbs are in little endian order, that is, `limbs[0]` is the least
+    /// significant limb and `limbs[3]` the most significant one, regardless of
+    /// the target platform's endianness. This is the same layout used by the
+    /// `uint` crate's `construct_uint!` types, see the `impl_uint_conversions`
+    /// macro (enabled with the `parity-uint` feature) for generating
+    /// conversions to and from them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(
+    ///     U256::from_limbs([1, 2, 3, 4]),
+    ///     U256::from_words(0x4_0000000000000003, 0x2_0000000000000001),
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_limbs(limbs: [u64; 4]) -> Self {
+        let [a, b, c, d] = limbs;
+        U256::from_words((d as u128) << 64 | c as u128, (b as u128) << 64 | a as u128)
+    }
+
+    /// Splits a 256-bit integer into 64-bit limbs.
+    ///
+    /// The limbs are in little endian order, that is, the first limb is the
+    /// least significant one, regardless of the target platform's endianness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::new(42).into_limbs(), [42, 0, 0, 0]);
+    /// assert_eq!(U256::MAX.into_limbs(), [u64::MAX; 4]);
+    /// ```
+    #[inline]
+    pub const fn into_limbs(self) -> [u64; 4] {
+        let (hi, lo) = self.into_words();
+        [lo as _, (lo >> 64) as _, hi as _, (hi >> 64) as _]
+    }
+
+    /// Returns the 64-bit limbs of a 256-bit integer in little endian order.
+    ///
+    /// See [`U256::into_limbs`] for more details.
+    #[inline]
+    pub const fn as_limbs(&self) -> [u64; 4] {
+        self.into_limbs()
+    }
+
     /// Get the low 128-bit word for this unsigned integer.
     #[inline]
     pub fn low(&self) -> &u128 {
@@ -164,6 +216,398 @@ impl U256 {
         crate::parse::from_str_prefixed(src)
     }
 
+    /// Converts a string slice in base 16 to an integer, with an optional `0x`
+    /// or `0X` prefix.
+    ///
+    /// This is a more lenient version of [`U256::from_str_hex`] that accepts
+    /// the output of both `U256::to_hex_string` and unprefixed hexadecimal
+    /// strings in either case.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::from_hex("0x2a"), Ok(U256::new(42)));
+    /// assert_eq!(U256::from_hex("0X2A"), Ok(U256::new(42)));
+    /// assert_eq!(U256::from_hex("2a"), Ok(U256::new(42)));
+    /// assert!(U256::from_hex("0x").is_err());
+    /// ```
+    pub fn from_hex(src: &str) -> Result<Self, ParseIntError> {
+        crate::parse::from_hex(src)
+    }
+
+    /// Returns the `0x`-prefixed lowercase hexadecimal representation of this
+    /// integer without any leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::new(42).to_hex_string(), "0x2a");
+    /// assert_eq!(U256::ZERO.to_hex_string(), "0x0");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(&self) -> alloc::string::String {
+        alloc::format!("{self:#x}")
+    }
+
+    /// Returns the `0x`-prefixed lowercase hexadecimal representation of this
+    /// integer, zero-padded to the full 64 nibbles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(
+    ///     U256::new(42).to_hex_string_padded(),
+    ///     "0x000000000000000000000000000000000000000000000000000000000000002a",
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string_padded(&self) -> alloc::string::String {
+        alloc::format!("{self:#066x}")
+    }
+
+    /// Returns an iterator over the digits of this integer in the specified
+    /// radix, starting with the least significant digit.
+    ///
+    /// Radixes from `2` up to and including `256` are supported. Zero has a
+    /// single `0` digit, and no other value has most significant zero digits.
+    /// Use [`Iterator::collect`] and reverse the result for most significant
+    /// digit first order.
+    ///
+    /// Digits are extracted from the largest power of the radix that fits in a
+    /// `u64` at a time, so a full 256-bit division is only performed once for
+    /// every chunk of digits instead of once per digit.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 256.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let digits = U256::new(1234).to_digits(10).collect::<Vec<_>>();
+    /// assert_eq!(digits, [4, 3, 2, 1]);
+    /// assert_eq!(U256::ZERO.to_digits(58).collect::<Vec<_>>(), [0]);
+    /// assert_eq!(U256::MAX.to_digits(256).count(), 32);
+    /// ```
+    pub fn to_digits(self, radix: u32) -> impl Iterator<Item = u8> {
+        let (power, digits_per_chunk) = radix_chunk(radix);
+        let radix = radix as u64;
+
+        let mut value = self;
+        let (mut chunk, mut left) = if self == 0 { (0, 1) } else { (0, 0) };
+        let mut first = true;
+        core::iter::from_fn(move || {
+            if left == 0 {
+                if value == 0 {
+                    return None;
+                }
+                (value, chunk) = value.div_rem_u64(power);
+                left = digits_per_chunk;
+            }
+            if value == 0 && chunk == 0 && !first {
+                return None;
+            }
+
+            let digit = chunk % radix;
+            chunk /= radix;
+            left -= 1;
+            first = false;
+            Some(digit as _)
+        })
+    }
+
+    /// Creates an integer from its digits in the specified radix, starting
+    /// with the most significant digit. Returns `None` if any of the digits is
+    /// not smaller than the radix, or if the value doesn't fit in a `U256`.
+    ///
+    /// Radixes from `2` up to and including `256` are supported, and an empty
+    /// slice of digits represents zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 256.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::from_radix_digits_be(&[1, 2, 3, 4], 10), Some(U256::new(1234)));
+    /// assert_eq!(U256::from_radix_digits_be(&[10], 10), None);
+    /// assert_eq!(U256::from_radix_digits_be(&[1; 33], 256), None);
+    /// ```
+    pub fn from_radix_digits_be(digits: &[u8], radix: u32) -> Option<Self> {
+        let (power, digits_per_chunk) = radix_chunk(radix);
+        let radix = radix as u64;
+
+        // NOTE: Align chunks so that only the first one can be partial, this
+        // way all subsequent chunks are scaled by the same `power`.
+        let first = digits.len() % digits_per_chunk as usize;
+        let (head, tail) = digits.split_at(first);
+
+        let mut result = U256::ZERO;
+        for chunk in [head].into_iter().chain(tail.chunks(digits_per_chunk as _)) {
+            let mut value = 0;
+            for &digit in chunk {
+                if digit as u64 >= radix {
+                    return None;
+                }
+                value = value * radix + digit as u64;
+            }
+            let scale = if chunk.len() == digits_per_chunk as usize {
+                power
+            } else {
+                radix.pow(chunk.len() as _)
+            };
+            result = result.checked_mul_add_u64(scale, value)?;
+        }
+        Some(result)
+    }
+
+    /// Scales a token amount by the specified number of decimals, i.e.
+    /// computes `value * 10^decimals`. Returns `None` if the result overflows,
+    /// or if `decimals` is larger than 77 (since `10^decimals` doesn't fit in
+    /// a `U256` in that case).
+    ///
+    /// This is the equivalent of `Decimal256::from_atomics` or `parse_units`
+    /// from `ethers` for integer values.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// // 42 USDC, which has 6 decimals.
+    /// assert_eq!(U256::from_units(42u32, 6), Some(U256::new(42_000_000)));
+    /// // 1 WBTC, which has 8 decimals.
+    /// assert_eq!(U256::from_units(1u32, 8), Some(U256::new(100_000_000)));
+    /// // 3 ETH, which has 18 decimals.
+    /// assert_eq!(U256::from_units(3u32, 18), Some(U256::new(3_000_000_000_000_000_000)));
+    /// assert_eq!(U256::from_units(1u32, 77), U256::new(10).checked_pow(77));
+    /// assert_eq!(U256::from_units(1u32, 78), None);
+    /// assert_eq!(U256::from_units(12u32, 76), None);
+    /// ```
+    pub fn from_units(value: impl Into<U256>, decimals: u32) -> Option<Self> {
+        U256::new(10)
+            .checked_pow(decimals)?
+            .checked_mul(value.into())
+    }
+
+    /// Splits a scaled token amount into its integer part and remainder for
+    /// the specified number of decimals, i.e. computes `self / 10^decimals`
+    /// and `self % 10^decimals`.
+    ///
+    /// If `10^decimals` does not fit in a `U256`, then the integer part is
+    /// zero and the remainder is `self`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// // 42.5 USDC, which has 6 decimals.
+    /// assert_eq!(U256::new(42_500_000).to_units(6), (U256::new(42), U256::new(500_000)));
+    /// // 0.00000001 WBTC, which has 8 decimals.
+    /// assert_eq!(U256::new(1).to_units(8), (U256::ZERO, U256::ONE));
+    /// // 1.5 ETH, which has 18 decimals.
+    /// assert_eq!(
+    ///     U256::new(1_500_000_000_000_000_000).to_units(18),
+    ///     (U256::ONE, U256::new(500_000_000_000_000_000)),
+    /// );
+    /// assert_eq!(U256::MAX.to_units(78), (U256::ZERO, U256::MAX));
+    /// ```
+    pub fn to_units(self, decimals: u32) -> (Self, Self) {
+        match U256::new(10).checked_pow(decimals) {
+            Some(scale) => (self / scale, self % scale),
+            None => (U256::ZERO, self),
+        }
+    }
+
+    /// Divides by a 64-bit divisor, returning the quotient and remainder.
+    pub(crate) fn div_rem_u64(self, divisor: u64) -> (Self, u64) {
+        let mut limbs = self.into_limbs();
+        let mut rem = 0;
+        for limb in limbs.iter_mut().rev() {
+            let n = ((rem as u128) << 64) | *limb as u128;
+            *limb = (n / divisor as u128) as _;
+            rem = (n % divisor as u128) as _;
+        }
+        (Self::from_limbs(limbs), rem)
+    }
+
+    /// Computes `self * mul + add`, returning `None` on overflow.
+    pub(crate) fn checked_mul_add_u64(self, mul: u64, add: u64) -> Option<Self> {
+        let mut limbs = self.into_limbs();
+        let mut carry = add;
+        for limb in limbs.iter_mut() {
+            let n = (*limb as u128) * (mul as u128) + carry as u128;
+            *limb = n as _;
+            carry = (n >> 64) as _;
+        }
+        if carry != 0 {
+            return None;
+        }
+        Some(Self::from_limbs(limbs))
+    }
+
+    /// Writes the memory representation of this integer as a byte array in
+    /// big endian (network) byte order directly into a buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let mut buf = [0; 32];
+    /// U256::new(0x0102).to_be_bytes_into(&mut buf);
+    /// assert_eq!(buf[30..], [0x01, 0x02]);
+    /// assert_eq!(buf, U256::new(0x0102).to_be_bytes());
+    /// ```
+    #[inline]
+    pub fn to_be_bytes_into(&self, buf: &mut [u8; 32]) {
+        let (hi, lo) = self.into_words();
+        buf[..16].copy_from_slice(&hi.to_be_bytes());
+        buf[16..].copy_from_slice(&lo.to_be_bytes());
+    }
+
+    /// Writes the memory representation of this integer as a byte array in
+    /// little endian byte order directly into a buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let mut buf = [0; 32];
+    /// U256::new(0x0102).to_le_bytes_into(&mut buf);
+    /// assert_eq!(buf[..2], [0x02, 0x01]);
+    /// assert_eq!(buf, U256::new(0x0102).to_le_bytes());
+    /// ```
+    #[inline]
+    pub fn to_le_bytes_into(&self, buf: &mut [u8; 32]) {
+        let (hi, lo) = self.into_words();
+        buf[..16].copy_from_slice(&lo.to_le_bytes());
+        buf[16..].copy_from_slice(&hi.to_le_bytes());
+    }
+
+    /// Writes the big endian representation of this integer into a buffer and
+    /// returns the minimal-length suffix with all leading zero bytes stripped.
+    ///
+    /// Note that zero is encoded as an empty slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let mut buf = [0; 32];
+    /// assert_eq!(U256::new(0x0102).to_be_bytes_trimmed(&mut buf), [0x01, 0x02]);
+    /// assert_eq!(U256::new(128).to_be_bytes_trimmed(&mut buf), [0x80]);
+    /// assert_eq!(U256::ZERO.to_be_bytes_trimmed(&mut buf), [0u8; 0]);
+    /// ```
+    pub fn to_be_bytes_trimmed<'a>(&self, buf: &'a mut [u8; 32]) -> &'a [u8] {
+        self.to_be_bytes_into(buf);
+        &buf[(self.leading_zeros() / 8) as usize..]
+    }
+
+    /// Returns the big endian representation of this integer with all leading
+    /// zero bytes stripped.
+    ///
+    /// See [`U256::to_be_bytes_trimmed`] for more details.
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes_trimmed_vec(&self) -> alloc::vec::Vec<u8> {
+        self.to_be_bytes_trimmed(&mut [0; 32]).to_vec()
+    }
+
+    /// Create an integer value from a big endian byte slice of up to 32 bytes.
+    ///
+    /// The slice is interpreted as a right-aligned big endian number, that is,
+    /// shorter slices are zero-extended. Returns an error if the slice is
+    /// longer than 32 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::from_be_slice(&[0x01, 0x02]).ok(), Some(U256::new(0x0102)));
+    /// assert_eq!(U256::from_be_slice(&[]).ok(), Some(U256::ZERO));
+    /// assert_eq!(U256::from_be_slice(&[0xff; 32]).ok(), Some(U256::MAX));
+    /// assert!(U256::from_be_slice(&[0; 33]).is_err());
+    /// ```
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, TryFromSliceError> {
+        let offset = 32usize.checked_sub(bytes.len()).ok_or(tfse())?;
+        let mut buf = [0; 32];
+        buf[offset..].copy_from_slice(bytes);
+        Ok(Self::from_be_bytes(buf))
+    }
+
+    /// Create an integer value from a little endian byte slice of up to 32
+    /// bytes.
+    ///
+    /// The slice is interpreted as a left-aligned little endian number, that
+    /// is, shorter slices are zero-extended. Returns an error if the slice is
+    /// longer than 32 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::from_le_slice(&[0x02, 0x01]).ok(), Some(U256::new(0x0102)));
+    /// assert_eq!(U256::from_le_slice(&[]).ok(), Some(U256::ZERO));
+    /// assert!(U256::from_le_slice(&[0; 33]).is_err());
+    /// ```
+    pub fn from_le_slice(bytes: &[u8]) -> Result<Self, TryFromSliceError> {
+        if bytes.len() > 32 {
+            return Err(tfse());
+        }
+        let mut buf = [0; 32];
+        buf[..bytes.len()].copy_from_slice(bytes);
+        Ok(Self::from_le_bytes(buf))
+    }
+
+    /// Returns an order-preserving byte encoding of this integer, such that
+    /// comparing the encodings of two values lexicographically gives the same
+    /// result as comparing the values numerically. This makes it suitable for
+    /// use as a database or storage key.
+    ///
+    /// For `U256` this is the big endian byte representation. The encoding is
+    /// stable and will not change across versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert!(U256::new(255).to_key_bytes() < U256::new(256).to_key_bytes());
+    /// assert_eq!(U256::new(1).to_key_bytes()[31], 1);
+    /// ```
+    #[inline]
+    pub fn to_key_bytes(self) -> [u8; 32] {
+        self.to_be_bytes()
+    }
+
+    /// Decodes an integer from its order-preserving byte encoding, as created
+    /// by [`U256::to_key_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let key = U256::MAX.to_key_bytes();
+    /// assert_eq!(U256::from_key_bytes(key), U256::MAX);
+    /// ```
+    #[inline]
+    pub fn from_key_bytes(bytes: [u8; 32]) -> Self {
+        Self::from_be_bytes(bytes)
+    }
+
     /// Cast to a primitive `i8`.
     pub const fn as_i8(self) -> i8 {
         let (_, lo) = self.into_words();
@@ -194,12 +638,65 @@ impl U256 {
         lo as _
     }
 
+    /// Converts to a primitive integer type, saturating at the target type's
+    /// numeric bounds instead of truncating like the `as_*` casts do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::new(1000).saturating_to::<u8>(), 255);
+    /// assert_eq!(U256::MAX.saturating_to::<i64>(), i64::MAX);
+    /// assert_eq!(U256::new(42).saturating_to::<u64>(), 42);
+    /// ```
+    #[inline]
+    pub fn saturating_to<T: CastTarget>(self) -> T {
+        T::saturating_from_u256(self)
+    }
+
+    /// Converts to a primitive integer type, keeping only the lowest bits
+    /// that fit in the target type. This is equivalent to an `as` cast.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::new(0x1ff).truncate_to::<u8>(), 0xff);
+    /// assert_eq!(U256::MAX.truncate_to::<i64>(), -1);
+    /// ```
+    #[inline]
+    pub fn truncate_to<T: CastTarget>(self) -> T {
+        T::truncating_from_u256(self)
+    }
+
     /// Cast to a `I256`.
     pub const fn as_i256(self) -> I256 {
         let Self([a, b]) = self;
         I256([a as _, b as _])
     }
 
+    /// Converts to a `I256`, returning an error if the value is larger than
+    /// [`I256::MAX`] instead of reinterpreting it as a negative number like
+    /// [`U256::as_i256`] does.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::{I256, U256};
+    /// assert_eq!(U256::new(42).try_into_signed(), Ok(I256::new(42)));
+    /// assert_eq!(I256::MAX.as_u256().try_into_signed(), Ok(I256::MAX));
+    /// assert!(U256::MAX.try_into_signed().is_err());
+    /// ```
+    pub const fn try_into_signed(self) -> Result<I256, TryFromIntError> {
+        let (hi, _) = self.into_words();
+        if hi > i128::MAX as u128 {
+            return Err(tfie());
+        }
+        Ok(self.as_i256())
+    }
+
     /// Cast to a primitive `u8`.
     pub const fn as_u8(self) -> u8 {
         let (_, lo) = self.into_words();
@@ -243,30 +740,443 @@ impl U256 {
     }
 
     /// Cast to a primitive `f32`.
+    ///
+    /// The value is rounded to the nearest representable `f32`, with ties
+    /// rounding to even, and values exceeding `f32::MAX` converting to
+    /// `f32::INFINITY`.
     pub fn as_f32(self) -> f32 {
-        match self.into_words() {
-            (0, lo) => lo as _,
-            _ => f32::INFINITY,
-        }
+        let (bits, exp) = self.to_f64_parts();
+        let scale = if exp < f32::MAX_EXP as u32 {
+            f32::from_bits((exp + 127) << 23)
+        } else {
+            f32::INFINITY
+        };
+        (bits as f32) * scale
     }
 
     /// Cast to a primitive `f64`.
+    ///
+    /// The value is rounded to the nearest representable `f64`, with ties
+    /// rounding to even.
     pub fn as_f64(self) -> f64 {
-        // NOTE: Binary representation of 2**128. This is used because `powi` is
-        // neither `const` nor `no_std`.
-        const HI: u64 = 0x47f0000000000000;
-        let (hi, lo) = self.into_words();
-        (hi as f64) * f64::from_bits(HI) + (lo as f64)
+        // NOTE: Scale by constructing the binary representation of `2^exp`
+        // directly. This is used because `powi` is not `no_std`.
+        let (bits, exp) = self.to_f64_parts();
+        (bits as f64) * f64::from_bits((exp as u64 + 1023) << 52)
+    }
+
+    /// Splits the integer into its 64 most significant bits and an exponent,
+    /// such that the value can be correctly rounded to a floating point number
+    /// by converting the bits to a float and scaling it by `2^exp`.
+    ///
+    /// The least significant bit of the returned bits is a "sticky" bit that
+    /// is set if any of the truncated bits are non-zero. This ensures that the
+    /// primitive `u64` to float conversion rounds exactly as if all 256 bits
+    /// were taken into account (64 bits is more than enough to accommodate the
+    /// mantissa, rounding and sticky bits of both `f32` and `f64`).
+    fn to_f64_parts(self) -> (u64, u32) {
+        let exp = 192u32.saturating_sub(self.leading_zeros());
+        if exp == 0 {
+            return (self.as_u64(), 0);
+        }
+        let bits = (self >> exp).as_u64();
+        let sticky = (self << (256 - exp)) != 0;
+        (bits | sticky as u64, exp)
     }
 }
 
+/// Returns the largest power of `radix` that fits in a `u64` along with its
+/// exponent.
+fn radix_chunk(radix: u32) -> (u64, u32) {
+    assert!(
+        (2..=256).contains(&radix),
+        "radix must lie in the range `[2, 256]` - found {}",
+        radix
+    );
+
+    let radix = radix as u64;
+    let (mut power, mut exp) = (radix, 1);
+    while let Some(next) = power.checked_mul(radix) {
+        power = next;
+        exp += 1;
+    }
+    (power, exp)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::uint::U256;
+    use crate::{error::tfie, int::I256, uint::U256};
+
+    #[test]
+    fn const_bit_casts() {
+        const SIGNED: I256 = U256::MAX.cast_signed();
+        const AS_SIGNED: I256 = U256::MAX.as_i256();
+        const UNSIGNED: U256 = I256::MIN.cast_unsigned();
+        const AS_UNSIGNED: U256 = I256::MIN.as_u256();
+
+        assert_eq!(SIGNED, I256::MINUS_ONE);
+        assert_eq!(SIGNED, AS_SIGNED);
+        assert_eq!(UNSIGNED, U256::ONE << 255u32);
+        assert_eq!(UNSIGNED, AS_UNSIGNED);
+    }
 
     #[test]
     #[allow(clippy::float_cmp)]
     fn converts_to_f64() {
         assert_eq!(U256::from_words(1, 0).as_f64(), 2.0f64.powi(128))
     }
+
+    #[test]
+    fn converts_to_f64_with_single_rounding() {
+        // Reference values computed with an exact integer to float conversion
+        // that rounds to nearest, ties to even.
+        for (hi, lo, bits) in [
+            (
+                0xffffffffffffffffffffffffffffffff,
+                0xffffffffffffffffffffffffffffffff,
+                0x4ff0000000000000,
+            ),
+            (
+                0x7fffffffffffffffffffffffffffffff,
+                0xffffffffffffffffffffffffffffffff,
+                0x4fe0000000000000,
+            ),
+            (
+                0x3fffffffffffffffffffffffffffffff,
+                0xffffffffffffffffffffffffffffffff,
+                0x4fd0000000000000,
+            ),
+            (
+                0x80000000000004000000000000000000,
+                0x00000000000000000000000000000000,
+                0x4fe0000000000000,
+            ),
+            (
+                0x80000000000004000000000000000000,
+                0x00000000000000000000000000000001,
+                0x4fe0000000000001,
+            ),
+            (
+                0x8000000000000c000000000000000000,
+                0x00000000000000000000000000000000,
+                0x4fe0000000000002,
+            ),
+            (
+                0x8000000000000bffffffffffffffffff,
+                0xffffffffffffffffffffffffffffffff,
+                0x4fe0000000000001,
+            ),
+            (
+                0x00000000000000ffffffffffffffffff,
+                0xffffffffffffffffffffffffffffffff,
+                0x4c70000000000000,
+            ),
+            (
+                0x00000000000000000000000000000001,
+                0xffffffffffffffffffffffffffffffff,
+                0x4800000000000000,
+            ),
+            (
+                0x00000000000000000000000000000001,
+                0x00000000000008000000000000000000,
+                0x47f0000000000000,
+            ),
+            (
+                0x00000000000000000000000000000001,
+                0x00000000000008000000000000000001,
+                0x47f0000000000001,
+            ),
+            (
+                0x00000000000000000000000000000001,
+                0x00000000000018000000000000000000,
+                0x47f0000000000002,
+            ),
+            (
+                0xfffffffffffff8000000000000000000,
+                0x00000000000000000000000000000000,
+                0x4fefffffffffffff,
+            ),
+            (
+                0xfffffffffffffc000000000000000000,
+                0x00000000000000000000000000000000,
+                0x4ff0000000000000,
+            ),
+            (
+                0x0fffffffffffffffffffffffffffffff,
+                0xf0000000000000000000000000000000,
+                0x4fb0000000000000,
+            ),
+            (
+                0x00000000000000010000000000000000,
+                0x00000000000000000000000000000001,
+                0x4bf0000000000000,
+            ),
+        ] {
+            assert_eq!(U256::from_words(hi, lo).as_f64().to_bits(), bits);
+        }
+
+        for x in [0, 1, 42, u64::MAX as u128, u128::MAX >> 11, u128::MAX] {
+            assert_eq!(U256::new(x).as_f64().to_bits(), (x as f64).to_bits());
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn converts_to_f32() {
+        assert_eq!(U256::ZERO.as_f32(), 0.0);
+        assert_eq!(U256::new(42).as_f32(), 42.0);
+        assert_eq!(U256::new(u128::MAX).as_f32(), u128::MAX as f32);
+
+        // Largest value still rounding down to `f32::MAX`, and smallest value
+        // rounding up to infinity. Note that `f32::MAX < 2^128`, so any value
+        // with a non-zero high word is out of range.
+        let max = U256::new(f32::MAX as u128);
+        let half_ulp = U256::ONE << (127u32 - 24);
+        assert_eq!(max.as_f32(), f32::MAX);
+        assert_eq!((max + half_ulp - U256::ONE).as_f32(), f32::MAX);
+        assert_eq!((max + half_ulp).as_f32(), f32::INFINITY);
+        assert_eq!(U256::from_words(1, 0).as_f32(), f32::INFINITY);
+        assert_eq!(U256::MAX.as_f32(), f32::INFINITY);
+
+        // Random values shifted over the whole range convert exactly as the
+        // corresponding `u128` to `f32` conversion scaled by a power of two.
+        let mut state = 0x2545f4914f6cdd1d_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..1000 {
+            let window = (next() as u128) << 64 | next() as u128;
+            let shift = (next() % 128) as u32;
+            let x = U256::new(window) << shift;
+            let scale = 2.0f32.powi(shift as _);
+            assert_eq!(x.as_f32(), (window as f32) * scale);
+            assert_eq!(x.as_f32(), x.as_f64() as f32);
+        }
+    }
+
+    #[test]
+    fn slice_round_trips() {
+        let x = U256::from_words(
+            0x00010203_04050607_08090a0b_0c0d0e0f,
+            0x10111213_14151617_18191a1b_1c1d1e1f,
+        );
+        let (be, le) = (x.to_be_bytes(), x.to_le_bytes());
+        assert_eq!(U256::from_be_slice(&[]).ok(), Some(U256::ZERO));
+        assert_eq!(U256::from_le_slice(&[]).ok(), Some(U256::ZERO));
+        for n in 1..=32 {
+            let expected = x & (U256::MAX >> (8 * (32 - n) as u32));
+            assert_eq!(U256::from_be_slice(&be[32 - n..]).ok(), Some(expected));
+            assert_eq!(U256::from_le_slice(&le[..n]).ok(), Some(expected));
+        }
+
+        let mut buf = [0; 32];
+        x.to_be_bytes_into(&mut buf);
+        assert_eq!(buf, be);
+        x.to_le_bytes_into(&mut buf);
+        assert_eq!(buf, le);
+
+        assert!(U256::from_be_slice(&[0; 33]).is_err());
+        assert!(U256::from_le_slice(&[0; 33]).is_err());
+    }
+
+    #[test]
+    fn to_be_bytes_trimmed() {
+        let mut buf = [0; 32];
+        assert_eq!(U256::ZERO.to_be_bytes_trimmed(&mut buf), [0u8; 0]);
+        assert_eq!(U256::ONE.to_be_bytes_trimmed(&mut buf), [0x01]);
+        assert_eq!(U256::new(0xff).to_be_bytes_trimmed(&mut buf), [0xff]);
+        assert_eq!(U256::new(0x100).to_be_bytes_trimmed(&mut buf), [0x01, 0x00]);
+        assert_eq!(U256::MAX.to_be_bytes_trimmed(&mut buf), [0xff; 32]);
+
+        for i in 0..256u32 {
+            let x = U256::ONE << i;
+            let trimmed = x.to_be_bytes_trimmed(&mut buf);
+            assert_eq!(trimmed.len(), (i / 8 + 1) as usize);
+            assert_eq!(U256::from_be_slice(trimmed).ok(), Some(x));
+        }
+    }
+
+    #[test]
+    fn limbs() {
+        const X: U256 = U256::from_limbs([
+            0x1011121314151617,
+            0x18191a1b1c1d1e1f,
+            0x0001020304050607,
+            0x08090a0b0c0d0e0f,
+        ]);
+        assert_eq!(
+            X,
+            U256::from_words(
+                0x08090a0b0c0d0e0f_0001020304050607,
+                0x18191a1b1c1d1e1f_1011121314151617,
+            ),
+        );
+
+        // Limbs are defined in terms of numerical value, so their order is
+        // independent of the in-memory word order.
+        for i in 0..4 {
+            let mut limbs = [0; 4];
+            limbs[i] = 0xff;
+            let x = U256::from_limbs(limbs);
+            assert_eq!(x, U256::new(0xff) << (64 * i as u32));
+            assert_eq!(x.into_limbs(), limbs);
+            assert_eq!(x.as_limbs(), limbs);
+            assert_eq!(x.to_le_bytes()[8 * i], 0xff);
+        }
+
+        for x in [U256::ZERO, U256::ONE, U256::MAX, X, X.swap_bytes()] {
+            assert_eq!(U256::from_limbs(x.into_limbs()), x);
+        }
+    }
+
+    #[test]
+    fn try_into_signed_boundary() {
+        let max = U256::from_words(i128::MAX as _, u128::MAX);
+        assert_eq!(max.try_into_signed(), Ok(I256::MAX));
+        assert_eq!(I256::try_from(max), Ok(I256::MAX));
+        assert_eq!((max + 1).try_into_signed(), Err(tfie()));
+        assert_eq!(I256::try_from(max + 1), Err(tfie()));
+        assert_eq!(U256::MAX.try_into_signed(), Err(tfie()));
+        assert_eq!(U256::ZERO.try_into_signed(), Ok(I256::ZERO));
+    }
+
+    #[test]
+    fn key_bytes_preserve_order() {
+        let mut values = alloc::vec![
+            U256::MAX,
+            U256::ZERO,
+            U256::new(u128::MAX) + 1,
+            U256::ONE << 255u32,
+            U256::new(255),
+            U256::ONE,
+            U256::new(256),
+            U256::MAX - 1,
+            U256::new(u128::MAX),
+        ];
+        let mut keys = values
+            .iter()
+            .map(|x| x.to_key_bytes())
+            .collect::<alloc::vec::Vec<_>>();
+        values.sort();
+        keys.sort();
+
+        let decoded = keys
+            .into_iter()
+            .map(U256::from_key_bytes)
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(decoded, values);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn hex_string_round_trip() {
+        for x in [U256::ZERO, U256::ONE, U256::MAX, U256::new(u128::MAX) + 1] {
+            assert_eq!(U256::from_hex(&x.to_hex_string()), Ok(x));
+            assert_eq!(U256::from_str_hex(&x.to_hex_string()), Ok(x));
+            assert_eq!(U256::from_hex(&x.to_hex_string_padded()), Ok(x));
+            assert_eq!(x.to_hex_string_padded().len(), 66);
+        }
+        assert_eq!(
+            U256::MAX.to_hex_string(),
+            alloc::format!("0x{}", "f".repeat(64))
+        );
+        assert_eq!(U256::from_hex("0XFF"), Ok(U256::new(255)));
+        assert!(U256::from_hex("-0x1").is_err());
+        assert!(U256::from_hex("").is_err());
+    }
+
+    #[test]
+    fn isqrt() {
+        for x in (0..10_000u128).chain([u64::MAX as u128, u128::MAX - 1, u128::MAX]) {
+            assert_eq!(U256::new(x).isqrt(), U256::new(x.isqrt()));
+        }
+
+        assert_eq!(U256::MAX.isqrt(), U256::new(u128::MAX));
+        let root = U256::new(u128::MAX);
+        assert_eq!((root * root).isqrt(), root);
+        assert_eq!((root * root - 1).isqrt(), root - 1);
+        let root = U256::new(0x1234_5678_9abc_def0_1234_5678_9abc_def0);
+        assert_eq!((root * root + root * 2).isqrt(), root);
+        assert_eq!((root * root + root * 2 + 1).isqrt(), root + 1);
+    }
+
+    #[test]
+    fn units() {
+        for decimals in 0..=77 {
+            let scale = U256::new(10).pow(decimals);
+            assert_eq!(U256::from_units(1u32, decimals), Some(scale));
+            assert_eq!(
+                U256::from_units(U256::MAX / scale, decimals),
+                Some(U256::MAX / scale * scale)
+            );
+            if let Some(next) = (U256::MAX / scale).checked_add(U256::ONE) {
+                assert_eq!(U256::from_units(next, decimals), None);
+            }
+            assert_eq!(
+                U256::MAX.to_units(decimals),
+                (U256::MAX / scale, U256::MAX % scale)
+            );
+            if (1..=76).contains(&decimals) {
+                assert_eq!(
+                    (scale * 3 + 7).to_units(decimals),
+                    (U256::new(3), U256::new(7))
+                );
+            }
+        }
+        assert_eq!(U256::from_units(0u32, 78), None);
+        assert_eq!(U256::ONE.to_units(u32::MAX), (U256::ZERO, U256::ONE));
+    }
+
+    #[test]
+    fn radix_digits() {
+        let mut rng = 0x2545f4914f6cdd1du64;
+        let mut next = || {
+            rng ^= rng << 13;
+            rng ^= rng >> 7;
+            rng ^= rng << 17;
+            rng
+        };
+
+        for radix in 2..=256u32 {
+            let mut values = alloc::vec![U256::ZERO, U256::ONE, U256::MAX, U256::new(radix as _)];
+            for _ in 0..8 {
+                let x = U256::from_limbs([next(), next(), next(), next()]);
+                values.push(x >> (next() % 256) as u32);
+            }
+
+            for x in values {
+                let digits = x.to_digits(radix).collect::<alloc::vec::Vec<_>>();
+                assert!(digits.iter().all(|&d| (d as u32) < radix));
+                assert!(digits.len() == 1 || digits.last() != Some(&0));
+
+                // NOTE: Check against naive repeated division.
+                let mut naive = alloc::vec::Vec::new();
+                let mut y = x;
+                loop {
+                    naive.push((y % radix as u128).as_u8());
+                    y /= radix as u128;
+                    if y == 0 {
+                        break;
+                    }
+                }
+                assert_eq!(digits, naive);
+
+                let be = digits.iter().rev().copied().collect::<alloc::vec::Vec<_>>();
+                assert_eq!(U256::from_radix_digits_be(&be, radix), Some(x));
+            }
+        }
+
+        assert_eq!(U256::from_radix_digits_be(&[], 10), Some(U256::ZERO));
+        assert_eq!(U256::from_radix_digits_be(&[0; 100], 2), Some(U256::ZERO));
+        assert_eq!(U256::from_radix_digits_be(&[1; 256], 2), Some(U256::MAX));
+        assert_eq!(U256::from_radix_digits_be(&[1; 257], 2), None);
+        let mut max = U256::MAX.to_digits(58).collect::<alloc::vec::Vec<_>>();
+        max.reverse();
+        *max.last_mut().unwrap() += 1;
+        assert_eq!(U256::from_radix_digits_be(&max, 58), None);
+        assert_eq!(U256::from_radix_digits_be(&[2], 2), None);
+    }
 }
@@ -2,7 +2,7 @@
 //! standard library API for `uN` types.
 
 use super::U256;
-use crate::intrinsics;
+use crate::{intrinsics, I256};
 use core::{
     mem::{self, MaybeUninit},
     num::ParseIntError,
@@ -175,6 +175,27 @@ impl U256 {
         (!self).trailing_zeros()
     }
 
+    /// Returns the bit pattern of `self` reinterpreted as a signed integer of
+    /// the same size.
+    ///
+    /// This produces the same result as [`U256::as_i256`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::{I256, U256};
+    /// let n = U256::MAX;
+    /// assert_eq!(n.cast_signed(), -1);
+    /// let n = U256::ONE << 255u32;
+    /// assert_eq!(n.cast_signed(), I256::MIN);
+    /// ```
+    #[inline]
+    pub const fn cast_signed(self) -> I256 {
+        self.as_i256()
+    }
+
     /// Shifts the bits to the left by a specified amount, `n`, wrapping the
     /// truncated bits to the end of the resulting integer.
     ///
@@ -685,7 +706,6 @@ impl U256 {
     /// assert_eq!(U256::new(100).saturating_add(U256::new(1)), U256::new(101));
     /// assert_eq!(U256::MAX.saturating_add(U256::new(127)), U256::MAX);
     /// ```
-
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
     #[inline]
@@ -1163,6 +1183,29 @@ impl U256 {
         (unsafe { result.assume_init() }, overflow)
     }
 
+    /// Calculates the complete product `self * rhs` without the possibility
+    /// to overflow.
+    ///
+    /// This returns the low-order (wrapping) bits and the high-order (overflow)
+    /// bits of the result as two separate values, in that order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::new(5).widening_mul(U256::new(2)), (U256::new(10), U256::ZERO));
+    /// assert_eq!(U256::MAX.widening_mul(U256::new(2)), (U256::MAX - 1, U256::ONE));
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let (high, low) = intrinsics::umul512(&self, &rhs);
+        (low, high)
+    }
+
     /// Calculates the divisor when `self` is divided by `rhs`.
     ///
     /// Returns a tuple of the divisor along with a boolean indicating whether
@@ -1431,6 +1474,39 @@ impl U256 {
         acc
     }
 
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::new(10).isqrt(), U256::new(3));
+    /// assert_eq!(U256::MAX.isqrt(), U256::new(u128::MAX));
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn isqrt(self) -> Self {
+        if self < 2 {
+            return self;
+        }
+
+        // Newton's method, starting from a power of two that is guaranteed to
+        // be greater than or equal to the square root so that the sequence of
+        // estimates decreases monotonically.
+        let bits = 256 - self.leading_zeros();
+        let mut x = U256::ONE << bits.div_ceil(2);
+        loop {
+            let y = (x + self / x) >> 1;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
     /// Performs Euclidean division.
     ///
     /// Since, for the positive integers, all common definitions of division are
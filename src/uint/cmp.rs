@@ -9,8 +9,18 @@
 //! assert_eq!(U256::new(42), 42);
 //! assert!(U256::ONE > 0 && U256::ZERO == 0);
 //! ```
+//!
+//! Comparisons with `f32` and `f64` are exact, comparing the integer against
+//! the real value of the float without converting either side:
+//!
+//! ```
+//! # use ethnum::U256;
+//! assert!(U256::ONE > 0.5 && U256::ONE < 1.5);
+//! assert!(U256::MAX < 2.0f64.powi(256) && U256::ZERO > -1.0);
+//! assert!(U256::ZERO.partial_cmp(&f64::NAN).is_none());
+//! ```
 
-use crate::uint::U256;
+use crate::uint::{AsU256 as _, U256};
 use core::cmp::Ordering;
 
 impl Ord for U256 {
@@ -24,6 +34,31 @@ impl_cmp! {
     impl Cmp for U256 (u128);
 }
 
+/// Compares an integer with the exact value of a float.
+fn cmp_f64(a: &U256, b: f64) -> Option<Ordering> {
+    /// 2^256, the first float that is larger than all 256-bit integers.
+    const MAX: f64 = f64::from_bits(0x4ff0000000000000);
+
+    if b.is_nan() {
+        return None;
+    }
+    if b < 0.0 {
+        return Some(Ordering::Greater);
+    }
+    if b >= MAX {
+        return Some(Ordering::Less);
+    }
+    // Converting the float truncates it exactly since it is in range, and
+    // its integer part converts back to a float exactly. The integers only
+    // compare equal if the float has no fractional part.
+    let int = b.as_u256();
+    Some(a.cmp(&int).then(int.as_f64().partial_cmp(&b)?))
+}
+
+impl_cmp_float! {
+    impl CmpFloat for U256 (cmp_f64);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,4 +80,66 @@ mod tests {
         assert!(x <= y);
         assert_eq!(x.cmp(&y), Ordering::Equal);
     }
+
+    #[test]
+    fn cmp_float() {
+        let pow2 = |n: i32| 2.0f64.powi(n);
+        let p53 = U256::ONE << 53u32;
+        for (int, float, ordering) in [
+            (U256::ZERO, 0.0, Some(Ordering::Equal)),
+            (U256::ZERO, -0.0, Some(Ordering::Equal)),
+            (U256::ZERO, -0.5, Some(Ordering::Greater)),
+            (U256::ZERO, 0.5, Some(Ordering::Less)),
+            (U256::ONE, 0.5, Some(Ordering::Greater)),
+            (U256::ONE, 1.5, Some(Ordering::Less)),
+            (U256::ZERO, f64::MIN_POSITIVE, Some(Ordering::Less)),
+            (U256::MAX, -1.0, Some(Ordering::Greater)),
+            (U256::ZERO, f64::NEG_INFINITY, Some(Ordering::Greater)),
+            (U256::MAX, f64::INFINITY, Some(Ordering::Less)),
+            (U256::ZERO, f64::NAN, None),
+            // 2^53 + 1 is the first integer that isn't exactly representable.
+            (p53, pow2(53), Some(Ordering::Equal)),
+            (p53 + 1, pow2(53), Some(Ordering::Greater)),
+            (p53 + 1, pow2(53) + 2.0, Some(Ordering::Less)),
+            (p53 - 1, pow2(53) - 1.0, Some(Ordering::Equal)),
+            (p53 - 1, pow2(53) - 0.5, Some(Ordering::Less)),
+            // 2^256 - 1 rounds to 2^256 when converted to a float.
+            (U256::MAX, U256::MAX.as_f64(), Some(Ordering::Less)),
+            (U256::MAX, pow2(255), Some(Ordering::Greater)),
+            (U256::ONE << 255, pow2(255), Some(Ordering::Equal)),
+            (
+                (U256::ONE << 255u32) + 1,
+                pow2(255),
+                Some(Ordering::Greater),
+            ),
+            ((U256::ONE << 255u32) - 1, pow2(255), Some(Ordering::Less)),
+            (U256::MAX, f64::MAX, Some(Ordering::Less)),
+        ] {
+            assert_eq!(int.partial_cmp(&float), ordering, "{int} <=> {float}");
+            assert_eq!(
+                float.partial_cmp(&int),
+                ordering.map(Ordering::reverse),
+                "{float} <=> {int}"
+            );
+            assert_eq!(int == float, ordering == Some(Ordering::Equal));
+            assert_eq!(float == int, ordering == Some(Ordering::Equal));
+        }
+
+        let p24 = U256::ONE << 24u32;
+        for (int, float, ordering) in [
+            (U256::ONE, 0.5f32, Some(Ordering::Greater)),
+            (p24, 2.0f32.powi(24), Some(Ordering::Equal)),
+            (p24 + 1, 2.0f32.powi(24), Some(Ordering::Greater)),
+            (U256::MAX, f32::MAX, Some(Ordering::Greater)),
+            (U256::MAX, f32::INFINITY, Some(Ordering::Less)),
+            (U256::ZERO, f32::NAN, None),
+        ] {
+            assert_eq!(int.partial_cmp(&float), ordering, "{int} <=> {float}");
+            assert_eq!(
+                float.partial_cmp(&int),
+                ordering.map(Ordering::reverse),
+                "{float} <=> {int}"
+            );
+        }
+    }
 }
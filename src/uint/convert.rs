@@ -1,8 +1,17 @@
 //! Module contains conversions for [`U256`] to and from primimitive types.
 
 use super::U256;
-use crate::{error::tfie, int::I256};
-use core::{convert::TryFrom, num::TryFromIntError};
+use crate::{
+    error::{tfie, tfse},
+    int::I256,
+};
+use core::{
+    array::TryFromSliceError,
+    convert::TryFrom,
+    num::{
+        NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, TryFromIntError,
+    },
+};
 
 macro_rules! impl_from {
     ($($t:ty),* $(,)?) => {$(
@@ -41,10 +50,7 @@ impl TryFrom<I256> for U256 {
     type Error = TryFromIntError;
 
     fn try_from(value: I256) -> Result<Self, Self::Error> {
-        if value < 0 {
-            return Err(tfie());
-        }
-        Ok(value.as_u256())
+        value.try_into_unsigned()
     }
 }
 
@@ -142,7 +148,7 @@ macro_rules! impl_as_u256_float {
                     let mantissa = (bits & MAN_MASK) | MAN_ONE;
                     if exponent <= M {
                         U256::from(mantissa >> (M - exponent))
-                    } else if exponent < 256 {
+                    } else if exponent < 256 && self.is_finite() {
                         U256::from(mantissa) << (exponent - M)
                     } else {
                         U256::MAX
@@ -159,6 +165,135 @@ impl_as_u256_float! {
     f32[u32], f64[u64],
 }
 
+/// This trait defines fallible conversions from primitive types to
+/// [`U256`].
+///
+/// Unlike [`AsU256`], which mirrors the truncating and saturating semantics of
+/// `as` casts, these conversions return an error when the value can't be
+/// represented exactly as a [`U256`]. For floating point values this means that
+/// `NaN`, infinities and values outside of the [`U256`] range are rejected,
+/// while the fractional part is truncated.
+///
+/// [`U256`]: struct.U256.html
+///
+/// # Examples
+///
+/// ```
+/// # use ethnum::{U256, TryAsU256};
+/// assert_eq!(42u8.try_as_u256(), Ok(U256::new(42)));
+/// assert!((-1i32).try_as_u256().is_err());
+///
+/// assert_eq!(1.5f64.try_as_u256(), Ok(U256::ONE));
+/// assert!((-1.0f64).try_as_u256().is_err());
+/// assert!(f64::INFINITY.try_as_u256().is_err());
+/// assert!(2.0f64.powi(256).try_as_u256().is_err());
+/// assert!(f64::NAN.try_as_u256().is_err());
+/// ```
+pub trait TryAsU256 {
+    /// Perform a checked conversion to a [`U256`].
+    ///
+    /// [`U256`]: struct.U256.html
+    #[allow(clippy::wrong_self_convention)]
+    fn try_as_u256(self) -> Result<U256, TryFromIntError>;
+}
+
+impl TryAsU256 for U256 {
+    #[inline]
+    fn try_as_u256(self) -> Result<U256, TryFromIntError> {
+        Ok(self)
+    }
+}
+
+macro_rules! impl_try_as_u256 {
+    (
+        infallible: $($i:ty),* ;
+        fallible: $($f:ty),* $(,)?
+    ) => {
+        $(
+            impl TryAsU256 for $i {
+                #[inline]
+                fn try_as_u256(self) -> Result<U256, TryFromIntError> {
+                    Ok(self.as_u256())
+                }
+            }
+        )*
+        $(
+            impl TryAsU256 for $f {
+                #[inline]
+                fn try_as_u256(self) -> Result<U256, TryFromIntError> {
+                    U256::try_from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_as_u256! {
+    infallible:
+    u8, u16, u32, u64, u128,
+    usize;
+    fallible:
+    i8, i16, i32, i64, i128,
+    isize,
+    I256, f32, f64,
+}
+
+impl TryFrom<&[u8]> for U256 {
+    type Error = TryFromSliceError;
+
+    /// Converts a big endian byte slice of arbitrary length to a `U256`.
+    ///
+    /// Slices shorter than 32 bytes are zero-extended, and slices longer than
+    /// 32 bytes are accepted as long as all extra leading bytes are zero. The
+    /// only possible error is the value not fitting in 256 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// assert_eq!(U256::try_from([0x2a].as_slice()).ok(), Some(U256::new(42)));
+    /// assert_eq!(U256::try_from([].as_slice()).ok(), Some(U256::ZERO));
+    /// assert!(U256::try_from([0x01; 33].as_slice()).is_err());
+    /// ```
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let start = bytes.len().saturating_sub(32);
+        let (extra, bytes) = bytes.split_at(start);
+        if extra.iter().any(|&b| b != 0) {
+            return Err(tfse());
+        }
+        U256::from_be_slice(bytes)
+    }
+}
+
+impl TryFrom<f64> for U256 {
+    type Error = TryFromIntError;
+
+    /// Converts a floating point value to a `U256`, truncating any fractional
+    /// part. Returns an error for `NaN`, negative and infinite values, as well
+    /// as values greater than or equal to `2^256`.
+    #[inline]
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        // NOTE: Binary representation of 2**256.
+        const MAX: u64 = 0x4ff0000000000000;
+        if (0.0..f64::from_bits(MAX)).contains(&value) {
+            Ok(value.as_u256())
+        } else {
+            Err(tfie())
+        }
+    }
+}
+
+impl TryFrom<f32> for U256 {
+    type Error = TryFromIntError;
+
+    /// Converts a floating point value to a `U256`, truncating any fractional
+    /// part. Returns an error for `NaN`, negative and infinite values.
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        U256::try_from(value as f64)
+    }
+}
+
 macro_rules! impl_try_into {
     ($($t:ty),* $(,)?) => {$(
         impl TryFrom<U256> for $t {
@@ -182,6 +317,35 @@ impl_try_into! {
     isize, usize,
 }
 
+macro_rules! impl_nonzero {
+    ($($nz:ident => $t:ty),* $(,)?) => {$(
+        impl From<$nz> for U256 {
+            #[inline]
+            fn from(value: $nz) -> Self {
+                value.get().as_u256()
+            }
+        }
+
+        impl TryFrom<U256> for $nz {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(x: U256) -> Result<Self, Self::Error> {
+                <$t>::try_from(x).ok().and_then($nz::new).ok_or_else(tfie)
+            }
+        }
+    )*};
+}
+
+impl_nonzero! {
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+}
+
 macro_rules! impl_into_float {
     ($($t:ty => $f:ident),* $(,)?) => {$(
         impl From<U256> for $t {
@@ -196,3 +360,157 @@ macro_rules! impl_into_float {
 impl_into_float! {
     f32 => as_f32, f64 => as_f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonzero() {
+        assert_eq!(U256::from(NonZeroU128::new(u128::MAX).unwrap()), u128::MAX);
+        assert_eq!(U256::from(NonZeroUsize::new(1).unwrap()), 1);
+        assert_eq!(
+            NonZeroU128::try_from(U256::new(u128::MAX)).unwrap().get(),
+            u128::MAX
+        );
+        assert!(NonZeroU128::try_from(U256::new(u128::MAX) + 1).is_err());
+        assert!(NonZeroU8::try_from(U256::new(256)).is_err());
+        assert!(NonZeroU8::try_from(U256::ZERO).is_err());
+        assert_eq!(NonZeroU8::try_from(U256::new(255)).unwrap().get(), 255);
+    }
+
+    #[test]
+    fn try_as_u256() {
+        fn try_as<T: TryAsU256>(x: T) -> Option<U256> {
+            x.try_as_u256().ok()
+        }
+
+        assert_eq!(try_as(u128::MAX), Some(U256::new(u128::MAX)));
+        assert_eq!(try_as(usize::MAX), Some(U256::new(usize::MAX as _)));
+        assert_eq!(try_as(i128::MAX), Some(U256::new(i128::MAX as _)));
+        assert_eq!(try_as(-1i8), None);
+        assert_eq!(try_as(isize::MIN), None);
+        assert_eq!(try_as(U256::MAX), Some(U256::MAX));
+        assert_eq!(try_as(I256::MAX), Some(I256::MAX.as_u256()));
+        assert_eq!(try_as(I256::MINUS_ONE), None);
+
+        assert_eq!(try_as(0.0f32), Some(U256::ZERO));
+        assert_eq!(try_as(-0.0f64), Some(U256::ZERO));
+        assert_eq!(try_as(-0.5f64), None);
+        assert_eq!(try_as(f32::MAX), Some(f32::MAX.as_u256()));
+        assert_eq!(try_as(f32::INFINITY), None);
+        assert_eq!(try_as(f64::NAN), None);
+        assert_eq!(
+            try_as(f64::from_bits(0x4fefffffffffffff)),
+            Some(U256::MAX - (U256::MAX >> 53u32))
+        );
+        assert_eq!(try_as(f64::from_bits(0x4ff0000000000000)), None);
+    }
+
+    #[test]
+    fn try_into_primitive_boundaries() {
+        macro_rules! assert_boundaries {
+            ($($t:ty),* $(,)?) => {$(
+                let max = <$t>::MAX.as_u256();
+                assert_eq!(<$t>::try_from(U256::ZERO), Ok(0));
+                assert_eq!(<$t>::try_from(max), Ok(<$t>::MAX));
+                assert_eq!(<$t>::try_from(max + 1), Err(tfie()));
+                assert_eq!(<$t>::try_from(U256::from_words(1, 0)), Err(tfie()));
+                assert_eq!(<$t>::try_from(U256::MAX), Err(tfie()));
+            )*};
+        }
+
+        assert_boundaries! {
+            i8, i16, i32, i64, i128,
+            u8, u16, u32, u64,
+            isize, usize,
+        }
+
+        assert_eq!(u128::try_from(U256::new(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(u128::try_from(U256::from_words(1, 0)), Err(tfie()));
+        assert_eq!(u128::try_from(U256::from_words(1, 42)), Err(tfie()));
+        assert_eq!(u128::try_from(U256::MAX), Err(tfie()));
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let convert = |bytes: &[u8]| U256::try_from(bytes).ok();
+
+        assert_eq!(convert(&[]), Some(U256::ZERO));
+        assert_eq!(convert(&[0x00]), Some(U256::ZERO));
+        assert_eq!(convert(&[0x01, 0x00]), Some(U256::new(0x100)));
+        assert_eq!(convert(&[0xff; 32]), Some(U256::MAX));
+
+        let mut padded = [0xff; 33];
+        padded[0] = 0x00;
+        assert_eq!(convert(&padded), Some(U256::MAX));
+        assert_eq!(convert(&[0x00; 100]), Some(U256::ZERO));
+
+        padded[0] = 0x01;
+        assert_eq!(convert(&padded), None);
+        assert_eq!(convert(&[0xff; 33]), None);
+    }
+
+    #[test]
+    fn as_u256_float_saturates() {
+        assert_eq!(f64::NAN.as_u256(), U256::ZERO);
+        assert_eq!(f32::NAN.as_u256(), U256::ZERO);
+        assert_eq!(f64::INFINITY.as_u256(), U256::MAX);
+        assert_eq!(f32::INFINITY.as_u256(), U256::MAX);
+        assert_eq!(f64::NEG_INFINITY.as_u256(), U256::ZERO);
+        assert_eq!(f32::NEG_INFINITY.as_u256(), U256::ZERO);
+
+        assert_eq!((-0.0f64).as_u256(), U256::ZERO);
+        assert_eq!((-1.5f64).as_u256(), U256::ZERO);
+        assert_eq!(f64::MIN.as_u256(), U256::ZERO);
+        assert_eq!(f32::MIN.as_u256(), U256::ZERO);
+
+        assert_eq!(0.5f64.as_u256(), U256::ZERO);
+        assert_eq!(2.5f64.as_u256(), U256::new(2));
+        assert_eq!(2.5f32.as_u256(), U256::new(2));
+        assert_eq!(f32::MAX.as_u256(), U256::new(f32::MAX as u128));
+
+        assert_eq!(2.0f64.powi(255).as_u256(), U256::ONE << 255);
+        assert_eq!(2.0f64.powi(256).as_u256(), U256::MAX);
+        assert_eq!(f64::MAX.as_u256(), U256::MAX);
+    }
+
+    #[test]
+    fn try_from_float() {
+        for i in 0..256 {
+            assert_eq!(U256::try_from(2.0f64.powi(i)), Ok(U256::ONE << i));
+        }
+        for i in 0..128 {
+            assert_eq!(U256::try_from(2.0f32.powi(i)), Ok(U256::ONE << i));
+        }
+
+        assert_eq!(U256::try_from(0.0f64), Ok(U256::ZERO));
+        assert_eq!(U256::try_from(-0.0f64), Ok(U256::ZERO));
+        assert_eq!(U256::try_from(0.99f64), Ok(U256::ZERO));
+        assert_eq!(U256::try_from(42.9f64), Ok(U256::new(42)));
+        assert_eq!(U256::try_from(1337.5f32), Ok(U256::new(1337)));
+        assert_eq!(
+            U256::try_from(2.0f64.powi(60) + 1024.0),
+            Ok(U256::ONE << 60 | U256::new(1024)),
+        );
+        assert_eq!(
+            U256::try_from(f32::MAX),
+            Ok(U256::new(0xffffff) << (127 - 23)),
+        );
+        assert_eq!(
+            U256::try_from(f64::MAX / 2.0f64.powi(768)),
+            Ok(U256::MAX << 203),
+        );
+
+        assert_eq!(U256::try_from(2.0f64.powi(256)), Err(tfie()));
+        assert_eq!(U256::try_from(f64::MAX), Err(tfie()));
+        assert_eq!(U256::try_from(f64::INFINITY), Err(tfie()));
+        assert_eq!(U256::try_from(f64::NEG_INFINITY), Err(tfie()));
+        assert_eq!(U256::try_from(f64::NAN), Err(tfie()));
+        assert_eq!(U256::try_from(-0.5f64), Err(tfie()));
+        assert_eq!(U256::try_from(-1.0f64), Err(tfie()));
+        assert_eq!(U256::try_from(f32::INFINITY), Err(tfie()));
+        assert_eq!(U256::try_from(f32::NAN), Err(tfie()));
+        assert_eq!(U256::try_from(-1.0f32), Err(tfie()));
+    }
+}
@@ -0,0 +1,166 @@
+//! Module containing conversions between byte strings of concatenated 32-byte
+//! big endian words and 256-bit unsigned integers.
+//!
+//! This is the layout of ABI encoded calldata, EVM memory and storage proofs.
+
+use crate::{error::tfse, U256};
+use core::{array::TryFromSliceError, iter::FusedIterator, slice::ChunksExact};
+
+/// An iterator over the 32-byte big endian words of a byte string.
+///
+/// This `struct` is created by [`U256::iter_be_words`].
+#[derive(Clone, Debug)]
+pub struct BeWords<'a> {
+    chunks: ChunksExact<'a, u8>,
+}
+
+/// Converts an exact 32-byte chunk to an integer.
+#[inline]
+fn word(chunk: &[u8]) -> U256 {
+    // The chunks always have the right length, so this never panics.
+    U256::from_be_bytes(chunk.try_into().expect("chunk is a 32-byte word"))
+}
+
+impl Iterator for BeWords<'_> {
+    type Item = U256;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(word)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.chunks.nth(n).map(word)
+    }
+}
+
+impl DoubleEndedIterator for BeWords<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(word)
+    }
+}
+
+impl ExactSizeIterator for BeWords<'_> {}
+
+impl FusedIterator for BeWords<'_> {}
+
+impl U256 {
+    /// Returns an iterator over the integers of a byte string of concatenated
+    /// 32-byte big endian words, without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of the byte string is not a multiple of
+    /// 32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let mut calldata = [0; 64];
+    /// calldata[31] = 1;
+    /// calldata[63] = 2;
+    /// let words = U256::iter_be_words(&calldata).unwrap();
+    /// assert_eq!(words.collect::<Vec<_>>(), [U256::ONE, U256::new(2)]);
+    ///
+    /// assert!(U256::iter_be_words(&calldata[1..]).is_err());
+    /// ```
+    pub fn iter_be_words(bytes: &[u8]) -> Result<BeWords<'_>, TryFromSliceError> {
+        let chunks = bytes.chunks_exact(32);
+        if !chunks.remainder().is_empty() {
+            return Err(tfse());
+        }
+        Ok(BeWords { chunks })
+    }
+
+    /// Converts a byte string of concatenated 32-byte big endian words into
+    /// integers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of the byte string is not a multiple of
+    /// 32.
+    #[cfg(feature = "alloc")]
+    pub fn from_be_words_slice(bytes: &[u8]) -> Result<alloc::vec::Vec<Self>, TryFromSliceError> {
+        Ok(Self::iter_be_words(bytes)?.collect())
+    }
+
+    /// Appends integers to a byte buffer as concatenated 32-byte big endian
+    /// words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::U256;
+    /// let values = [U256::ONE, U256::MAX];
+    /// let mut out = vec![0xff];
+    /// U256::write_be_words(&values, &mut out);
+    /// assert_eq!(out.len(), 1 + 64);
+    /// assert_eq!(U256::from_be_words_slice(&out[1..]).unwrap(), values);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn write_be_words(values: &[Self], out: &mut alloc::vec::Vec<u8>) {
+        out.reserve(values.len() * 32);
+        for value in values {
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn bytes(len: usize) -> Vec<u8> {
+        (0..len).map(|i| i as u8).collect()
+    }
+
+    #[test]
+    fn iterates_words() {
+        let bytes = bytes(128);
+        let word = |i: usize| U256::from_be_bytes(bytes[i * 32..][..32].try_into().unwrap());
+        let mut words = U256::iter_be_words(&bytes).unwrap();
+        assert_eq!(words.len(), 4);
+        assert_eq!(words.next(), Some(word(0)));
+        assert_eq!(words.next_back(), Some(word(3)));
+        assert_eq!(words.nth(1), Some(word(2)));
+        assert_eq!(words.next(), None);
+        assert_eq!(words.len(), 0);
+
+        assert_eq!(U256::iter_be_words(&[]).unwrap().next(), None);
+    }
+
+    #[test]
+    fn rejects_partial_words() {
+        for len in [1, 31, 33, 63, 65, 95] {
+            assert!(U256::iter_be_words(&bytes(len)).is_err(), "{len}");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trips() {
+        for len in [0, 32, 64, 320] {
+            let bytes = bytes(len);
+            let values = U256::from_be_words_slice(&bytes).unwrap();
+            assert_eq!(values.len(), len / 32);
+
+            let mut out = Vec::new();
+            U256::write_be_words(&values, &mut out);
+            assert_eq!(out, bytes);
+        }
+        assert!(U256::from_be_words_slice(&bytes(33)).is_err());
+
+        let mut out = Vec::new();
+        U256::write_be_words(&[], &mut out);
+        assert!(out.is_empty());
+    }
+}
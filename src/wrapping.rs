@@ -0,0 +1,312 @@
+//! Module containing wrapper types for intentionally-wrapped and
+//! intentionally-saturating arithmetic on 256-bit integers.
+//!
+//! These are the equivalent of `core::num::Wrapping` and
+//! `core::num::Saturating` for the 256-bit integer types, for which the
+//! standard library wrappers don't provide any arithmetic operations. All
+//! operators delegate to the corresponding `wrapping_*` or `saturating_*`
+//! methods of the inner type.
+
+use crate::{I256, U256};
+use core::fmt;
+
+macro_rules! impl_wrapper {
+    (
+        $(#[$attr:meta])*
+        $wrapper:ident($int:ident) | $prim:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
+        #[repr(transparent)]
+        pub struct $wrapper(pub $int);
+
+        impl $wrapper {
+            /// The additive identity for this integer type, i.e. `0`.
+            pub const ZERO: Self = Self($int::ZERO);
+
+            /// The multiplicative identity for this integer type, i.e. `1`.
+            pub const ONE: Self = Self($int::ONE);
+
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: Self = Self($int::MIN);
+
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self($int::MAX);
+
+            /// Creates a new wrapped value from a primitive integer.
+            #[inline]
+            pub const fn new(value: $prim) -> Self {
+                Self($int::new(value))
+            }
+        }
+
+        impl From<$int> for $wrapper {
+            #[inline]
+            fn from(value: $int) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$wrapper> for $int {
+            #[inline]
+            fn from(value: $wrapper) -> Self {
+                value.0
+            }
+        }
+
+        __impl_ops_unop! {
+            impl Not for $wrapper {
+                not(x) {
+                    $wrapper(!x.0)
+                }
+            }
+        }
+
+        impl_iter! {
+            impl Iter for $wrapper;
+        }
+
+        impl_wrapper!(@fmt $wrapper: Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex);
+    };
+
+    (@fmt $wrapper:ident: $($trait:ident),*) => {$(
+        impl fmt::$trait for $wrapper {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::$trait::fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_wrapper_bitwise {
+    (for $wrapper:ident | $prim:ident) => {
+        impl_ops_wrapper! {
+            for $wrapper | $prim {
+                impl BitAnd, BitAndAssign {
+                    & bitand, &= bitand_assign => |a, b| a & b;
+                }
+                impl BitOr, BitOrAssign {
+                    | bitor, |= bitor_assign => |a, b| a | b;
+                }
+                impl BitXor, BitXorAssign {
+                    ^ bitxor, ^= bitxor_assign => |a, b| a ^ b;
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_wrapping {
+    (for $wrapper:ident | $prim:ident) => {
+        impl_ops_wrapper! {
+            for $wrapper | $prim {
+                impl Add, AddAssign {
+                    + add, += add_assign => |a, b| a.wrapping_add(b);
+                }
+                impl Div, DivAssign {
+                    / div, /= div_assign => |a, b| a.wrapping_div(b);
+                }
+                impl Mul, MulAssign {
+                    * mul, *= mul_assign => |a, b| a.wrapping_mul(b);
+                }
+                impl Rem, RemAssign {
+                    % rem, %= rem_assign => |a, b| a.wrapping_rem(b);
+                }
+                impl Sub, SubAssign {
+                    - sub, -= sub_assign => |a, b| a.wrapping_sub(b);
+                }
+            }
+        }
+
+        impl_ops_wrapper_shift! {
+            for $wrapper {
+                impl Shl, ShlAssign {
+                    << shl, <<= shl_assign => |a, b| a.wrapping_shl(b);
+                }
+                impl Shr, ShrAssign {
+                    >> shr, >>= shr_assign => |a, b| a.wrapping_shr(b);
+                }
+            }
+        }
+
+        __impl_ops_unop! {
+            impl Neg for $wrapper {
+                neg(x) {
+                    $wrapper(x.0.wrapping_neg())
+                }
+            }
+        }
+
+        impl_wrapper_bitwise!(for $wrapper | $prim);
+    };
+}
+
+macro_rules! impl_saturating {
+    (for $wrapper:ident | $prim:ident) => {
+        impl_ops_wrapper! {
+            for $wrapper | $prim {
+                impl Add, AddAssign {
+                    + add, += add_assign => |a, b| a.saturating_add(b);
+                }
+                impl Div, DivAssign {
+                    / div, /= div_assign => |a, b| a.saturating_div(b);
+                }
+                impl Mul, MulAssign {
+                    * mul, *= mul_assign => |a, b| a.saturating_mul(b);
+                }
+                impl Sub, SubAssign {
+                    - sub, -= sub_assign => |a, b| a.saturating_sub(b);
+                }
+            }
+        }
+
+        impl_wrapper_bitwise!(for $wrapper | $prim);
+    };
+}
+
+impl_wrapper! {
+    /// A 256-bit unsigned integer with intentionally-wrapped arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::{U256, WrappingU256};
+    /// let x = WrappingU256(U256::MAX) + 1;
+    /// assert_eq!(x, WrappingU256::ZERO);
+    /// ```
+    WrappingU256(U256) | u128
+}
+impl_wrapping!(for WrappingU256 | u128);
+
+impl_wrapper! {
+    /// A 256-bit signed integer with intentionally-wrapped arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::{I256, WrappingI256};
+    /// let x = WrappingI256(I256::MAX) + 1;
+    /// assert_eq!(x, WrappingI256::MIN);
+    /// ```
+    WrappingI256(I256) | i128
+}
+impl_wrapping!(for WrappingI256 | i128);
+
+impl_wrapper! {
+    /// A 256-bit unsigned integer with intentionally-saturating arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::{U256, SaturatingU256};
+    /// let x = SaturatingU256(U256::MAX) + 1;
+    /// assert_eq!(x, SaturatingU256::MAX);
+    /// ```
+    SaturatingU256(U256) | u128
+}
+impl_saturating!(for SaturatingU256 | u128);
+
+impl_wrapper! {
+    /// A 256-bit signed integer with intentionally-saturating arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ethnum::{I256, SaturatingI256};
+    /// let x = SaturatingI256(I256::MIN) - 1;
+    /// assert_eq!(x, SaturatingI256::MIN);
+    /// ```
+    SaturatingI256(I256) | i128
+}
+impl_saturating!(for SaturatingI256 | i128);
+
+__impl_ops_unop! {
+    impl Neg for SaturatingI256 {
+        neg(x) {
+            SaturatingI256(x.0.saturating_neg())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping() {
+        let max = WrappingU256::MAX;
+        assert_eq!(max + 1, WrappingU256::ZERO);
+        assert_eq!(WrappingU256::ZERO - 1, max);
+        assert_eq!(max * max, WrappingU256::ONE);
+        assert_eq!(-WrappingU256::ONE, max);
+        assert_eq!(WrappingU256::ONE << 257, WrappingU256::new(2));
+        assert_eq!(max >> 255, WrappingU256::ONE);
+        assert_eq!(!max, WrappingU256::ZERO);
+        assert_eq!(max & 0xff, WrappingU256::new(0xff));
+        assert_eq!(42 + WrappingU256::new(1), WrappingU256::new(43));
+
+        let min = WrappingI256::MIN;
+        assert_eq!(min - 1, WrappingI256::MAX);
+        assert_eq!(-min, min);
+        let minus_one = WrappingI256::new(-1);
+        assert_eq!(min / minus_one, min);
+        assert_eq!(min % minus_one, WrappingI256::ZERO);
+
+        let mut x = WrappingU256(U256::MAX);
+        x += WrappingU256::new(2);
+        x *= 3;
+        x -= &WrappingU256::new(4);
+        x <<= 1;
+        assert_eq!(x, WrappingU256::ZERO - 2);
+
+        let sum: WrappingU256 = [max, max, WrappingU256::new(2)].iter().sum();
+        assert_eq!(sum, WrappingU256::ZERO);
+    }
+
+    #[test]
+    fn saturating() {
+        let max = SaturatingU256::MAX;
+        assert_eq!(max + 1, max);
+        assert_eq!(SaturatingU256::ZERO - 1, SaturatingU256::ZERO);
+        assert_eq!(max * 2, max);
+        assert_eq!(max / 2, SaturatingU256(U256::MAX >> 1));
+
+        let (min, max) = (SaturatingI256::MIN, SaturatingI256::MAX);
+        assert_eq!(min - 1, min);
+        assert_eq!(max + 1, max);
+        assert_eq!(-min, max);
+        assert_eq!(min / -1, max);
+        assert_eq!(min * 2, min);
+        assert_eq!(min * -2, max);
+
+        let mut x = SaturatingI256::new(-1);
+        x *= &max;
+        x -= 2;
+        assert_eq!(x, min);
+
+        let product: SaturatingU256 = [SaturatingU256::MAX, SaturatingU256::new(2)]
+            .into_iter()
+            .product();
+        assert_eq!(product, SaturatingU256::MAX);
+    }
+
+    #[test]
+    fn formatting() {
+        use alloc::format;
+
+        let x = WrappingI256::new(-42);
+        assert_eq!(format!("{x} {x:?}"), "-42 -42");
+        assert_eq!(format!("{:x}", SaturatingU256::new(255)), "ff");
+        assert_eq!(U256::from(WrappingU256::new(1)), U256::ONE);
+        assert_eq!(
+            SaturatingI256::from(I256::MINUS_ONE),
+            SaturatingI256::new(-1)
+        );
+    }
+}